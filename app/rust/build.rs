@@ -0,0 +1,120 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+//! Generates the known asset table from `assets/registry.json`.
+//!
+//! Every asset ID is recomputed from its base denom, the same way
+//! `AssetId::new` does, and the build fails if it differs from the ID
+//! recorded in the registry.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use decaf377::Fq;
+use serde_json::Value;
+
+const REGISTRY: &str = "assets/registry.json";
+const ASSET_ID_PERSONAL: &[u8; 16] = b"Penumbra_AssetID";
+
+struct Asset {
+    base: String,
+    display: String,
+    exponent: u8,
+}
+
+fn asset_id(base: &str) -> [u8; 32] {
+    let hash = blake2b_simd::Params::new()
+        .personal(ASSET_ID_PERSONAL)
+        .hash(base.as_bytes());
+    Fq::from_le_bytes_mod_order(hash.as_bytes()).to_bytes()
+}
+
+fn decode_hex(hex: &str) -> Option<[u8; 32]> {
+    if hex.len() != 64 || !hex.is_ascii() {
+        return None;
+    }
+    let mut out = [0u8; 32];
+    for (byte, pair) in out.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(out)
+}
+
+fn string_field<'a>(entry: &'a Value, field: &str, index: usize) -> &'a str {
+    entry[field]
+        .as_str()
+        .unwrap_or_else(|| panic!("{REGISTRY}: entry {index} has no string `{field}`"))
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={REGISTRY}");
+
+    let registry = fs::read_to_string(REGISTRY).expect("reading asset registry");
+    let registry: Value = serde_json::from_str(&registry).expect("parsing asset registry");
+    let entries = registry
+        .as_array()
+        .unwrap_or_else(|| panic!("{REGISTRY}: expected an array of assets"));
+
+    // Keyed by the ID bytes so the table comes out in lookup order.
+    let mut assets = BTreeMap::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let base = string_field(entry, "base", index);
+        let display = string_field(entry, "display", index);
+        let exponent = entry["exponent"]
+            .as_u64()
+            .and_then(|exponent| u8::try_from(exponent).ok())
+            .unwrap_or_else(|| panic!("{REGISTRY}: entry {index} has an invalid `exponent`"));
+        let claimed = decode_hex(string_field(entry, "id", index))
+            .unwrap_or_else(|| panic!("{REGISTRY}: entry {index} has a malformed `id`"));
+
+        let id = asset_id(base);
+        if id != claimed {
+            panic!("{REGISTRY}: asset ID of `{base}` does not match the registry");
+        }
+
+        let asset = Asset {
+            base: base.into(),
+            display: display.into(),
+            exponent,
+        };
+        if assets.insert(id, asset).is_some() {
+            panic!("{REGISTRY}: `{base}` is listed more than once");
+        }
+    }
+
+    let mut table = String::new();
+    writeln!(
+        table,
+        "pub static KNOWN_ASSETS: [KnownAsset; {}] = [",
+        assets.len()
+    )
+    .unwrap();
+    for (id, asset) in &assets {
+        writeln!(
+            table,
+            "    KnownAsset {{ id: {:?}, base_denom: {:?}, display_denom: {:?}, exponent: {} }},",
+            id, asset.base, asset.display, asset.exponent
+        )
+        .unwrap();
+    }
+    table.push_str("];\n");
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("known_assets.rs");
+    fs::write(out, table).expect("writing known asset table");
+}
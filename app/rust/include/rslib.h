@@ -22,11 +22,33 @@ void sign_decaf377(const uint8_t *msg, uint32_t msg_len, uint8_t *sig, uint32_t
 // randomized or not(randomizer = NULL)
 parser_error_t rs_compute_address(keys_t *keys, uint32_t account, uint8_t *randomizer);
 
+// use to prove ownership of the address at account/randomizer for the auditor's challenge, revealing ak but no secret key
+parser_error_t rs_prove_address_ownership(const keys_t *keys, uint32_t account, const uint8_t *randomizer,
+                                          const bytes_t *challenge, uint8_t *output, uint16_t output_len);
+
+// To compute a one-time address for account under a device-chosen randomizer,
+// writing its 16-byte address index (account || randomizer) into address_index
+parser_error_t rs_compute_ephemeral_address(keys_t *keys, uint32_t account, uint8_t *address_index);
+
 // use to compute the full-viewing key
 parser_error_t rs_compute_keys(keys_t *keys);
+
+// use to encrypt the full-viewing key to a host-provided decaf377 public key
+parser_error_t rs_encrypt_fvk(const keys_t *keys, const uint8_t *host_pk, uint8_t *output, size_t output_len);
+
+// use to compute the fingerprint of the host public key shown before an encrypted export
+parser_error_t rs_fvk_recipient_fingerprint(const uint8_t *host_pk, uint8_t *output, size_t output_len);
+
+// use to encode the full-viewing key as a custody ExportFullViewingKeyResponse
+parser_error_t rs_encode_fvk_response(const keys_t *keys, uint8_t *output, size_t output_len);
+
 int32_t rs_bech32_encode(const uint8_t *hrp_ptr, size_t hrp_len, const uint8_t *data_ptr, size_t data_len,
                          uint8_t *output_ptr, size_t output_len);
 
+// renders a delegation or unbonding denom, written is 0 for any other denom
+parser_error_t rs_format_staking_denom(const bytes_t *data, uint8_t *out, uint16_t out_len, uint16_t *written);
+parser_error_t rs_format_amount(const amount_t *amount, uint8_t exponent, uint8_t *out, uint16_t out_len);
+
 parser_error_t rs_is_address_visible(const bytes_t *address, bool *is_visible, uint32_t *index);
 
 parser_error_t rs_compute_effect_hash(transaction_plan_t *plan, uint8_t *output, size_t output_len);
@@ -35,17 +57,70 @@ parser_error_t rs_parameter_hash(bytes_t *data, uint8_t *output, size_t output_l
 parser_error_t rs_spend_action_hash(spend_plan_t *plan, uint8_t *output, size_t output_len);
 parser_error_t rs_output_action_hash(output_plan_t *plan, bytes_t *memo_key, uint8_t *output, size_t output_len);
 parser_error_t rs_swap_action_hash(swap_plan_t *plan, uint8_t *output, size_t output_len);
+parser_error_t rs_delegate_action_hash(delegate_plan_t *plan, uint8_t *output, size_t output_len);
+parser_error_t rs_undelegate_action_hash(undelegate_plan_t *plan, uint8_t *output, size_t output_len);
 parser_error_t rs_undelegate_claim_action_hash(undelegate_claim_plan_t *plan, uint8_t *output, size_t output_len);
+parser_error_t rs_undelegate_claim_penalty(const undelegate_claim_plan_t *plan, uint8_t *output, uint16_t output_len,
+                                           amount_t *slashed_amount, amount_t *claimed_amount, bool *is_slashed);
 parser_error_t rs_delegator_vote_action_hash(delegator_vote_plan_t *plan, uint8_t *output, size_t output_len);
+parser_error_t rs_delegator_vote_validate(delegator_vote_plan_t *plan, const bytes_t *staked_denom);
+parser_error_t rs_delegate_check_rate(const delegate_plan_t *plan, const rate_data_t *rate_data);
+parser_error_t rs_undelegate_check_rate(const undelegate_plan_t *plan, const rate_data_t *rate_data);
+parser_error_t rs_position_open_action_hash(position_open_plan_t *plan, uint8_t *output, size_t output_len);
+parser_error_t rs_position_open_implied_price(const position_open_plan_t *plan, bool inverse, uint8_t *output,
+                                              uint16_t output_len);
+parser_error_t rs_trading_pair_validate(const trading_pair_t *pair);
+parser_error_t rs_swap_direction(const swap_plan_t *plan, bool *sells_asset_1, bool *sells_asset_2);
+parser_error_t rs_position_open_position_id(const position_open_plan_t *plan, uint8_t *output, uint16_t output_len);
+parser_error_t rs_position_close_action_hash(position_close_plan_t *plan, uint8_t *output, size_t output_len);
 parser_error_t rs_position_withdraw_action_hash(position_withdraw_plan_t *plan, uint8_t *output, size_t output_len);
+parser_error_t rs_action_dutch_auction_schedule_action_hash(action_dutch_auction_schedule_plan_t *plan, uint8_t *output,
+                                                            size_t output_len);
+parser_error_t rs_action_dutch_auction_schedule_auction_id(const action_dutch_auction_schedule_plan_t *plan,
+                                                           uint8_t *output, uint16_t output_len);
+parser_error_t rs_action_dutch_auction_schedule_price(const action_dutch_auction_schedule_plan_t *plan,
+                                                      uint64_t step_index, uint8_t *output, uint16_t output_len);
+parser_error_t rs_action_dutch_auction_end_action_hash(action_dutch_auction_end_plan_t *plan, uint8_t *output,
+                                                       size_t output_len);
 parser_error_t rs_action_dutch_auction_withdraw_action_hash(action_dutch_auction_withdraw_plan_t *plan, uint8_t *output,
                                                             size_t output_len);
 parser_error_t rs_generic_action_hash(bytes_t *data, uint8_t action_type, uint8_t *output, size_t output_len);
 
+parser_error_t rs_flow_summary_add_action(uint8_t action_type, const void *action, flow_summary_t *summary);
+parser_error_t rs_flow_summary_add_fee(const value_t *fee, flow_summary_t *summary);
+parser_error_t rs_plan_balance_add(uint8_t action_type, const void *action, balance_t *plan_balance);
+parser_error_t rs_plan_balance_check(const balance_t *plan_balance, const value_t *fee);
+parser_error_t rs_binding_add_action(uint8_t action_type, const void *action, binding_t *binding);
+
 parser_error_t rs_get_asset_id_from_metadata(const bytes_t *metadata, uint8_t *asset_id, uint16_t asset_id_len);
+parser_error_t rs_known_asset(const uint8_t *asset_id, uint8_t *display_denom, uint16_t display_denom_len,
+                              uint8_t *exponent);
+parser_error_t rs_parse_denom_metadata(const bytes_t *data, tx_metadata_t *metadata);
+parser_error_t rs_parse_rate_data(const bytes_t *data, rate_data_t *rate_data);
+parser_error_t rs_parse_authorize_request(const bytes_t *request, bytes_t *plan,
+                                          pre_authorizations_t *pre_authorizations);
+parser_error_t rs_confirm_address(const bytes_t *request, uint8_t *response, uint16_t response_len, uint16_t *written,
+                                  uint8_t *ui_address, uint16_t ui_address_len);
+parser_error_t rs_delegation_token_asset_id(const identity_key_t *ik, uint8_t *asset_id, uint16_t asset_id_len);
+parser_error_t rs_unbonding_token_asset_id(const identity_key_t *ik, uint64_t start_height, uint8_t *asset_id,
+                                           uint16_t asset_id_len);
 
 parser_error_t rs_sign_spend(const bytes_t *effect_hash, const bytes_t *randomizer, const spend_key_bytes_t *spend_key,
                              uint8_t *signature, uint16_t len);
+parser_error_t rs_sign_binding(const bytes_t *effect_hash, const binding_t *binding, const value_t *fee,
+                               uint8_t *signature, uint16_t len);
+parser_error_t rs_sign_message(const bytes_t *message, const bytes_t *randomizer, const spend_key_bytes_t *spend_key,
+                               uint8_t *output, uint16_t output_len);
+parser_error_t rs_frost_commit(const spend_key_bytes_t *spend_key, const bytes_t *transcript, uint8_t *commitments,
+                               uint16_t commitments_len);
+parser_error_t rs_frost_parse_sign_request(const bytes_t *request, bytes_t *message);
+parser_error_t rs_frost_sign_share(const spend_key_bytes_t *spend_key, const bytes_t *request, uint8_t *share,
+                                   uint16_t share_len);
+parser_error_t rs_encode_authorization_data(const bytes_t *effect_hash, const uint8_t *spend_auths,
+                                            uint16_t spend_auths_qty, const uint8_t *delegator_vote_auths,
+                                            uint16_t delegator_vote_auths_qty, const uint8_t *lqt_vote_auths,
+                                            uint16_t lqt_vote_auths_qty, uint8_t *output, uint16_t output_len,
+                                            uint16_t *written);
 
 #ifdef __cplusplus
 }
@@ -15,14 +15,17 @@
 ********************************************************************************/
 
 use decaf377::Fq;
+use rand::{CryptoRng, RngCore};
 
 use crate::keys::dk::Diversifier;
 use crate::keys::{ka, ClueKey};
 use crate::{keys::dk::DiversifierKey, ParserError};
 
 pub mod address_view;
+pub mod ownership_proof;
 
-use crate::constants::ADDRESS_LEN;
+use crate::constants::{ADDRESS_LEN, ELLIPSIS};
+use crate::ffi::bech32::bech32_encode;
 
 #[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
@@ -60,6 +63,14 @@ impl Address {
     /// Number of bits in the address short form divided by the number of bits per Bech32m character
     pub const ADDRESS_NUM_CHARS_SHORT_FORM: usize = 24;
 
+    pub const BECH32_PREFIX: &'static str = "penumbra";
+    // prefix, separator, 128 data characters and 6 checksum characters
+    pub const BECH32_LEN: usize = 8 + 1 + 128 + 6;
+
+    /// The short form shown on screen: prefix, separator and the leading
+    /// characters of the data, followed by an ellipsis.
+    pub const SHORT_FORM_LEN: usize = 8 + 1 + Self::ADDRESS_NUM_CHARS_SHORT_FORM + ELLIPSIS.len();
+
     /// Use to fill buffer with an raw address(before F4Jumble and bech32 encoding)
     /// for index `idx` and `spend_key`
     /// Returns Ok on success
@@ -122,6 +133,27 @@ impl Address {
         f4jumble::f4jumble_mut(&mut bytes).map_err(|_| ParserError::InvalidLength)?;
         Ok(bytes)
     }
+
+    /// Writes the `penumbra1…` bech32m encoding of this address, returning the number of bytes written.
+    pub fn to_bech32m(self, output: &mut [u8]) -> Result<usize, ParserError> {
+        bech32_encode(Self::BECH32_PREFIX, &self.to_bytes()?, output)
+            .map_err(|_| ParserError::UnexpectedBufferEnd)
+    }
+
+    /// Writes the short form of this address, as `printShortAddress` renders it.
+    pub fn to_short_form(self, output: &mut [u8]) -> Result<usize, ParserError> {
+        if output.len() < Self::SHORT_FORM_LEN {
+            return Err(ParserError::UnexpectedBufferEnd);
+        }
+
+        let mut encoded = [0u8; Self::BECH32_LEN];
+        self.to_bech32m(&mut encoded)?;
+
+        let visible = Self::SHORT_FORM_LEN - ELLIPSIS.len();
+        output[..visible].copy_from_slice(&encoded[..visible]);
+        output[visible..Self::SHORT_FORM_LEN].copy_from_slice(ELLIPSIS.as_bytes());
+        Ok(Self::SHORT_FORM_LEN)
+    }
 }
 
 impl TryFrom<&[u8]> for Address {
@@ -190,6 +222,16 @@ impl AddressIndex {
     pub fn new(account: u32) -> Self {
         AddressIndex::from(account)
     }
+
+    /// A fresh ephemeral index for `account`, with the randomizer drawn from `rng`.
+    pub fn ephemeral<R: RngCore + CryptoRng>(account: u32, rng: &mut R) -> Self {
+        let mut index = Self::new(account);
+        // an all-zero draw would give back the account's default address
+        while !index.is_ephemeral() {
+            rng.fill_bytes(&mut index.randomizer);
+        }
+        index
+    }
 }
 
 impl From<u32> for AddressIndex {
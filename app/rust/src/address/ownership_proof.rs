@@ -0,0 +1,363 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+//! Proofs that an address belongs to the holder of its spend key.
+//!
+//! Every address carries a transmission key `pk_d = ivk * g_d`, where the
+//! diversified generator `g_d` follows from the address's diversifier. A
+//! proof carries the address with its index, and two signatures over the
+//! auditor's challenge:
+//!
+//! - a SpendAuth signature by the unrandomized spend authorization key over
+//!   the address, its index and the challenge, verified with the revealed `ak`;
+//! - a Schnorr signature over the address and the challenge with `g_d` as the
+//!   basepoint and `ivk` as the secret, showing knowledge of the ivk behind
+//!   `pk_d`.
+//!
+//! A verifier holding the wallet's incoming viewing key re-derives the address
+//! from its index, so the proof discloses `ak` and the index but neither the
+//! ivk nor the nullifier key.
+
+use crate::address::{Address, AddressIndex};
+use crate::constants::{ADDRESS_LEN, KEY_LEN, SIGNATURE_LEN};
+use crate::keys::dk::DiversifierKey;
+use crate::keys::ivk::Ivk;
+use crate::keys::spend_key::SpendKeyBytes;
+use crate::ParserError;
+use decaf377::{Element, Encoding, Fr};
+use decaf377_rdsa::{Signature, SpendAuth, VerificationKey};
+use zeroize::Zeroize;
+
+const DIGEST_LEN: usize = 64;
+
+/// A proof that the holder of the spend key behind `address` answered a challenge.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct AddressOwnershipProof {
+    /// The f4jumbled address.
+    pub address: [u8; ADDRESS_LEN],
+    /// The index the address is derived at.
+    pub index: AddressIndex,
+    /// The spend verification key.
+    pub ak: [u8; KEY_LEN],
+    /// The SpendAuth signature over the address, its index and the challenge.
+    pub spend_auth_sig: [u8; SIGNATURE_LEN],
+    /// The commitment `R = k * g_d` to the nonce `k`.
+    pub commitment: [u8; KEY_LEN],
+    /// The response `s = k + c * ivk` to the challenge `c`.
+    pub response: [u8; KEY_LEN],
+}
+
+impl AddressOwnershipProof {
+    pub const LEN: usize =
+        ADDRESS_LEN + DiversifierKey::LEN + KEY_LEN + SIGNATURE_LEN + 2 * KEY_LEN;
+
+    /// Proves ownership of the address at `index`, answering `challenge`.
+    pub fn prove(
+        spend_key: &SpendKeyBytes,
+        index: AddressIndex,
+        challenge: &[u8],
+    ) -> Result<Self, ParserError> {
+        use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+        let fvk = spend_key.fvk()?;
+        let ivk = fvk.ivk();
+        let (address, _) = ivk.payment_address(index)?;
+        let g_d = *address.diversified_generator();
+        let address = address.to_bytes()?;
+
+        // derived from the secret and everything signed, so that the nonce
+        // never repeats across different addresses or challenges
+        let mut secret = ivk.secret().to_bytes();
+        let nonce = Fr::from_le_bytes_mod_order(
+            blake2b_simd::Params::new()
+                .hash_length(DIGEST_LEN)
+                .personal(b"Penumbra_AddrNon")
+                .to_state()
+                .update(&secret)
+                .update(&address)
+                .update(&(challenge.len() as u64).to_le_bytes())
+                .update(challenge)
+                .finalize()
+                .as_bytes(),
+        );
+        secret.zeroize();
+
+        let commitment = (g_d * nonce).vartime_compress().0;
+        let c = Self::challenge(&address, &commitment, challenge);
+
+        let digest = Self::signed_digest(&address, index, challenge);
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&digest[..32]);
+        let mut rng = ChaCha20Rng::from_seed(seed);
+        let ask = spend_key.signing_key()?;
+
+        Ok(Self {
+            address,
+            index,
+            ak: fvk.spend_verification_key().to_bytes(),
+            spend_auth_sig: ask.signing_key().sign(&mut rng, &digest).to_bytes(),
+            commitment,
+            response: ivk.secret().schnorr_response(&nonce, &c).to_bytes(),
+        })
+    }
+
+    /// Checks the proof for `challenge` against the wallet's incoming viewing
+    /// key, returning the index of the proven address.
+    ///
+    /// The address must be viewed by `ivk` and re-derive from the claimed
+    /// index, `response * g_d == commitment + c * pk_d` must hold for the
+    /// challenge `c` over the address, the commitment and `challenge`, and
+    /// `ak` must verify the SpendAuth signature.
+    pub fn verify(&self, ivk: &Ivk, challenge: &[u8]) -> Result<AddressIndex, ParserError> {
+        let address = Address::try_from(self.address.as_slice())?;
+        if !ivk.views_address(&address) {
+            return Err(ParserError::InvalidAddress);
+        }
+        let (derived, _) = ivk.payment_address(self.index)?;
+        if derived.to_bytes()? != self.address {
+            return Err(ParserError::InvalidAddress);
+        }
+
+        let pk_d = decompress(&address.transmission_key().0)?;
+        let commitment = decompress(&self.commitment)?;
+        if pk_d == Element::IDENTITY || commitment == Element::IDENTITY {
+            return Err(ParserError::InvalidAddress);
+        }
+
+        let response =
+            Fr::from_bytes_checked(&self.response).map_err(|_| ParserError::InvalidSignature)?;
+        let c = Self::challenge(&self.address, &self.commitment, challenge);
+
+        if *address.diversified_generator() * response != commitment + pk_d * c {
+            return Err(ParserError::InvalidSignature);
+        }
+
+        let ak = VerificationKey::<SpendAuth>::try_from(self.ak)
+            .map_err(|_| ParserError::InvalidPubkeyEncoding)?;
+        ak.verify(
+            &Self::signed_digest(&self.address, self.index, challenge),
+            &Signature::from(self.spend_auth_sig),
+        )
+        .map_err(|_| ParserError::InvalidSignature)?;
+
+        Ok(self.index)
+    }
+
+    /// The Schnorr challenge: the address, which carries both `g_d` and
+    /// `pk_d`, the commitment, and the length-prefixed auditor's challenge.
+    fn challenge(address: &[u8; ADDRESS_LEN], commitment: &[u8; KEY_LEN], challenge: &[u8]) -> Fr {
+        let digest = blake2b_simd::Params::new()
+            .hash_length(DIGEST_LEN)
+            .personal(b"Penumbra_AddrPrf")
+            .to_state()
+            .update(address)
+            .update(commitment)
+            .update(&(challenge.len() as u64).to_le_bytes())
+            .update(challenge)
+            .finalize();
+
+        Fr::from_le_bytes_mod_order(digest.as_bytes())
+    }
+
+    /// The digest the spend key signs: the address, its index, and the
+    /// length-prefixed auditor's challenge, under their own personalization
+    /// so the signature never passes for one over an effect hash.
+    fn signed_digest(
+        address: &[u8; ADDRESS_LEN],
+        index: AddressIndex,
+        challenge: &[u8],
+    ) -> [u8; DIGEST_LEN] {
+        let digest = blake2b_simd::Params::new()
+            .hash_length(DIGEST_LEN)
+            .personal(b"Penumbra_AddrSig")
+            .to_state()
+            .update(address)
+            .update(&index.to_bytes())
+            .update(&(challenge.len() as u64).to_le_bytes())
+            .update(challenge)
+            .finalize();
+
+        let mut output = [0u8; DIGEST_LEN];
+        output.copy_from_slice(digest.as_bytes());
+        output
+    }
+
+    /// Writes `address || index || ak || spend_auth_sig || commitment || response`.
+    pub fn to_bytes(self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+        let mut offset = 0;
+        for field in [
+            &self.address[..],
+            &self.index.to_bytes(),
+            &self.ak,
+            &self.spend_auth_sig,
+            &self.commitment,
+            &self.response,
+        ] {
+            bytes[offset..offset + field.len()].copy_from_slice(field);
+            offset += field.len();
+        }
+        bytes
+    }
+}
+
+fn decompress(bytes: &[u8; KEY_LEN]) -> Result<Element, ParserError> {
+    Encoding(*bytes)
+        .vartime_decompress()
+        .map_err(|_| ParserError::InvalidPubkeyEncoding)
+}
+
+impl TryFrom<&[u8]> for AddressOwnershipProof {
+    type Error = ParserError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != Self::LEN {
+            return Err(ParserError::InvalidLength);
+        }
+
+        let (address, rest) = bytes.split_at(ADDRESS_LEN);
+        let (index, rest) = rest.split_at(DiversifierKey::LEN);
+        let (ak, rest) = rest.split_at(KEY_LEN);
+        let (spend_auth_sig, rest) = rest.split_at(SIGNATURE_LEN);
+        let (commitment, response) = rest.split_at(KEY_LEN);
+
+        let mut proof = Self {
+            address: [0u8; ADDRESS_LEN],
+            index: AddressIndex::try_from(index)?,
+            ak: [0u8; KEY_LEN],
+            spend_auth_sig: [0u8; SIGNATURE_LEN],
+            commitment: [0u8; KEY_LEN],
+            response: [0u8; KEY_LEN],
+        };
+        proof.address.copy_from_slice(address);
+        proof.ak.copy_from_slice(ak);
+        proof.spend_auth_sig.copy_from_slice(spend_auth_sig);
+        proof.commitment.copy_from_slice(commitment);
+        proof.response.copy_from_slice(response);
+        Ok(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEND_KEY: &str = "ff726c71bcec76abc6a88cba71df655b28de6580edbd33c7415fdfded2e422e7";
+    const OTHER_SPEND_KEY: &str =
+        "a1ffba0c37931f0a626137520da650632d35853bf591b36bb428630a4d87c4dc";
+    const CHALLENGE: &[u8] = b"audit 2024-Q3 #17";
+
+    fn spend_key(hex_key: &str) -> SpendKeyBytes {
+        SpendKeyBytes::try_from(hex::decode(hex_key).unwrap().as_slice()).unwrap()
+    }
+
+    fn ivk(hex_key: &str) -> Ivk {
+        spend_key(hex_key).fvk().unwrap().ivk().clone()
+    }
+
+    fn index() -> AddressIndex {
+        AddressIndex {
+            account: 2,
+            randomizer: [7u8; AddressIndex::RAND_LEN],
+        }
+    }
+
+    #[test]
+    fn test_prove_and_verify() {
+        let proof =
+            AddressOwnershipProof::prove(&spend_key(SPEND_KEY), index(), CHALLENGE).unwrap();
+        assert_eq!(proof.verify(&ivk(SPEND_KEY), CHALLENGE), Ok(index()));
+
+        let parsed = AddressOwnershipProof::try_from(proof.to_bytes().as_slice()).unwrap();
+        assert_eq!(parsed, proof);
+        assert_eq!(parsed.verify(&ivk(SPEND_KEY), CHALLENGE), Ok(index()));
+
+        assert_eq!(
+            proof.verify(&ivk(SPEND_KEY), b"audit 2024-Q3 #18"),
+            Err(ParserError::InvalidSignature)
+        );
+        assert_eq!(
+            AddressOwnershipProof::try_from(&proof.to_bytes()[1..]),
+            Err(ParserError::InvalidLength)
+        );
+
+        // the proof does not disclose the incoming viewing key
+        let ivk = ivk(SPEND_KEY).secret().to_bytes();
+        let bytes = proof.to_bytes();
+        assert!(!bytes.windows(ivk.len()).any(|window| window == ivk));
+    }
+
+    #[test]
+    fn test_proof_bound_to_address() {
+        let proof =
+            AddressOwnershipProof::prove(&spend_key(SPEND_KEY), index(), CHALLENGE).unwrap();
+        let other =
+            AddressOwnershipProof::prove(&spend_key(OTHER_SPEND_KEY), index(), CHALLENGE).unwrap();
+
+        // someone else's wallet does not view the address
+        assert_eq!(
+            proof.verify(&ivk(OTHER_SPEND_KEY), CHALLENGE),
+            Err(ParserError::InvalidAddress)
+        );
+
+        // someone else's address with this wallet's proof
+        let mut foreign = proof;
+        foreign.address = other.address;
+        assert_eq!(
+            foreign.verify(&ivk(SPEND_KEY), CHALLENGE),
+            Err(ParserError::InvalidAddress)
+        );
+
+        // another address of the same wallet does not re-derive from the index
+        let sibling =
+            AddressOwnershipProof::prove(&spend_key(SPEND_KEY), AddressIndex::new(3), CHALLENGE)
+                .unwrap();
+        let mut moved = proof;
+        moved.address = sibling.address;
+        assert_eq!(
+            moved.verify(&ivk(SPEND_KEY), CHALLENGE),
+            Err(ParserError::InvalidAddress)
+        );
+        let mut reindexed = proof;
+        reindexed.index = sibling.index;
+        assert_eq!(
+            reindexed.verify(&ivk(SPEND_KEY), CHALLENGE),
+            Err(ParserError::InvalidAddress)
+        );
+
+        let mut tampered = proof;
+        tampered.response = other.response;
+        assert_eq!(
+            tampered.verify(&ivk(SPEND_KEY), CHALLENGE),
+            Err(ParserError::InvalidSignature)
+        );
+
+        // the spend key signs too, so the ivk alone does not make a proof
+        let mut unsigned = proof;
+        unsigned.spend_auth_sig = other.spend_auth_sig;
+        assert_eq!(
+            unsigned.verify(&ivk(SPEND_KEY), CHALLENGE),
+            Err(ParserError::InvalidSignature)
+        );
+        let mut rekeyed = proof;
+        rekeyed.ak = other.ak;
+        assert_eq!(
+            rekeyed.verify(&ivk(SPEND_KEY), CHALLENGE),
+            Err(ParserError::InvalidSignature)
+        );
+    }
+}
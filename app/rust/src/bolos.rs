@@ -32,6 +32,7 @@ extern "C" {
     fn app_mode_expert() -> u8;
     fn zemu_log_stack(s: *const u8);
     fn io_heartbeat();
+    fn crypto_verify_ed25519(vk: *const u8, msg: *const u8, msg_len: u32, sig: *const u8) -> bool;
 }
 
 extern "C" {
@@ -99,6 +100,26 @@ pub fn is_expert_mode() -> bool {
     }
 }
 
+/// Verifies an Ed25519 signature with the SDK on device. Unit tests verify
+/// on the host instead, and other off-device builds never accept.
+pub fn verify_ed25519(_vk: &[u8; 32], _msg: &[u8], _sig: &[u8; 64]) -> bool {
+    cfg_if::cfg_if! {
+        if #[cfg(all(not(test), not(feature = "clippy"), not(feature = "fuzzing"), not(feature = "cpp_tests")))] {
+            unsafe {
+                crypto_verify_ed25519(_vk.as_ptr(), _msg.as_ptr(), _msg.len() as u32, _sig.as_ptr())
+            }
+        } else if #[cfg(test)] {
+            use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+
+            VerifyingKey::from_bytes(_vk)
+                .map(|vk| vk.verify(_msg, &Signature::from_bytes(_sig)).is_ok())
+                .unwrap_or(false)
+        } else {
+            false
+        }
+    }
+}
+
 pub fn zlog(_msg: &str) {
     #[cfg(all(
         not(test),
@@ -143,3 +164,50 @@ pub(crate) fn heartbeat() {
         io_heartbeat();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode<const N: usize>(hex: &str) -> [u8; N] {
+        hex::decode(hex).unwrap().try_into().unwrap()
+    }
+
+    // RFC 8032, section 7.1, tests 1 to 3
+    const VECTORS: [(&str, &str, &str); 3] = [
+        (
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "",
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+        ),
+        (
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "72",
+            "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+        ),
+        (
+            "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            "af82",
+            "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+        ),
+    ];
+
+    #[test]
+    fn test_verify_ed25519_rfc8032() {
+        for (vk, msg, sig) in VECTORS {
+            let vk = decode::<32>(vk);
+            let msg = hex::decode(msg).unwrap();
+            let mut sig = decode::<64>(sig);
+            assert!(verify_ed25519(&vk, &msg, &sig));
+
+            sig[0] ^= 1;
+            assert!(!verify_ed25519(&vk, &msg, &sig));
+        }
+
+        // a signature does not carry over to another message or key
+        let (vk, _, sig) = VECTORS[1];
+        assert!(!verify_ed25519(&decode(vk), &[0x73], &decode(sig)));
+        let (other_vk, _, _) = VECTORS[2];
+        assert!(!verify_ed25519(&decode(other_vk), &[0x72], &decode(sig)));
+    }
+}
@@ -12,6 +12,11 @@ pub const ADDRESS_LEN: usize = 80;
 
 pub const KEY_LEN: usize = 32;
 pub const FVK_LEN: usize = 64;
+// Ephemeral public key and nonce, followed by the encrypted FVK and its authentication tag.
+pub const ENCRYPTED_FVK_LEN: usize = KEY_LEN + NONCE_LEN + FVK_LEN + 16;
+pub const ENCODED_FVK_RESPONSE_LEN: usize = 2 + 2 + FVK_LEN;
+// The recipient of an encrypted FVK export is shown on screen by this many bytes.
+pub const FVK_RECIPIENT_FINGERPRINT_LEN: usize = 20;
 pub const DIVERSIFIER_KEY_LEN: usize = 16;
 pub const OUTGOING_VIEWING_KEY_LEN: usize = KEY_LEN;
 pub const NULLIFIER_KEY_LEN: usize = KEY_LEN; // Assuming decaf377 curve parameters
@@ -23,19 +28,37 @@ pub const MAX_PRECISION: u8 = 24;
 pub const PAYLOAD_KEY_LEN_BYTES: usize = 32;
 pub const RSEED_LEN_BYTES: usize = 32;
 pub const ID_LEN_BYTES: usize = 32;
+// Same as MAX_DENOM_LEN in constants.h, including the null terminator
+pub const MAX_DENOM_LEN: usize = 121;
 pub const AMOUNT_LEN_BYTES: usize = 16;
 pub const VALIDATOR_IDENTITY_BYTES: usize = 32;
 pub const PENALTY_BYTES: usize = 32;
 pub const RK_LEN_BYTES: usize = 32;
+pub const POSITION_NONCE_LEN_BYTES: usize = 32;
+pub const DUTCH_AUCTION_NONCE_LEN_BYTES: usize = 32;
 pub const CLUE_LEN_BYTES: usize = 68;
+pub const ED25519_VK_LEN: usize = 32;
+pub const ED25519_SIGNATURE_LEN: usize = 64;
 
 pub const DETECTION_DATA_QTY: usize = 16;
 pub const ACTION_DATA_QTY: usize = 16;
+pub const FLOW_ASSETS_QTY: usize = 16;
+pub const BALANCE_ASSETS_QTY: usize = 16;
+pub const PRE_AUTHORIZATIONS_QTY: usize = 4;
+pub const FROST_MAX_SIGNERS: usize = 8;
+// identifier, hiding and binding commitments
+pub const FROST_COMMITMENTS_LEN: usize = 2 + 2 * KEY_LEN;
 pub const MAX_CLUE_SUBKEYS: usize = 10;
 pub const MAX_REWARDS: usize = 5;
 
 pub const EFFECT_HASH_LEN: usize = 64;
 pub const UI_ADDRESS_LEN: usize = 37;
+pub const ELLIPSIS: &str = "…";
+// Fractional digits shown for prices.
+pub const PRICE_DISPLAY_PRECISION: usize = 6;
+// Fractional digits shown for slashing penalties, in percent. A penalty is
+// expressed in bps^2, so six digits show it exactly.
+pub const PENALTY_DISPLAY_PRECISION: usize = 6;
 
 // Nonces:
 pub const NONCE_LEN: usize = 12;
@@ -1,5 +1,7 @@
 pub mod asset_id;
 pub mod bech32;
 pub mod c_api;
+pub mod custody;
+pub mod frost;
 mod keys;
 pub mod sign;
@@ -1,6 +1,14 @@
 use crate::constants::ID_LEN_BYTES;
 
+use crate::parser::amount::{Amount, AmountC};
+use crate::parser::delegation_token::DelegationToken;
+use crate::parser::denom_metadata::{DenomMetadata, DenomMetadataC};
 use crate::parser::id::AssetId;
+use crate::parser::identity_key::IdentityKeyC;
+use crate::parser::known_assets;
+use crate::parser::rate_data::{RateData, RateDataC};
+use crate::parser::unbonding_token::UnbondingToken;
+use crate::parser::validator_identity::ValidatorIdentity;
 use crate::parser::BytesC;
 use crate::ParserError;
 
@@ -43,3 +51,401 @@ pub unsafe extern "C" fn rs_get_asset_id_from_metadata(
 
     ParserError::Ok as u32
 }
+
+/// Looks an asset ID up in the asset registry, writing its display denom as
+/// a null-terminated string and its exponent.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Parameters
+///
+/// - `asset_id`: Pointer to the `ID_LEN_BYTES` asset ID.
+/// - `display_denom`: Mutable pointer to the output buffer for the display denom.
+/// - `display_denom_len`: Length of the output buffer.
+/// - `exponent`: The exponent of the display denom, only written on success.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code, `NoData` if the registry does
+/// not list the asset.
+#[no_mangle]
+pub unsafe extern "C" fn rs_known_asset(
+    asset_id: *const u8,
+    display_denom: *mut u8,
+    display_denom_len: u16,
+    exponent: &mut u8,
+) -> u32 {
+    if asset_id.is_null() || display_denom.is_null() {
+        return ParserError::NoData as u32;
+    }
+
+    let asset_id = &*asset_id.cast::<[u8; ID_LEN_BYTES]>();
+    let Some(asset) = known_assets::lookup(asset_id) else {
+        return ParserError::NoData as u32;
+    };
+
+    let denom = asset.display_denom.as_bytes();
+    if denom.len() >= display_denom_len as usize {
+        return ParserError::UnexpectedBufferEnd as u32;
+    }
+
+    let out = core::slice::from_raw_parts_mut(display_denom, display_denom_len as _);
+    out[..denom.len()].copy_from_slice(denom);
+    out[denom.len()] = 0;
+    *exponent = asset.exponent;
+
+    ParserError::Ok as u32
+}
+
+/// Parses an encoded `penumbra.core.asset.v1.Metadata` provided by the host,
+/// checking its base denom against the claimed asset ID.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Parameters
+///
+/// - `data`: Reference to `BytesC` containing the encoded metadata.
+/// - `out`: The parsed metadata, only written on success.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_parse_denom_metadata(data: &BytesC, out: &mut DenomMetadataC) -> u32 {
+    let Ok(data) = data.get_bytes() else {
+        return ParserError::InvalidMetadata as u32;
+    };
+
+    match DenomMetadata::parse(data) {
+        Ok(metadata) => out.fill(&metadata),
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+/// Parses an encoded `penumbra.core.component.stake.v1.RateData` provided by
+/// the host.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Parameters
+///
+/// - `data`: Reference to `BytesC` containing the encoded rate data.
+/// - `out`: The parsed rate data, only written on success.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_parse_rate_data(data: &BytesC, out: &mut RateDataC) -> u32 {
+    let Ok(data) = data.get_bytes() else {
+        return ParserError::InvalidExchangeRate as u32;
+    };
+
+    match RateData::parse(data) {
+        Ok(rate) => out.fill(&rate),
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+/// Computes the asset ID of the delegation token for the validator `ik`.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_delegation_token_asset_id(
+    ik: &IdentityKeyC,
+    out: *mut u8,
+    out_len: u16,
+) -> u32 {
+    if out_len as usize != ID_LEN_BYTES {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let out = core::slice::from_raw_parts_mut(out, out_len as _);
+    match ValidatorIdentity::try_from(ik.clone())
+        .and_then(|identity| DelegationToken::new(identity).asset_id())
+    {
+        Ok(id) => out.copy_from_slice(&id.to_bytes()),
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+/// Computes the asset ID of the unbonding token for the validator `ik` whose
+/// unbonding started at `start_height`.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_unbonding_token_asset_id(
+    ik: &IdentityKeyC,
+    start_height: u64,
+    out: *mut u8,
+    out_len: u16,
+) -> u32 {
+    if out_len as usize != ID_LEN_BYTES {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let out = core::slice::from_raw_parts_mut(out, out_len as _);
+    match ValidatorIdentity::try_from(ik.clone())
+        .and_then(|identity| UnbondingToken::new(identity, start_height).asset_id())
+    {
+        Ok(id) => out.copy_from_slice(&id.to_bytes()),
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+/// Renders a staking denom found in the transaction metadata as
+/// "delegation to <validator>" or "unbonding from <validator> since height N".
+///
+/// `written` is set to zero when `data` is not a delegation or unbonding denom.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_format_staking_denom(
+    data: &BytesC,
+    out: *mut u8,
+    out_len: u16,
+    written: *mut u16,
+) -> u32 {
+    if out.is_null() || written.is_null() {
+        return ParserError::NoData as u32;
+    }
+    *written = 0;
+
+    let out = core::slice::from_raw_parts_mut(out, out_len as _);
+    let Ok(denom) = data.get_bytes() else {
+        return ParserError::InvalidMetadata as u32;
+    };
+    let Ok(denom) = core::str::from_utf8(denom) else {
+        return ParserError::InvalidUtf8 as u32;
+    };
+
+    match format_staking_denom(denom, out) {
+        Ok(len) => *written = len as u16,
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+/// Writes `amount` as a null-terminated decimal string with `exponent`
+/// fractional digits, trailing fractional zeros trimmed.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_format_amount(
+    amount: &AmountC,
+    exponent: u8,
+    out: *mut u8,
+    out_len: u16,
+) -> u32 {
+    if out.is_null() || out_len == 0 {
+        return ParserError::NoData as u32;
+    }
+
+    let out = core::slice::from_raw_parts_mut(out, out_len as _);
+    let last = out.len() - 1;
+    match Amount::try_from(amount.clone())
+        .and_then(|amount| amount.format(exponent, &mut out[..last]))
+    {
+        Ok(len) => out[len] = 0,
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+fn format_staking_denom(denom: &str, out: &mut [u8]) -> Result<usize, ParserError> {
+    if denom.starts_with(DelegationToken::DENOM_PREFIX) {
+        DelegationToken::from_denom(denom)?.render(out)
+    } else if denom.starts_with(UnbondingToken::DENOM_PREFIX) {
+        UnbondingToken::from_denom(denom)?.render(out)
+    } else {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::VALIDATOR_IDENTITY_BYTES;
+
+    const IDENTITY: [u8; VALIDATOR_IDENTITY_BYTES] = [7u8; VALIDATOR_IDENTITY_BYTES];
+
+    #[test]
+    fn format_amount() {
+        let mut out = [0xffu8; 41];
+        let format = |lo: u64, hi: u64, exponent: u8, out: &mut [u8]| unsafe {
+            rs_format_amount(
+                &AmountC { lo, hi },
+                exponent,
+                out.as_mut_ptr(),
+                out.len() as u16,
+            )
+        };
+
+        assert_eq!(format(1_234_500, 0, 6, &mut out), ParserError::Ok as u32);
+        assert!(out.starts_with(b"1.2345\0"));
+        assert_eq!(format(4_000_000, 0, 6, &mut out), ParserError::Ok as u32);
+        assert!(out.starts_with(b"4\0"));
+        assert_eq!(format(0, 0, 6, &mut out), ParserError::Ok as u32);
+        assert!(out.starts_with(b"0\0"));
+
+        // The largest amount with the largest exponent still fits 41 bytes.
+        assert_eq!(
+            format(u64::MAX, u64::MAX, 38, &mut out),
+            ParserError::Ok as u32
+        );
+        assert!(out.starts_with(b"3.40282366920938463463374607431768211455\0"));
+        assert_eq!(format(1, 0, 38, &mut out), ParserError::Ok as u32);
+        assert!(out.starts_with(b"0.00000000000000000000000000000000000001\0"));
+
+        // too short for the terminator
+        assert_eq!(
+            format(1_234_500, 0, 6, &mut out[..6]),
+            ParserError::UnexpectedBufferEnd as u32
+        );
+    }
+
+    #[test]
+    fn known_asset_lookup() {
+        let mut denom = [0u8; 80];
+        let mut exponent = 0u8;
+
+        let staking_token = AssetId::new("upenumbra").unwrap().to_bytes();
+        let err = unsafe {
+            rs_known_asset(
+                staking_token.as_ptr(),
+                denom.as_mut_ptr(),
+                denom.len() as u16,
+                &mut exponent,
+            )
+        };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert!(denom.starts_with(b"penumbra\0"));
+        assert_eq!(exponent, 6);
+
+        // too short for the terminator
+        let err =
+            unsafe { rs_known_asset(staking_token.as_ptr(), denom.as_mut_ptr(), 8, &mut exponent) };
+        assert_eq!(err, ParserError::UnexpectedBufferEnd as u32);
+
+        let unknown = AssetId::new("unknown").unwrap().to_bytes();
+        let err = unsafe {
+            rs_known_asset(
+                unknown.as_ptr(),
+                denom.as_mut_ptr(),
+                denom.len() as u16,
+                &mut exponent,
+            )
+        };
+        assert_eq!(err, ParserError::NoData as u32);
+    }
+
+    #[test]
+    fn staking_denoms_roundtrip() {
+        let mut valid = [0u8; ValidatorIdentity::BECH32_LEN];
+        let len = ValidatorIdentity(IDENTITY).to_bech32m(&mut valid).unwrap();
+        let valid = core::str::from_utf8(&valid[..len]).unwrap();
+
+        let mut denom = [0u8; UnbondingToken::MAX_DENOM_LEN];
+        let mut out = [0u8; 128];
+
+        let token = DelegationToken::new(ValidatorIdentity(IDENTITY));
+        let len = token.denom(&mut denom).unwrap();
+        let parsed = DelegationToken::from_denom(core::str::from_utf8(&denom[..len]).unwrap());
+        assert_eq!(parsed.unwrap().validator_identity.0, IDENTITY);
+
+        let len = format_staking_denom(core::str::from_utf8(&denom[..len]).unwrap(), &mut out);
+        let rendered = core::str::from_utf8(&out[..len.unwrap()]).unwrap();
+        assert_eq!(rendered.strip_prefix("delegation to "), Some(valid));
+
+        let token = UnbondingToken::new(ValidatorIdentity(IDENTITY), 1_234_567);
+        let len = token.denom(&mut denom).unwrap();
+        let parsed =
+            UnbondingToken::from_denom(core::str::from_utf8(&denom[..len]).unwrap()).unwrap();
+        assert_eq!(parsed.validator_identity.0, IDENTITY);
+        assert_eq!(parsed.unbonding_start_height, 1_234_567);
+
+        let len = format_staking_denom(core::str::from_utf8(&denom[..len]).unwrap(), &mut out);
+        let rendered = core::str::from_utf8(&out[..len.unwrap()]).unwrap();
+        let rendered = rendered.strip_prefix("unbonding from ").unwrap();
+        assert_eq!(rendered.strip_suffix(" since height 1234567"), Some(valid));
+    }
+
+    #[test]
+    fn staking_denoms_rejected() {
+        let mut out = [0u8; 128];
+        assert_eq!(format_staking_denom("upenumbra", &mut out), Ok(0));
+
+        // wrong prefix, bad checksum, uppercase identities and non-canonical heights
+        let mut valid = [0u8; ValidatorIdentity::BECH32_LEN];
+        let len = ValidatorIdentity(IDENTITY).to_bech32m(&mut valid).unwrap();
+        let valid = core::str::from_utf8(&valid[..len]).unwrap();
+        let mut foreign = [0u8; ValidatorIdentity::BECH32_LEN];
+        let foreign_len =
+            crate::ffi::bech32::bech32_encode("penumbra", &IDENTITY, &mut foreign).unwrap();
+        let foreign = core::str::from_utf8(&foreign[..foreign_len]).unwrap();
+        let mut broken = [0u8; ValidatorIdentity::BECH32_LEN];
+        broken.copy_from_slice(valid.as_bytes());
+        broken[len - 1] = if broken[len - 1] == b'q' { b'p' } else { b'q' };
+        let broken = core::str::from_utf8(&broken).unwrap();
+        let mut upper = [0u8; ValidatorIdentity::BECH32_LEN];
+        upper.copy_from_slice(valid.as_bytes());
+        upper.make_ascii_uppercase();
+        let upper = core::str::from_utf8(&upper).unwrap();
+
+        let mut denom = [0u8; UnbondingToken::MAX_DENOM_LEN + 8];
+        for (prefix, identity) in [
+            ("udelegation_", foreign),
+            ("udelegation_", broken),
+            ("udelegation_", upper),
+            ("uunbonding_start_at_012_", valid),
+            ("uunbonding_start_at_+12_", valid),
+            ("uunbonding_start_at__", valid),
+        ] {
+            let len = prefix.len() + identity.len();
+            denom[..prefix.len()].copy_from_slice(prefix.as_bytes());
+            denom[prefix.len()..len].copy_from_slice(identity.as_bytes());
+            let denom = core::str::from_utf8(&denom[..len]).unwrap();
+            assert!(format_staking_denom(denom, &mut out).is_err());
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::address::Address;
+use crate::bolos::verify_ed25519;
+use crate::ffi::c_api::c_fvk_bytes;
+use crate::parser::authorize_request::{AuthorizeRequest, PreAuthorizationsC};
+use crate::parser::confirm_address::ConfirmAddressRequest;
+use crate::parser::BytesC;
+use crate::ParserError;
+
+/// Splits a custody `AuthorizeRequest` into the encoded plan and the keys of
+/// its co-signers, checking every pre-authorization signature over the plan.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Parameters
+///
+/// - `request`: Reference to `BytesC` containing the encoded request.
+/// - `plan`: Set to the encoded plan, which points into `request`.
+/// - `pre_authorizations`: The co-signer keys, only written on success.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_parse_authorize_request(
+    request: &BytesC,
+    plan: &mut BytesC,
+    pre_authorizations: &mut PreAuthorizationsC,
+) -> u32 {
+    crate::zlog("rs_parse_authorize_request\x00");
+
+    let Ok(request) = request.get_bytes() else {
+        return ParserError::NoData as u32;
+    };
+
+    let parsed = AuthorizeRequest::parse(request)
+        .and_then(|parsed| parsed.verify(verify_ed25519).map(|_| parsed));
+    match parsed {
+        Ok(parsed) => {
+            *plan = BytesC::from_slice(parsed.plan);
+            pre_authorizations.fill(&parsed);
+            ParserError::Ok as u32
+        }
+        Err(err) => err as u32,
+    }
+}
+
+/// Answers a custody `ConfirmAddressRequest`: derives the address at the
+/// requested index and encodes it as a `ConfirmAddressResponse`, together
+/// with the string to show on screen so both are known to match.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Parameters
+///
+/// - `request`: Reference to `BytesC` containing the encoded request.
+/// - `response`: Buffer for the encoded response; its length is written to `written`.
+/// - `ui_address`: Buffer for the null-terminated on-screen address.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_confirm_address(
+    request: &BytesC,
+    response: *mut u8,
+    response_len: u16,
+    written: &mut u16,
+    ui_address: *mut u8,
+    ui_address_len: u16,
+) -> u32 {
+    crate::zlog("rs_confirm_address\x00");
+    *written = 0;
+
+    if response.is_null()
+        || ui_address.is_null()
+        || (ui_address_len as usize) <= Address::SHORT_FORM_LEN
+    {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let Ok(request) = request.get_bytes() else {
+        return ParserError::NoData as u32;
+    };
+
+    let Ok(fvk) = c_fvk_bytes() else {
+        return ParserError::InvalidFvk as u32;
+    };
+
+    let confirmed =
+        match ConfirmAddressRequest::parse(request).and_then(|parsed| parsed.confirm(&fvk)) {
+            Ok(confirmed) => confirmed,
+            Err(err) => return err as u32,
+        };
+
+    let response = core::slice::from_raw_parts_mut(response, response_len as usize);
+    let ui_address = core::slice::from_raw_parts_mut(ui_address, ui_address_len as usize);
+    ui_address.fill(0);
+
+    let encoded = confirmed
+        .encode(response)
+        .and_then(|len| confirmed.ui_address(ui_address).map(|_| len));
+    match encoded {
+        Ok(len) => {
+            *written = len as u16;
+            ParserError::Ok as u32
+        }
+        Err(err) => err as u32,
+    }
+}
@@ -0,0 +1,348 @@
+use core::ptr::addr_of_mut;
+
+use crate::bolos::Trng;
+use crate::constants::{FROST_COMMITMENTS_LEN, KEY_LEN};
+use crate::frost::request::{DkgTranscript, SignRequest};
+use crate::frost::{NonceStore, SigningPackage};
+use crate::keys::spend_key::SpendKeyBytes;
+use crate::parser::BytesC;
+use crate::ParserError;
+
+// The nonces of the last round one, waiting for the request to sign with them.
+static mut NONCES: NonceStore = NonceStore::new();
+
+fn nonces() -> &'static mut NonceStore {
+    // The app runs a single thread and never holds two of these at once.
+    unsafe { &mut *addr_of_mut!(NONCES) }
+}
+
+/// Runs FROST round one for the participant of the key generation in
+/// `transcript`, keeping the nonces on the device. Any nonces kept before are
+/// dropped unused.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Parameters
+///
+/// - `spend_key`: The spend key the participant was seeded with.
+/// - `transcript`: Reference to `BytesC` containing the key generation transcript.
+/// - `commitments`: Buffer for the identifier, followed by the hiding and binding commitments.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_frost_commit(
+    spend_key: &SpendKeyBytes,
+    transcript: &BytesC,
+    commitments: *mut u8,
+    commitments_len: u16,
+) -> u32 {
+    crate::zlog("rs_frost_commit\x00");
+
+    if commitments.is_null() || (commitments_len as usize) < FROST_COMMITMENTS_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let Ok(transcript) = transcript.get_bytes() else {
+        return ParserError::NoData as u32;
+    };
+
+    let key_package = match DkgTranscript::parse(transcript) {
+        Ok(([], transcript)) => transcript.key_package(spend_key),
+        Ok(_) => Err(ParserError::UnexpectedData),
+        Err(err) => Err(err.into()),
+    };
+    match key_package {
+        Ok(key_package) => {
+            let committed = nonces().commit(&key_package.participant, &mut Trng);
+
+            let commitments = core::slice::from_raw_parts_mut(commitments, FROST_COMMITMENTS_LEN);
+            commitments[..2].copy_from_slice(&committed.identifier.index().to_le_bytes());
+            commitments[2..2 + KEY_LEN].copy_from_slice(&committed.hiding);
+            commitments[2 + KEY_LEN..].copy_from_slice(&committed.binding);
+
+            ParserError::Ok as u32
+        }
+        Err(err) => err as u32,
+    }
+}
+
+/// Checks a request for a signature share, pointing `message` at the message
+/// to review before signing it.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Parameters
+///
+/// - `request`: Reference to `BytesC` containing the encoded request.
+/// - `message`: Set to the message to sign, which points into `request`.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_frost_parse_sign_request(
+    request: &BytesC,
+    message: &mut BytesC,
+) -> u32 {
+    crate::zlog("rs_frost_parse_sign_request\x00");
+
+    let Ok(request) = request.get_bytes() else {
+        return ParserError::NoData as u32;
+    };
+
+    match SignRequest::parse(request) {
+        Ok(parsed) => {
+            *message = BytesC::from_slice(parsed.message);
+            ParserError::Ok as u32
+        }
+        Err(err) => err as u32,
+    }
+}
+
+/// Runs FROST round two, signing the request with the nonces of the last
+/// round one. The nonces are used up whatever the outcome.
+///
+/// # Safety
+///
+/// This function is `unsafe` because it dereferences raw pointers.
+///
+/// # Parameters
+///
+/// - `spend_key`: The spend key the participant was seeded with.
+/// - `request`: Reference to `BytesC` containing the encoded request.
+/// - `share`: Buffer for the signature share.
+///
+/// # Returns
+///
+/// A `u32` representing a `ParserError` code.
+#[no_mangle]
+pub unsafe extern "C" fn rs_frost_sign_share(
+    spend_key: &SpendKeyBytes,
+    request: &BytesC,
+    share: *mut u8,
+    share_len: u16,
+) -> u32 {
+    crate::zlog("rs_frost_sign_share\x00");
+
+    let taken = nonces().take();
+
+    if share.is_null() || (share_len as usize) < KEY_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let Ok(request) = request.get_bytes() else {
+        return ParserError::NoData as u32;
+    };
+
+    let signed = taken.and_then(|taken| {
+        let request = SignRequest::parse(request)?;
+        let key_package = request.transcript.key_package(spend_key)?;
+        let package =
+            SigningPackage::new(&request.commitments, request.message, request.randomizer)?;
+        key_package.participant.sign(taken, &package)
+    });
+    match signed {
+        Ok(signed) => {
+            let share = core::slice::from_raw_parts_mut(share, KEY_LEN);
+            share.copy_from_slice(&signed.share);
+            ParserError::Ok as u32
+        }
+        Err(err) => err as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frost::dkg::{
+        DkgParameters, DkgParticipant, Round1Package, Round2Guard, Round2Package,
+    };
+    use crate::frost::{Identifier, SignatureShare, SigningCommitments};
+    use decaf377::Fr;
+    use std::vec::Vec;
+
+    const CONTEXT: [u8; KEY_LEN] = [0x17; KEY_LEN];
+
+    fn encode_transcript(
+        parameters: DkgParameters,
+        identifier: u16,
+        round1: &[Round1Package],
+        round2: &[Round2Package],
+    ) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(&parameters.threshold.to_le_bytes());
+        out.extend_from_slice(&parameters.max_signers.to_le_bytes());
+        out.extend_from_slice(&identifier.to_le_bytes());
+        out.extend_from_slice(&CONTEXT);
+        for package in round1 {
+            out.extend_from_slice(&package.identifier.index().to_le_bytes());
+            for coefficient in &package.commitment {
+                out.extend_from_slice(coefficient);
+            }
+            out.extend_from_slice(&package.proof_r);
+            out.extend_from_slice(&package.proof_mu);
+            out.extend_from_slice(&package.nk_commitment);
+            out.extend_from_slice(&package.encryption_key);
+        }
+        for package in round2 {
+            out.extend_from_slice(&package.sender.index().to_le_bytes());
+            out.extend_from_slice(&package.receiver.index().to_le_bytes());
+            out.extend_from_slice(&package.ciphertext);
+        }
+        out
+    }
+
+    fn encode_sign_request(
+        transcript: &[u8],
+        randomizer: &[u8; KEY_LEN],
+        commitments: &[SigningCommitments],
+        message: &[u8],
+    ) -> Vec<u8> {
+        let mut out = transcript.to_vec();
+        out.extend_from_slice(randomizer);
+        out.push(commitments.len() as u8);
+        for signer in commitments {
+            out.extend_from_slice(&signer.identifier.index().to_le_bytes());
+            out.extend_from_slice(&signer.hiding);
+            out.extend_from_slice(&signer.binding);
+        }
+        out.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        out.extend_from_slice(message);
+        out
+    }
+
+    fn bytes(data: &[u8]) -> BytesC {
+        BytesC::from_slice(data)
+    }
+
+    #[test]
+    fn test_frost_sign_share() {
+        let parameters = DkgParameters::new(2, 3).unwrap();
+        let seeds: Vec<SpendKeyBytes> = (1..=3).map(|i| SpendKeyBytes::from([i; 32])).collect();
+        let participants: Vec<DkgParticipant> = seeds
+            .iter()
+            .zip(1..)
+            .map(|(seed, index)| {
+                DkgParticipant::new(seed, Identifier::new(index).unwrap(), parameters, CONTEXT)
+                    .unwrap()
+            })
+            .collect();
+        let round1: Vec<Round1Package> = participants.iter().map(|p| p.round1().unwrap()).collect();
+        let round2: Vec<Round2Package> = participants
+            .iter()
+            .flat_map(|p| p.round2(&mut Round2Guard::default(), &round1).unwrap())
+            .collect();
+        let transcripts: Vec<Vec<u8>> = (1..=3u16)
+            .map(|index| {
+                let received: Vec<Round2Package> = round2
+                    .iter()
+                    .filter(|package| package.receiver.index() == index)
+                    .copied()
+                    .collect();
+                encode_transcript(parameters, index, &round1, &received)
+            })
+            .collect();
+
+        // the device is participant 1, participant 3 signs with it
+        let device = &seeds[0];
+        let cosigner = DkgTranscript::parse(&transcripts[2])
+            .unwrap()
+            .1
+            .key_package(&seeds[2])
+            .unwrap();
+
+        let mut out = [0u8; FROST_COMMITMENTS_LEN];
+        let err = unsafe {
+            rs_frost_commit(
+                device,
+                &bytes(&transcripts[0]),
+                out.as_mut_ptr(),
+                out.len() as u16,
+            )
+        };
+        assert_eq!(err, ParserError::Ok as u32);
+        let device_commitments = SigningCommitments {
+            identifier: Identifier::new(u16::from_le_bytes([out[0], out[1]])).unwrap(),
+            hiding: out[2..2 + KEY_LEN].try_into().unwrap(),
+            binding: out[2 + KEY_LEN..].try_into().unwrap(),
+        };
+        assert_eq!(device_commitments.identifier.index(), 1);
+
+        let mut store = NonceStore::default();
+        let cosigner_commitments = store.commit(&cosigner.participant, &mut Trng);
+        let commitments = [device_commitments, cosigner_commitments];
+
+        let randomizer = [0x05; KEY_LEN];
+        let message = b"effect hash";
+        let request = encode_sign_request(&transcripts[0], &randomizer, &commitments, message);
+
+        let mut shown = bytes(&[]);
+        let err = unsafe { rs_frost_parse_sign_request(&bytes(&request), &mut shown) };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert_eq!(<&[u8]>::from(&shown), message);
+
+        let mut share = [0u8; KEY_LEN];
+        let err = unsafe {
+            rs_frost_sign_share(
+                device,
+                &bytes(&request),
+                share.as_mut_ptr(),
+                share.len() as u16,
+            )
+        };
+        assert_eq!(err, ParserError::Ok as u32);
+
+        // the nonces are gone
+        let mut again = [0u8; KEY_LEN];
+        let err = unsafe {
+            rs_frost_sign_share(
+                device,
+                &bytes(&request),
+                again.as_mut_ptr(),
+                again.len() as u16,
+            )
+        };
+        assert_eq!(err, ParserError::NoData as u32);
+
+        let randomizer = Fr::from_le_bytes_mod_order(&randomizer);
+        let package = SigningPackage::new(&commitments, message, randomizer).unwrap();
+        let shares = [
+            SignatureShare {
+                identifier: device_commitments.identifier,
+                share,
+            },
+            cosigner
+                .participant
+                .sign(store.take().unwrap(), &package)
+                .unwrap(),
+        ];
+        let signature = package.aggregate(&shares, &cosigner.group_key).unwrap();
+        assert!(cosigner
+            .group_key
+            .randomize(&randomizer)
+            .verify(message, &signature)
+            .is_ok());
+
+        // a request with bytes past the message
+        let mut trailing = request.clone();
+        trailing.push(0);
+        let err = unsafe { rs_frost_parse_sign_request(&bytes(&trailing), &mut shown) };
+        assert_eq!(err, ParserError::UnexpectedData as u32);
+
+        // a signing set below the threshold
+        let alone = encode_sign_request(
+            &transcripts[0],
+            &[0x05; KEY_LEN],
+            &commitments[..1],
+            message,
+        );
+        let err = unsafe { rs_frost_parse_sign_request(&bytes(&alone), &mut shown) };
+        assert_eq!(err, ParserError::UnexpectedNumberItems as u32);
+    }
+}
@@ -1,9 +1,17 @@
+use crate::address::ownership_proof::AddressOwnershipProof;
 use crate::address::{Address, AddressIndex};
-use crate::constants::KEY_LEN;
+use crate::constants::{
+    ADDR_INDEX_LEN, ENCODED_FVK_RESPONSE_LEN, ENCRYPTED_FVK_LEN, FVK_LEN,
+    FVK_RECIPIENT_FINGERPRINT_LEN, KEY_LEN, NONCE_LEN,
+};
 use crate::ffi::c_api::c_fvk_bytes;
+use crate::keys::ka;
 use crate::keys::spend_key::SpendKeyBytes;
 use crate::parser::bytes::BytesC;
+use crate::parser::export_fvk::ExportFullViewingKeyResponse;
+use crate::parser::symmetric::FvkExportKey;
 use crate::ParserError;
+use rand::{CryptoRng, RngCore};
 
 #[repr(C)]
 pub struct Keys {
@@ -29,6 +37,75 @@ pub unsafe extern "C" fn rs_compute_keys(keys: &mut Keys) -> u32 {
     ParserError::Ok as u32
 }
 
+#[no_mangle]
+/// Encrypt the full viewing key held in `keys` to the public key supplied
+/// by the host, writing the ephemeral public key and the nonce followed by
+/// the ciphertext into output argument.
+pub unsafe extern "C" fn rs_encrypt_fvk(
+    keys: &Keys,
+    host_pk: *const u8,
+    output: *mut u8,
+    output_len: usize,
+) -> u32 {
+    crate::zlog("rs_encrypt_fvk\x00");
+
+    if host_pk.is_null() || output.is_null() || output_len < ENCRYPTED_FVK_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let host_pk = ka::Public(*(host_pk as *const [u8; KEY_LEN]));
+    let output = core::slice::from_raw_parts_mut(output, ENCRYPTED_FVK_LEN);
+
+    if let Err(code) = encrypt_fvk(&keys.fvk, &host_pk, &mut crate::Trng, output) {
+        return code as u32;
+    }
+
+    ParserError::Ok as u32
+}
+
+#[no_mangle]
+/// Writes the fingerprint of the host public key an encrypted export is
+/// addressed to, for the user to confirm on screen.
+pub unsafe extern "C" fn rs_fvk_recipient_fingerprint(
+    host_pk: *const u8,
+    output: *mut u8,
+    output_len: usize,
+) -> u32 {
+    if host_pk.is_null() || output.is_null() || output_len < FVK_RECIPIENT_FINGERPRINT_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let host_pk = ka::Public(*(host_pk as *const [u8; KEY_LEN]));
+    let output = core::slice::from_raw_parts_mut(output, FVK_RECIPIENT_FINGERPRINT_LEN);
+    output.copy_from_slice(&FvkExportKey::recipient_fingerprint(&host_pk));
+
+    ParserError::Ok as u32
+}
+
+#[no_mangle]
+/// Encode the full viewing key held in `keys` as a custody
+/// `ExportFullViewingKeyResponse` and write it into output argument.
+pub unsafe extern "C" fn rs_encode_fvk_response(
+    keys: &Keys,
+    output: *mut u8,
+    output_len: usize,
+) -> u32 {
+    crate::zlog("rs_encode_fvk_response\x00");
+
+    if output.is_null() || output_len < ENCODED_FVK_RESPONSE_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let output = core::slice::from_raw_parts_mut(output, ENCODED_FVK_RESPONSE_LEN);
+    let response = ExportFullViewingKeyResponse { fvk: &keys.fvk };
+
+    if let Err(code) = response.encode(output) {
+        return code as u32;
+    }
+
+    ParserError::Ok as u32
+}
+
 #[no_mangle]
 /// Use to compute an address and write it back into output
 /// argument.
@@ -52,6 +129,79 @@ pub unsafe extern "C" fn rs_compute_address(
     ParserError::Ok as u32
 }
 
+#[no_mangle]
+/// Computes a one-time address for `account` under a randomizer drawn from
+/// the device's TRNG, writing the address into `keys` and its full index,
+/// account and randomizer, into `address_index`.
+///
+/// # Safety
+/// This function is unsafe because depends on passed raw pointers from C
+pub unsafe extern "C" fn rs_compute_ephemeral_address(
+    keys: &mut Keys,
+    account: u32,
+    address_index: *mut u8,
+) -> u32 {
+    crate::zlog("rs_compute_ephemeral_address\x00");
+
+    if address_index.is_null() {
+        return ParserError::NoData as u32;
+    }
+
+    let addr_idx = AddressIndex::ephemeral(account, &mut crate::Trng);
+
+    if let Err(code) = compute_address(keys, addr_idx) {
+        return code as u32;
+    }
+
+    let address_index = core::slice::from_raw_parts_mut(address_index, ADDR_INDEX_LEN);
+    address_index.copy_from_slice(&addr_idx.to_bytes());
+
+    ParserError::Ok as u32
+}
+
+#[no_mangle]
+/// Proves ownership of the address at the given index, answering the
+/// auditor's `challenge`, and writes the address followed by its index and
+/// the proof into `output`. The proof discloses `ak` but no secret key.
+///
+/// # Safety
+/// This function is unsafe because depends on passed raw pointers from C
+pub unsafe extern "C" fn rs_prove_address_ownership(
+    keys: &Keys,
+    account: u32,
+    randomizer: *const u8,
+    challenge: &BytesC,
+    output: *mut u8,
+    output_len: u16,
+) -> u32 {
+    crate::zlog("rs_prove_address_ownership\x00");
+
+    if output.is_null() || (output_len as usize) < AddressOwnershipProof::LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let mut addr_idx = AddressIndex::new(account);
+    if !randomizer.is_null() {
+        let randomizer = core::slice::from_raw_parts(randomizer, AddressIndex::RAND_LEN);
+        addr_idx.randomizer.copy_from_slice(randomizer);
+    }
+
+    let Ok(challenge) = challenge.get_bytes() else {
+        return ParserError::NoData as u32;
+    };
+
+    let spend_key = SpendKeyBytes::from(keys.skb);
+    match AddressOwnershipProof::prove(&spend_key, addr_idx, challenge) {
+        Ok(proof) => {
+            let output = core::slice::from_raw_parts_mut(output, AddressOwnershipProof::LEN);
+            output.copy_from_slice(&proof.to_bytes());
+
+            ParserError::Ok as u32
+        }
+        Err(e) => e as u32,
+    }
+}
+
 #[no_mangle]
 /// Use to compute an address and write it back into output
 /// argument.
@@ -115,6 +265,28 @@ fn compute_keys(keys: &mut Keys) -> Result<(), ParserError> {
     Ok(())
 }
 
+fn encrypt_fvk<R: RngCore + CryptoRng>(
+    fvk: &[u8; FVK_LEN],
+    host_pk: &ka::Public,
+    rng: &mut R,
+    output: &mut [u8],
+) -> Result<(), ParserError> {
+    // a fresh ephemeral secret and nonce for each export
+    let esk = ka::Secret::new(rng);
+    let epk = esk.public();
+    let shared_secret = esk.key_agreement_with(host_pk)?;
+    let key = FvkExportKey::derive(&shared_secret, &epk, host_pk);
+
+    let mut nonce = [0u8; NONCE_LEN];
+    rng.fill_bytes(&mut nonce);
+
+    let (header, ciphertext) = output.split_at_mut(KEY_LEN + NONCE_LEN);
+    header[..KEY_LEN].copy_from_slice(&epk.0);
+    header[KEY_LEN..].copy_from_slice(&nonce);
+    ciphertext[..FVK_LEN].copy_from_slice(fvk);
+    key.encrypt(&nonce, ciphertext)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -159,6 +331,71 @@ mod test {
         assert_eq!(keys.address, expected_addr.as_slice());
     }
 
+    #[test]
+    fn ephemeral_addresses() {
+        let key_bytes = hex::decode(SPEND_KEY).unwrap();
+
+        let mut keys = Keys {
+            skb: [0; SpendKeyBytes::LEN],
+            fvk: [0; KEY_LEN * 2],
+            address: [0; Address::LEN],
+        };
+        keys.skb.copy_from_slice(&key_bytes);
+
+        let mut index = [0u8; ADDR_INDEX_LEN];
+        let code =
+            unsafe { rs_compute_ephemeral_address(&mut keys, ACCOUNT_IDX, index.as_mut_ptr()) };
+        assert_eq!(code, ParserError::Ok as u32);
+
+        let addr_idx = AddressIndex::try_from(index.as_slice()).unwrap();
+        assert_eq!(addr_idx.account, ACCOUNT_IDX);
+        assert!(addr_idx.is_ephemeral());
+
+        // the returned index is enough for the host to recognize the address
+        let fvk = SpendKeyBytes::from(keys.skb).fvk().unwrap();
+        let address = Address::try_from(keys.address.as_slice()).unwrap();
+        assert_eq!(fvk.address_index(&address), Some(addr_idx));
+
+        let first = keys.address;
+        let mut other = [0u8; ADDR_INDEX_LEN];
+        let code =
+            unsafe { rs_compute_ephemeral_address(&mut keys, ACCOUNT_IDX, other.as_mut_ptr()) };
+        assert_eq!(code, ParserError::Ok as u32);
+        assert_ne!(other, index);
+        assert_ne!(keys.address, first);
+    }
+
+    #[test]
+    fn ephemeral_index_is_never_the_default() {
+        struct Draws(u8);
+
+        impl RngCore for Draws {
+            fn next_u32(&mut self) -> u32 {
+                let mut bytes = [0u8; 4];
+                self.fill_bytes(&mut bytes);
+                u32::from_le_bytes(bytes)
+            }
+            fn next_u64(&mut self) -> u64 {
+                let mut bytes = [0u8; 8];
+                self.fill_bytes(&mut bytes);
+                u64::from_le_bytes(bytes)
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                // an all-zero draw first, then a usable one
+                dest.fill(self.0);
+                self.0 += 1;
+            }
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+        impl CryptoRng for Draws {}
+
+        let index = AddressIndex::ephemeral(ACCOUNT_IDX, &mut Draws(0));
+        assert_eq!(index.randomizer, [1u8; AddressIndex::RAND_LEN]);
+    }
+
     #[test]
     fn verify_fvk() {
         let key_bytes = hex::decode(SPEND_KEY).unwrap();
@@ -215,4 +452,70 @@ mod test {
 
         assert_eq!(s, s_2);
     }
+
+    #[test]
+    fn encrypt_fvk_roundtrip() {
+        use crate::parser::symmetric::FvkExportKey;
+        use chacha20poly1305::aead::{AeadInPlace, Nonce};
+        use chacha20poly1305::{ChaCha20Poly1305, KeyInit};
+
+        let key_bytes = hex::decode(SPEND_KEY).unwrap();
+        let mut keys = Keys {
+            skb: [0; SpendKeyBytes::LEN],
+            fvk: [0; KEY_LEN * 2],
+            address: [0; Address::LEN],
+        };
+        keys.skb.copy_from_slice(&key_bytes);
+        compute_keys(&mut keys).unwrap();
+
+        let host_sk = ka::Secret::new(&mut crate::Trng);
+        let host_pk = host_sk.public();
+
+        let mut output = [0u8; ENCRYPTED_FVK_LEN];
+        encrypt_fvk(&keys.fvk, &host_pk, &mut crate::Trng, &mut output).unwrap();
+        let (header, ciphertext) = output.split_at(KEY_LEN + NONCE_LEN);
+        assert_ne!(&ciphertext[..FVK_LEN], &keys.fvk[..]);
+
+        // the host side: recover the key from the ephemeral public key
+        let epk = ka::Public::try_from(&header[..KEY_LEN]).unwrap();
+        let shared_secret = host_sk.key_agreement_with(&epk).unwrap();
+        let key = FvkExportKey::derive(&shared_secret, &epk, &host_pk);
+
+        let cipher = ChaCha20Poly1305::new(&key.0);
+        let nonce = Nonce::<ChaCha20Poly1305>::from_slice(&header[KEY_LEN..]);
+        let tag = &ciphertext[FVK_LEN..];
+
+        let mut plaintext = [0u8; FVK_LEN];
+        plaintext.copy_from_slice(&ciphertext[..FVK_LEN]);
+        cipher
+            .decrypt_in_place_detached(nonce, &[], &mut plaintext, tag.into())
+            .unwrap();
+        assert_eq!(plaintext, keys.fvk);
+
+        // a tampered ciphertext is rejected
+        plaintext.copy_from_slice(&ciphertext[..FVK_LEN]);
+        plaintext[0] ^= 1;
+        assert!(cipher
+            .decrypt_in_place_detached(nonce, &[], &mut plaintext, tag.into())
+            .is_err());
+
+        // every export draws its own nonce
+        let mut again = [0u8; ENCRYPTED_FVK_LEN];
+        encrypt_fvk(&keys.fvk, &host_pk, &mut crate::Trng, &mut again).unwrap();
+        assert_ne!(&again[KEY_LEN..KEY_LEN + NONCE_LEN], &header[KEY_LEN..]);
+
+        // the fingerprint shown on screen tells host keys apart
+        let mut fingerprint = [0u8; FVK_RECIPIENT_FINGERPRINT_LEN];
+        let code = unsafe {
+            rs_fvk_recipient_fingerprint(
+                host_pk.0.as_ptr(),
+                fingerprint.as_mut_ptr(),
+                fingerprint.len(),
+            )
+        };
+        assert_eq!(code, ParserError::Ok as u32);
+        assert_eq!(fingerprint, FvkExportKey::recipient_fingerprint(&host_pk));
+        let other = ka::Secret::new(&mut crate::Trng).public();
+        assert_ne!(fingerprint, FvkExportKey::recipient_fingerprint(&other));
+    }
 }
@@ -2,7 +2,16 @@ use decaf377::Fr;
 use decaf377_rdsa::{Signature, SigningKey, SpendAuth};
 
 use crate::{
-    constants::SIGNATURE_LEN, keys::spend_key::SpendKeyBytes, parser::BytesC, ParserError,
+    constants::{EFFECT_HASH_LEN, SIGNATURE_LEN},
+    keys::spend_key::SpendKeyBytes,
+    parser::{
+        authorization_data::AuthorizationData,
+        binding::BindingC,
+        fee::{Fee, FeeC},
+        message_signature::MessageSignature,
+        BytesC,
+    },
+    ParserError,
 };
 
 /// # Safety
@@ -30,6 +39,123 @@ pub unsafe extern "C" fn rs_sign_spend(
     }
 }
 
+/// # Safety
+/// This function is unsafe because depends on passed raw pointers from C
+#[no_mangle]
+pub unsafe extern "C" fn rs_sign_binding(
+    effect_hash: &BytesC,
+    binding: &BindingC,
+    fee: &FeeC,
+    signature: *mut u8,
+    len: u16,
+) -> u32 {
+    if len < SIGNATURE_LEN as u16 {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let signed = Fee::try_from(fee.clone()).and_then(|fee| binding.sign(&fee, effect_hash.into()));
+    match signed {
+        Ok(sig) => {
+            let signature = core::slice::from_raw_parts_mut(signature, len as usize);
+            signature[..SIGNATURE_LEN].copy_from_slice(sig.to_bytes().as_ref());
+
+            ParserError::Ok as u32
+        }
+        Err(e) => e as u32,
+    }
+}
+
+/// Encodes the effect hash and the signatures produced by signing as an
+/// `AuthorizationData` protobuf. Each signature list is `qty` consecutive
+/// 64-byte signatures, and the encoded length is written to `written`.
+///
+/// An empty `effect_hash` encodes a later part of the message, made of the
+/// given signatures only.
+///
+/// # Safety
+/// This function is unsafe because depends on passed raw pointers from C
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn rs_encode_authorization_data(
+    effect_hash: &BytesC,
+    spend_auths: *const u8,
+    spend_auths_qty: u16,
+    delegator_vote_auths: *const u8,
+    delegator_vote_auths_qty: u16,
+    lqt_vote_auths: *const u8,
+    lqt_vote_auths_qty: u16,
+    output: *mut u8,
+    output_len: u16,
+    written: &mut u16,
+) -> u32 {
+    *written = 0;
+
+    let effect_hash: &[u8] = effect_hash.into();
+    let effect_hash = match effect_hash.len() {
+        0 => None,
+        _ => match <&[u8; EFFECT_HASH_LEN]>::try_from(effect_hash) {
+            Ok(effect_hash) => Some(effect_hash),
+            Err(_) => return ParserError::InvalidLength as u32,
+        },
+    };
+
+    let data = AuthorizationData {
+        effect_hash,
+        spend_auths: signatures(spend_auths, spend_auths_qty),
+        delegator_vote_auths: signatures(delegator_vote_auths, delegator_vote_auths_qty),
+        lqt_vote_auths: signatures(lqt_vote_auths, lqt_vote_auths_qty),
+    };
+
+    let output = core::slice::from_raw_parts_mut(output, output_len as usize);
+    match data.encode(output) {
+        Ok(len) => {
+            *written = len as u16;
+            ParserError::Ok as u32
+        }
+        Err(e) => e as u32,
+    }
+}
+
+/// Signs an arbitrary UTF-8 message with the spend key randomized by
+/// `randomizer`, writing the signature followed by the randomized
+/// verification key `rk` into `output`.
+///
+/// # Safety
+/// This function is unsafe because depends on passed raw pointers from C
+#[no_mangle]
+pub unsafe extern "C" fn rs_sign_message(
+    message: &BytesC,
+    randomizer: &BytesC,
+    spend_key: &SpendKeyBytes,
+    output: *mut u8,
+    output_len: u16,
+) -> u32 {
+    if output.is_null() || (output_len as usize) < MessageSignature::LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let Ok(message) = message.get_bytes() else {
+        return ParserError::NoData as u32;
+    };
+
+    match sign_message(message, randomizer, spend_key) {
+        Ok(signed) => {
+            let output = core::slice::from_raw_parts_mut(output, MessageSignature::LEN);
+            output.copy_from_slice(&signed.to_bytes());
+
+            ParserError::Ok as u32
+        }
+        Err(e) => e as u32,
+    }
+}
+
+unsafe fn signatures<'a>(ptr: *const u8, qty: u16) -> &'a [[u8; SIGNATURE_LEN]] {
+    if ptr.is_null() || qty == 0 {
+        return &[];
+    }
+    core::slice::from_raw_parts(ptr.cast::<[u8; SIGNATURE_LEN]>(), qty as usize)
+}
+
 pub fn randomized_signing_key(
     spend_key: &SpendKeyBytes,
     randomizer: &BytesC,
@@ -43,6 +169,16 @@ pub fn randomized_signing_key(
     Ok(sk.randomize(&randomizer))
 }
 
+pub fn sign_message(
+    message: &[u8],
+    randomizer: &BytesC,
+    spend_key: &SpendKeyBytes,
+) -> Result<MessageSignature, ParserError> {
+    let rsk = randomized_signing_key(spend_key, randomizer)?;
+
+    MessageSignature::sign(&rsk, message)
+}
+
 pub fn sign_spend(
     effect_hash: &BytesC,
     randomizer: &BytesC,
@@ -0,0 +1,608 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+//! FROST threshold signing over decaf377, producing `SpendAuth` signatures.
+//!
+//! This follows the two round protocol of RFC 9591. The signatures are
+//! re-randomized the way spend authorizations are: every participant adds
+//! the spend randomizer to its share, so the shares of a signing set sum up
+//! to the randomized group key, and the result verifies against `rk`.
+
+use crate::constants::{FROST_MAX_SIGNERS, KEY_LEN, SIGNATURE_LEN};
+use crate::keys::signing_key::Sk;
+use crate::ParserError;
+use arrayvec::ArrayVec;
+use decaf377::{Element, Encoding, Fr};
+use decaf377_rdsa::{Signature, SpendAuth, VerificationKey};
+use rand::{CryptoRng, RngCore};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+pub mod dkg;
+pub mod request;
+
+// Personalizations of the hashes H1, H3, H4 and H5 of RFC 9591. The challenge,
+// H2, is the decaf377-rdsa one so that the signature verifies as usual.
+const RHO_PERSONALIZATION: &[u8; 16] = b"FROST_dcf377_rho";
+const NONCE_PERSONALIZATION: &[u8; 16] = b"FROST_dcf377_non";
+const MESSAGE_PERSONALIZATION: &[u8; 16] = b"FROST_dcf377_msg";
+const COMMITMENT_PERSONALIZATION: &[u8; 16] = b"FROST_dcf377_com";
+const CHALLENGE_PERSONALIZATION: &[u8; 16] = b"decaf377-rdsa---";
+
+fn hash(personalization: &[u8; 16], parts: &[&[u8]]) -> [u8; 64] {
+    let mut state = blake2b_simd::Params::new()
+        .hash_length(64)
+        .personal(personalization)
+        .to_state();
+    for part in parts {
+        state.update(part);
+    }
+    *state.finalize().as_array()
+}
+
+fn hash_to_scalar(personalization: &[u8; 16], parts: &[&[u8]]) -> Fr {
+    Fr::from_le_bytes_mod_order(&hash(personalization, parts))
+}
+
+fn decompress(bytes: &[u8; KEY_LEN]) -> Result<Element, ParserError> {
+    Encoding(*bytes)
+        .vartime_decompress()
+        .map_err(|_| ParserError::InvalidPubkeyEncoding)
+}
+
+/// A participant's index in the group, the point its share is evaluated at.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct Identifier(u16);
+
+impl Identifier {
+    pub fn new(index: u16) -> Result<Self, ParserError> {
+        if index == 0 {
+            return Err(ParserError::ValueOutOfRange);
+        }
+        Ok(Self(index))
+    }
+
+    pub fn to_scalar(self) -> Fr {
+        Fr::from(self.0)
+    }
+
+    pub fn index(self) -> u16 {
+        self.0
+    }
+}
+
+/// A participant's share of the group's spend authorization key. It is
+/// neither `Copy` nor `Clone`, and gets zeroized on drop.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct SigningShare(Fr);
+
+impl SigningShare {
+    pub fn new(share: Fr) -> Self {
+        Self(share)
+    }
+
+    /// The share's part of the verification key, which others check its
+    /// signature shares against.
+    pub fn verifying_share(&self) -> VerificationKey<SpendAuth> {
+        Sk::from_field(self.0).verification_key()
+    }
+
+    /// The share randomized for a spend, the same way `randomized_signing_key`
+    /// randomizes a whole spend key.
+    fn randomized(&self, randomizer: &Fr) -> Fr {
+        let mut bytes = Sk::from_field(self.0).randomize(randomizer).to_bytes();
+        let share = Fr::from_le_bytes_mod_order(&bytes);
+        bytes.zeroize();
+        share
+    }
+}
+
+/// The public commitments to a participant's round one nonces.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct SigningCommitments {
+    pub identifier: Identifier,
+    pub hiding: [u8; KEY_LEN],
+    pub binding: [u8; KEY_LEN],
+}
+
+impl SigningCommitments {
+    fn elements(&self) -> Result<(Element, Element), ParserError> {
+        let hiding = decompress(&self.hiding)?;
+        let binding = decompress(&self.binding)?;
+        if hiding == Element::IDENTITY || binding == Element::IDENTITY {
+            return Err(ParserError::InvalidPubkeyEncoding);
+        }
+        Ok((hiding, binding))
+    }
+}
+
+/// The secret nonces of round one. They are neither `Copy` nor `Clone`, and
+/// signing consumes them, so a pair of nonces signs at most one message.
+#[derive(Zeroize, ZeroizeOnDrop)]
+pub struct SigningNonces {
+    hiding: Fr,
+    binding: Fr,
+    #[zeroize(skip)]
+    commitments: SigningCommitments,
+}
+
+impl SigningNonces {
+    pub fn commitments(&self) -> &SigningCommitments {
+        &self.commitments
+    }
+}
+
+/// Holds the nonces between the two rounds. Taking them always empties the
+/// store, whatever the outcome, so nonces can never be handed out twice.
+#[derive(Default)]
+pub struct NonceStore {
+    nonces: Option<SigningNonces>,
+}
+
+impl NonceStore {
+    pub const fn new() -> Self {
+        Self { nonces: None }
+    }
+
+    /// Runs round one, replacing (and thereby dropping) any unused nonces.
+    pub fn commit<R: RngCore + CryptoRng>(
+        &mut self,
+        participant: &Participant,
+        rng: &mut R,
+    ) -> SigningCommitments {
+        let nonces = participant.commit(rng);
+        let commitments = nonces.commitments;
+        self.nonces = Some(nonces);
+        commitments
+    }
+
+    pub fn take(&mut self) -> Result<SigningNonces, ParserError> {
+        self.nonces.take().ok_or(ParserError::NoData)
+    }
+}
+
+/// A share of the signature by one participant.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct SignatureShare {
+    pub identifier: Identifier,
+    pub share: [u8; KEY_LEN],
+}
+
+/// What every participant signs in round two: the message, the spend
+/// randomizer, and the commitments of the signing set, ordered by identifier.
+pub struct SigningPackage<'a> {
+    commitments: &'a [SigningCommitments],
+    message: &'a [u8],
+    randomizer: Fr,
+}
+
+impl<'a> SigningPackage<'a> {
+    pub fn new(
+        commitments: &'a [SigningCommitments],
+        message: &'a [u8],
+        randomizer: Fr,
+    ) -> Result<Self, ParserError> {
+        if commitments.is_empty() || commitments.len() > FROST_MAX_SIGNERS {
+            return Err(ParserError::UnexpectedNumberItems);
+        }
+        // strictly increasing, which also rules out a signer appearing twice
+        if commitments
+            .windows(2)
+            .any(|pair| pair[0].identifier >= pair[1].identifier)
+        {
+            return Err(ParserError::DuplicatedField);
+        }
+
+        Ok(Self {
+            commitments,
+            message,
+            randomizer,
+        })
+    }
+
+    pub fn randomized_group_key(
+        &self,
+        group_key: &VerificationKey<SpendAuth>,
+    ) -> VerificationKey<SpendAuth> {
+        group_key.randomize(&self.randomizer)
+    }
+
+    fn commitment(&self, identifier: Identifier) -> Result<&SigningCommitments, ParserError> {
+        self.commitments
+            .iter()
+            .find(|commitments| commitments.identifier == identifier)
+            .ok_or(ParserError::MissingField)
+    }
+
+    /// The binding factor of every signer, which ties its nonces to this
+    /// exact message, key and signing set.
+    fn binding_factors(&self, rk: &VerificationKey<SpendAuth>) -> ArrayVec<Fr, FROST_MAX_SIGNERS> {
+        let message = hash(MESSAGE_PERSONALIZATION, &[self.message]);
+
+        let mut state = blake2b_simd::Params::new()
+            .hash_length(64)
+            .personal(COMMITMENT_PERSONALIZATION)
+            .to_state();
+        for commitments in self.commitments {
+            state.update(&commitments.identifier.to_scalar().to_bytes());
+            state.update(&commitments.hiding);
+            state.update(&commitments.binding);
+        }
+        let commitments = *state.finalize().as_array();
+
+        let rk = rk.to_bytes();
+        self.commitments
+            .iter()
+            .map(|signer| {
+                hash_to_scalar(
+                    RHO_PERSONALIZATION,
+                    &[
+                        &rk,
+                        &message,
+                        &commitments,
+                        &signer.identifier.to_scalar().to_bytes(),
+                    ],
+                )
+            })
+            .collect()
+    }
+
+    fn group_commitment(&self, binding_factors: &[Fr]) -> Result<Element, ParserError> {
+        let mut group_commitment = Element::IDENTITY;
+        for (commitments, rho) in self.commitments.iter().zip(binding_factors) {
+            let (hiding, binding) = commitments.elements()?;
+            group_commitment += hiding + binding * rho;
+        }
+        Ok(group_commitment)
+    }
+
+    fn challenge(&self, group_commitment: &[u8; KEY_LEN], rk: &VerificationKey<SpendAuth>) -> Fr {
+        hash_to_scalar(
+            CHALLENGE_PERSONALIZATION,
+            &[group_commitment, &rk.to_bytes(), self.message],
+        )
+    }
+
+    fn lagrange_coefficient(&self, identifier: Identifier) -> Result<Fr, ParserError> {
+        let x = identifier.to_scalar();
+        let mut numerator = Fr::ONE;
+        let mut denominator = Fr::ONE;
+        for other in self.commitments.iter().map(|c| c.identifier) {
+            if other == identifier {
+                continue;
+            }
+            let x_j = other.to_scalar();
+            numerator *= x_j;
+            denominator *= x_j - x;
+        }
+        Ok(numerator * denominator.inverse().ok_or(ParserError::DivisionByZero)?)
+    }
+
+    /// The values every share is computed from and checked against: the
+    /// binding factor of `identifier` and the challenge.
+    fn signer_context(
+        &self,
+        identifier: Identifier,
+        rk: &VerificationKey<SpendAuth>,
+    ) -> Result<(Fr, Fr), ParserError> {
+        let binding_factors = self.binding_factors(rk);
+        let position = self
+            .commitments
+            .iter()
+            .position(|commitments| commitments.identifier == identifier)
+            .ok_or(ParserError::MissingField)?;
+
+        let group_commitment = self
+            .group_commitment(&binding_factors)?
+            .vartime_compress()
+            .0;
+        let challenge = self.challenge(&group_commitment, rk);
+
+        Ok((binding_factors[position], challenge))
+    }
+
+    /// Checks a signature share against the verifying share of its signer.
+    pub fn verify_share(
+        &self,
+        share: &SignatureShare,
+        verifying_share: &VerificationKey<SpendAuth>,
+        group_key: &VerificationKey<SpendAuth>,
+    ) -> Result<(), ParserError> {
+        let rk = self.randomized_group_key(group_key);
+        let (rho, challenge) = self.signer_context(share.identifier, &rk)?;
+        let (hiding, binding) = self.commitment(share.identifier)?.elements()?;
+        let lambda = self.lagrange_coefficient(share.identifier)?;
+
+        let z = Fr::from_bytes_checked(&share.share).map_err(|_| ParserError::InvalidSignature)?;
+        let verifying_share = decompress(&verifying_share.randomize(&self.randomizer).to_bytes())?;
+
+        if Element::GENERATOR * z != hiding + binding * rho + verifying_share * (challenge * lambda)
+        {
+            return Err(ParserError::InvalidSignature);
+        }
+        Ok(())
+    }
+
+    /// Sums up the shares of the whole signing set, checking the result
+    /// against the randomized group key.
+    pub fn aggregate(
+        &self,
+        shares: &[SignatureShare],
+        group_key: &VerificationKey<SpendAuth>,
+    ) -> Result<Signature<SpendAuth>, ParserError> {
+        if shares.len() != self.commitments.len() {
+            return Err(ParserError::UnexpectedNumberItems);
+        }
+
+        let rk = self.randomized_group_key(group_key);
+        let binding_factors = self.binding_factors(&rk);
+        let group_commitment = self
+            .group_commitment(&binding_factors)?
+            .vartime_compress()
+            .0;
+
+        let mut z = Fr::ZERO;
+        for commitments in self.commitments {
+            let share = shares
+                .iter()
+                .find(|share| share.identifier == commitments.identifier)
+                .ok_or(ParserError::MissingField)?;
+            z += Fr::from_bytes_checked(&share.share).map_err(|_| ParserError::InvalidSignature)?;
+        }
+
+        let mut bytes = [0u8; SIGNATURE_LEN];
+        bytes[..KEY_LEN].copy_from_slice(&group_commitment);
+        bytes[KEY_LEN..].copy_from_slice(&z.to_bytes());
+        let signature = Signature::from(bytes);
+
+        rk.verify(self.message, &signature)
+            .map_err(|_| ParserError::InvalidSignature)?;
+        Ok(signature)
+    }
+}
+
+/// One signer of a FROST group, holding its share of the group key.
+pub struct Participant {
+    identifier: Identifier,
+    share: SigningShare,
+    group_key: VerificationKey<SpendAuth>,
+}
+
+impl Participant {
+    pub fn new(
+        identifier: Identifier,
+        share: SigningShare,
+        group_key: VerificationKey<SpendAuth>,
+    ) -> Self {
+        Self {
+            identifier,
+            share,
+            group_key,
+        }
+    }
+
+    pub fn identifier(&self) -> Identifier {
+        self.identifier
+    }
+
+    pub fn verifying_share(&self) -> VerificationKey<SpendAuth> {
+        self.share.verifying_share()
+    }
+
+    /// Derives a nonce from fresh randomness and the share, so a weak RNG
+    /// alone does not reveal the share.
+    fn nonce<R: RngCore + CryptoRng>(&self, rng: &mut R) -> Fr {
+        let mut random = [0u8; 32];
+        rng.fill_bytes(&mut random);
+        let mut share = self.share.0.to_bytes();
+        let nonce = hash_to_scalar(NONCE_PERSONALIZATION, &[&random, &share]);
+        random.zeroize();
+        share.zeroize();
+        nonce
+    }
+
+    /// Round one: draws a pair of nonces and commits to them.
+    pub fn commit<R: RngCore + CryptoRng>(&self, rng: &mut R) -> SigningNonces {
+        let hiding = self.nonce(rng);
+        let binding = self.nonce(rng);
+        let commitments = SigningCommitments {
+            identifier: self.identifier,
+            hiding: (Element::GENERATOR * hiding).vartime_compress().0,
+            binding: (Element::GENERATOR * binding).vartime_compress().0,
+        };
+        SigningNonces {
+            hiding,
+            binding,
+            commitments,
+        }
+    }
+
+    /// Round two: signs the package with the nonces committed to in round
+    /// one, which are used up whether or not signing succeeds.
+    pub fn sign(
+        &self,
+        nonces: SigningNonces,
+        package: &SigningPackage,
+    ) -> Result<SignatureShare, ParserError> {
+        if package.commitment(self.identifier)? != nonces.commitments() {
+            return Err(ParserError::UnexpectedValue);
+        }
+
+        let rk = package.randomized_group_key(&self.group_key);
+        let (rho, challenge) = package.signer_context(self.identifier, &rk)?;
+        let lambda = package.lagrange_coefficient(self.identifier)?;
+
+        let mut share = self.share.randomized(&package.randomizer);
+        let z = nonces.hiding + nonces.binding * rho + lambda * share * challenge;
+        share.zeroize();
+
+        Ok(SignatureShare {
+            identifier: self.identifier,
+            share: z.to_bytes(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Trng;
+    use decaf377_rdsa::SigningKey;
+    use std::vec::Vec;
+
+    const THRESHOLD: usize = 2;
+    const SIGNERS: u16 = 3;
+
+    fn random_scalar() -> Fr {
+        let mut bytes = [0u8; 64];
+        Trng.fill_bytes(&mut bytes);
+        Fr::from_le_bytes_mod_order(&bytes)
+    }
+
+    /// A trusted dealer, splitting a fresh key with a random polynomial.
+    fn deal() -> (Vec<Participant>, VerificationKey<SpendAuth>) {
+        let coefficients: Vec<Fr> = (0..THRESHOLD).map(|_| random_scalar()).collect();
+        let group_key: VerificationKey<SpendAuth> =
+            SigningKey::<SpendAuth>::new_from_field(coefficients[0]).into();
+
+        let participants = (1..=SIGNERS)
+            .map(|index| {
+                let x = Fr::from(index);
+                let share = coefficients
+                    .iter()
+                    .rev()
+                    .fold(Fr::ZERO, |acc, coefficient| acc * x + coefficient);
+                Participant::new(
+                    Identifier::new(index).unwrap(),
+                    SigningShare::new(share),
+                    group_key,
+                )
+            })
+            .collect();
+        (participants, group_key)
+    }
+
+    fn sign(
+        signers: &[&Participant],
+        message: &[u8],
+        randomizer: Fr,
+        group_key: &VerificationKey<SpendAuth>,
+    ) -> Signature<SpendAuth> {
+        let mut stores: Vec<NonceStore> = signers.iter().map(|_| NonceStore::default()).collect();
+        let commitments: Vec<SigningCommitments> = signers
+            .iter()
+            .zip(stores.iter_mut())
+            .map(|(signer, store)| store.commit(signer, &mut Trng))
+            .collect();
+
+        let package = SigningPackage::new(&commitments, message, randomizer).unwrap();
+        let shares: Vec<SignatureShare> = signers
+            .iter()
+            .zip(stores.iter_mut())
+            .map(|(signer, store)| {
+                let share = signer.sign(store.take().unwrap(), &package).unwrap();
+                package
+                    .verify_share(&share, &signer.verifying_share(), group_key)
+                    .unwrap();
+                share
+            })
+            .collect();
+
+        package.aggregate(&shares, group_key).unwrap()
+    }
+
+    #[test]
+    fn test_threshold_spend_auth_signature() {
+        let (participants, group_key) = deal();
+        let message = b"effect hash";
+        let randomizer = random_scalar();
+
+        // any two of the three can sign
+        for signers in [[0, 1], [0, 2], [1, 2]] {
+            let signers = [&participants[signers[0]], &participants[signers[1]]];
+            let signature = sign(&signers, message, randomizer, &group_key);
+
+            let rk = group_key.randomize(&randomizer);
+            assert!(rk.verify(message, &signature).is_ok());
+            assert!(group_key.verify(message, &signature).is_err());
+        }
+
+        // all three together as well
+        let signers: Vec<&Participant> = participants.iter().collect();
+        let signature = sign(&signers, message, Fr::ZERO, &group_key);
+        assert!(group_key.verify(message, &signature).is_ok());
+    }
+
+    #[test]
+    fn test_nonces_are_single_use() {
+        let (participants, _) = deal();
+        let mut store = NonceStore::default();
+        let commitments = [
+            store.commit(&participants[0], &mut Trng),
+            participants[1].commit(&mut Trng).commitments,
+        ];
+        let package = SigningPackage::new(&commitments, b"message", Fr::ZERO).unwrap();
+
+        assert!(participants[0]
+            .sign(store.take().unwrap(), &package)
+            .is_ok());
+        assert_eq!(store.take().err(), Some(ParserError::NoData));
+
+        // nonces committed to for another package are refused, and gone
+        store.commit(&participants[0], &mut Trng);
+        assert_eq!(
+            participants[0].sign(store.take().unwrap(), &package).err(),
+            Some(ParserError::UnexpectedValue)
+        );
+        assert_eq!(store.take().err(), Some(ParserError::NoData));
+    }
+
+    #[test]
+    fn test_invalid_share_rejected() {
+        let (participants, group_key) = deal();
+        let nonces = [
+            participants[0].commit(&mut Trng),
+            participants[1].commit(&mut Trng),
+        ];
+        let commitments = [nonces[0].commitments, nonces[1].commitments];
+        let package = SigningPackage::new(&commitments, b"message", Fr::ZERO).unwrap();
+
+        let [first, second] = nonces;
+        let honest = participants[0].sign(first, &package).unwrap();
+        let mut forged = participants[1].sign(second, &package).unwrap();
+        forged.share = (Fr::from_bytes_checked(&forged.share).unwrap() + Fr::ONE).to_bytes();
+
+        assert!(package
+            .verify_share(&honest, &participants[0].verifying_share(), &group_key)
+            .is_ok());
+        assert_eq!(
+            package.verify_share(&forged, &participants[1].verifying_share(), &group_key),
+            Err(ParserError::InvalidSignature)
+        );
+        assert_eq!(
+            package.aggregate(&[honest, forged], &group_key).err(),
+            Some(ParserError::InvalidSignature)
+        );
+
+        let unordered = [commitments[1], commitments[0]];
+        assert_eq!(
+            SigningPackage::new(&unordered, b"message", Fr::ZERO).err(),
+            Some(ParserError::DuplicatedField)
+        );
+    }
+}
@@ -0,0 +1,795 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+//! Distributed key generation for a FROST group, so that the spend
+//! authorization key never exists in one place.
+//!
+//! This is the Pedersen DKG with proofs of knowledge from the FROST paper.
+//! Every participant deals a random polynomial whose coefficients are derived
+//! from its seed and the group context, so the device keeps no state between
+//! rounds and the host replays the packages of the other participants. Each
+//! participant also contributes to the nullifier key, committing to its part
+//! in round one and revealing it in round two, so the group ends up with one
+//! full viewing key.
+//!
+//! The host only ever relays round two encrypted: every participant commits
+//! to a key agreement key in round one, and seals what it deals to another
+//! with ChaCha20-Poly1305 under the key both of them agree on.
+
+use super::{decompress, hash, hash_to_scalar, Identifier, Participant, SigningShare};
+use crate::constants::{FROST_MAX_SIGNERS, KEY_LEN, NONCE_LEN};
+use crate::keys::ka;
+use crate::keys::nk::NullifierKey;
+use crate::keys::spend_key::SpendKeyBytes;
+use crate::keys::FullViewingKey;
+use crate::{expand_fq, expand_fr, ParserError};
+use arrayvec::ArrayVec;
+use chacha20poly1305::aead::{AeadInPlace, Nonce, Tag};
+use chacha20poly1305::{ChaCha20Poly1305, Key, KeyInit};
+use decaf377::{Element, Fq, Fr};
+use decaf377_rdsa::{SpendAuth, VerificationKey};
+use zeroize::Zeroize;
+
+const COEFFICIENT_LABEL: &[u8; 16] = b"Penumbra_FrstDkg";
+const NULLIFIER_KEY_LABEL: &[u8; 16] = b"Penumbra_FrstNkd";
+const ENCRYPTION_KEY_LABEL: &[u8; 16] = b"Penumbra_FrstEnc";
+const PROOF_PERSONALIZATION: &[u8; 16] = b"FROST_dcf377_dkg";
+const NK_COMMITMENT_PERSONALIZATION: &[u8; 16] = b"FROST_dcf377_nkc";
+const SHARE_KEY_PERSONALIZATION: &[u8; 16] = b"FROST_dcf377_enc";
+const TRANSCRIPT_PERSONALIZATION: &[u8; 16] = b"FROST_dcf377_trn";
+
+// Every share key seals a single message, the share one dealer deals to one
+// receiver in one group.
+const SHARE_NONCE: &[u8; NONCE_LEN] = &[0; NONCE_LEN];
+
+// The number of groups a `Round2Guard` remembers dealing to.
+const MAX_DEALT_CONTEXTS: usize = 4;
+
+// Seed derivation inputs past the coefficients, which never exceed the
+// maximum number of signers.
+const PROOF_NONCE_INDEX: u8 = 0xff;
+
+/// The size of the group and how many of its members it takes to sign.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct DkgParameters {
+    pub threshold: u16,
+    pub max_signers: u16,
+}
+
+impl DkgParameters {
+    pub fn new(threshold: u16, max_signers: u16) -> Result<Self, ParserError> {
+        if threshold == 0 || threshold > max_signers || max_signers as usize > FROST_MAX_SIGNERS {
+            return Err(ParserError::InvalidThreshold);
+        }
+        Ok(Self {
+            threshold,
+            max_signers,
+        })
+    }
+}
+
+/// The broadcast of round one: the commitment to the dealt polynomial, a
+/// proof of knowledge of its constant term, a commitment to the participant's
+/// part of the nullifier key, and the key round two is encrypted to.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct Round1Package {
+    pub identifier: Identifier,
+    pub commitment: ArrayVec<[u8; KEY_LEN], FROST_MAX_SIGNERS>,
+    pub proof_r: [u8; KEY_LEN],
+    pub proof_mu: [u8; KEY_LEN],
+    pub nk_commitment: [u8; KEY_LEN],
+    pub encryption_key: [u8; KEY_LEN],
+}
+
+impl Round1Package {
+    fn coefficient_commitments(&self) -> Result<ArrayVec<Element, FROST_MAX_SIGNERS>, ParserError> {
+        self.commitment.iter().map(decompress).collect()
+    }
+
+    /// The public part of the share this package deals to `identifier`.
+    fn evaluate(&self, identifier: Identifier) -> Result<Element, ParserError> {
+        let x = identifier.to_scalar();
+        let mut power = Fr::ONE;
+        let mut point = Element::IDENTITY;
+        for coefficient in self.coefficient_commitments()? {
+            point += coefficient * power;
+            power *= x;
+        }
+        Ok(point)
+    }
+}
+
+/// The message of round two from one participant to another: the share of
+/// the sender's polynomial for the receiver and the sender's part of the
+/// nullifier key, encrypted to the receiver.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct Round2Package {
+    pub sender: Identifier,
+    pub receiver: Identifier,
+    pub ciphertext: [u8; Round2Package::CIPHERTEXT_LEN],
+}
+
+impl Round2Package {
+    /// The share and the nullifier key part, followed by the tag.
+    pub const CIPHERTEXT_LEN: usize = 2 * KEY_LEN + 16;
+}
+
+/// Remembers the groups a participant dealt its shares to. Shares of one
+/// polynomial for more receivers than the threshold reveal its secret, so
+/// round two deals only once per context.
+#[derive(Default)]
+pub struct Round2Guard {
+    dealt: ArrayVec<([u8; KEY_LEN], [u8; 64]), MAX_DEALT_CONTEXTS>,
+}
+
+impl Round2Guard {
+    /// Records dealing to the group of `context` with the round one packages
+    /// hashing to `transcript`, refusing any group dealt to before.
+    fn record(
+        &mut self,
+        context: &[u8; KEY_LEN],
+        transcript: &[u8; 64],
+    ) -> Result<(), ParserError> {
+        match self.dealt.iter().find(|(dealt, _)| dealt == context) {
+            Some((_, previous)) if previous != transcript => Err(ParserError::UnexpectedValue),
+            Some(_) => Err(ParserError::DuplicatedField),
+            None => self
+                .dealt
+                .try_push((*context, *transcript))
+                .map_err(|_| ParserError::UnexpectedNumberItems),
+        }
+    }
+}
+
+/// The outcome of key generation for one participant.
+pub struct KeyPackage {
+    pub participant: Participant,
+    pub group_key: VerificationKey<SpendAuth>,
+    pub fvk: FullViewingKey,
+}
+
+impl KeyPackage {
+    /// Checks the group key against one announced elsewhere, for example by
+    /// another participant.
+    pub fn verify_group_key(&self, expected: &[u8; KEY_LEN]) -> Result<(), ParserError> {
+        if &self.group_key.to_bytes() != expected {
+            return Err(ParserError::UnexpectedValue);
+        }
+        Ok(())
+    }
+}
+
+/// One participant of the key generation, dealing from its seed.
+pub struct DkgParticipant<'a> {
+    seed: &'a SpendKeyBytes,
+    identifier: Identifier,
+    parameters: DkgParameters,
+    context: [u8; KEY_LEN],
+}
+
+impl<'a> DkgParticipant<'a> {
+    /// `context` names the group, so that one seed deals unrelated
+    /// polynomials to different groups.
+    pub fn new(
+        seed: &'a SpendKeyBytes,
+        identifier: Identifier,
+        parameters: DkgParameters,
+        context: [u8; KEY_LEN],
+    ) -> Result<Self, ParserError> {
+        if identifier.index() > parameters.max_signers {
+            return Err(ParserError::ValueOutOfRange);
+        }
+        Ok(Self {
+            seed,
+            identifier,
+            parameters,
+            context,
+        })
+    }
+
+    fn derivation_input(&self, index: u8) -> [u8; KEY_LEN + 3] {
+        let mut input = [0u8; KEY_LEN + 3];
+        input[..KEY_LEN].copy_from_slice(&self.context);
+        input[KEY_LEN..KEY_LEN + 2].copy_from_slice(&self.identifier.index().to_le_bytes());
+        input[KEY_LEN + 2] = index;
+        input
+    }
+
+    fn coefficient(&self, index: u8) -> Result<Fr, ParserError> {
+        expand_fr::expand_ff(
+            COEFFICIENT_LABEL,
+            self.seed.key_bytes(),
+            &self.derivation_input(index),
+        )
+    }
+
+    fn nk_part(&self) -> Result<Fq, ParserError> {
+        expand_fq::expand_ff(
+            NULLIFIER_KEY_LABEL,
+            self.seed.key_bytes(),
+            &self.derivation_input(0),
+        )
+    }
+
+    fn encryption_key(&self) -> Result<ka::Secret, ParserError> {
+        Ok(ka::Secret::new_from_field(expand_fr::expand_ff(
+            ENCRYPTION_KEY_LABEL,
+            self.seed.key_bytes(),
+            &self.derivation_input(0),
+        )?))
+    }
+
+    /// The key sealing what `sender` deals to `receiver`, agreed on with the
+    /// other of the two, whose round one package is `other`.
+    fn share_key(
+        &self,
+        other: &Round1Package,
+        sender: Identifier,
+        receiver: Identifier,
+    ) -> Result<Key, ParserError> {
+        let shared_secret = self
+            .encryption_key()?
+            .key_agreement_with(&ka::Public(other.encryption_key))?;
+
+        let key = blake2b_simd::Params::new()
+            .hash_length(32)
+            .personal(SHARE_KEY_PERSONALIZATION)
+            .to_state()
+            .update(&self.context)
+            .update(&sender.to_scalar().to_bytes())
+            .update(&receiver.to_scalar().to_bytes())
+            .update(&shared_secret.0)
+            .finalize();
+        Ok(*Key::from_slice(key.as_bytes()))
+    }
+
+    /// The secret share the polynomial of this participant deals to `receiver`.
+    fn deal(&self, receiver: Identifier) -> Result<Fr, ParserError> {
+        let x = receiver.to_scalar();
+        let mut share = Fr::ZERO;
+        for index in (0..self.parameters.threshold as u8).rev() {
+            let mut coefficient = self.coefficient(index)?;
+            share = share * x + coefficient;
+            coefficient.zeroize();
+        }
+        Ok(share)
+    }
+
+    pub fn round1(&self) -> Result<Round1Package, ParserError> {
+        let mut commitment = ArrayVec::new();
+        for index in 0..self.parameters.threshold as u8 {
+            let mut coefficient = self.coefficient(index)?;
+            commitment.push((Element::GENERATOR * coefficient).vartime_compress().0);
+            coefficient.zeroize();
+        }
+
+        // a Schnorr proof of knowledge of the constant term; the nonce is
+        // derived like the coefficients, as the challenge never changes either
+        let mut secret = self.coefficient(0)?;
+        let mut nonce = expand_fr::expand_ff(
+            COEFFICIENT_LABEL,
+            self.seed.key_bytes(),
+            &self.derivation_input(PROOF_NONCE_INDEX),
+        )?;
+        let proof_r = (Element::GENERATOR * nonce).vartime_compress().0;
+        let encryption_key = self.encryption_key()?.public().0;
+        let challenge = proof_challenge(
+            &self.context,
+            self.identifier,
+            &commitment[0],
+            &encryption_key,
+            &proof_r,
+        );
+        let proof_mu = (nonce + secret * challenge).to_bytes();
+        secret.zeroize();
+        nonce.zeroize();
+
+        Ok(Round1Package {
+            identifier: self.identifier,
+            commitment,
+            proof_r,
+            proof_mu,
+            nk_commitment: nk_commitment(&self.context, self.identifier, &self.nk_part()?),
+            encryption_key,
+        })
+    }
+
+    /// Checks the round one packages of the group, which must come in order
+    /// of identifier and include this participant's own.
+    fn check_round1(&self, packages: &[Round1Package]) -> Result<(), ParserError> {
+        if packages.len() != self.parameters.max_signers as usize {
+            return Err(ParserError::UnexpectedNumberItems);
+        }
+
+        for (index, package) in (1..=self.parameters.max_signers).zip(packages) {
+            if package.identifier.index() != index {
+                return Err(ParserError::UnexpectedValue);
+            }
+            if package.commitment.len() != self.parameters.threshold as usize {
+                return Err(ParserError::UnexpectedNumberItems);
+            }
+            if package.identifier == self.identifier {
+                if *package != self.round1()? {
+                    return Err(ParserError::UnexpectedValue);
+                }
+                continue;
+            }
+
+            let challenge = proof_challenge(
+                &self.context,
+                package.identifier,
+                &package.commitment[0],
+                &package.encryption_key,
+                &package.proof_r,
+            );
+            let mu = Fr::from_bytes_checked(&package.proof_mu)
+                .map_err(|_| ParserError::InvalidSignature)?;
+            let expected =
+                decompress(&package.proof_r)? + decompress(&package.commitment[0])? * challenge;
+            if Element::GENERATOR * mu != expected {
+                return Err(ParserError::InvalidSignature);
+            }
+            if decompress(&package.encryption_key)? == Element::IDENTITY {
+                return Err(ParserError::InvalidPubkeyEncoding);
+            }
+        }
+        Ok(())
+    }
+
+    /// Round two: after checking everyone's round one, deals a share to every
+    /// other participant, encrypted to it. `guard` makes sure this happens
+    /// only once for the group.
+    pub fn round2(
+        &self,
+        guard: &mut Round2Guard,
+        round1: &[Round1Package],
+    ) -> Result<ArrayVec<Round2Package, FROST_MAX_SIGNERS>, ParserError> {
+        self.check_round1(round1)?;
+
+        let nk = self.nk_part()?.to_bytes();
+        let mut packages = ArrayVec::new();
+        for receiver in round1 {
+            if receiver.identifier == self.identifier {
+                continue;
+            }
+
+            let mut ciphertext = [0u8; Round2Package::CIPHERTEXT_LEN];
+            let mut share = self.deal(receiver.identifier)?;
+            ciphertext[..KEY_LEN].copy_from_slice(&share.to_bytes());
+            share.zeroize();
+            ciphertext[KEY_LEN..2 * KEY_LEN].copy_from_slice(&nk);
+
+            let key = self.share_key(receiver, self.identifier, receiver.identifier)?;
+            let (message, tag) = ciphertext.split_at_mut(2 * KEY_LEN);
+            let sealed = ChaCha20Poly1305::new(&key)
+                .encrypt_in_place_detached(
+                    Nonce::<ChaCha20Poly1305>::from_slice(SHARE_NONCE),
+                    &[],
+                    message,
+                )
+                .map_err(|_| ParserError::EncryptionError);
+            match sealed {
+                Ok(sealed) => tag.copy_from_slice(&sealed),
+                Err(err) => {
+                    ciphertext.zeroize();
+                    return Err(err);
+                }
+            }
+
+            packages.push(Round2Package {
+                sender: self.identifier,
+                receiver: receiver.identifier,
+                ciphertext,
+            });
+        }
+
+        guard.record(&self.context, &transcript(round1))?;
+        Ok(packages)
+    }
+
+    /// Opens the package `dealer` sent this participant, checking the share
+    /// against the dealer's commitment and the nullifier key part against the
+    /// one committed to in round one.
+    fn receive(
+        &self,
+        dealer: &Round1Package,
+        package: &Round2Package,
+    ) -> Result<(Fr, Fq), ParserError> {
+        let key = self.share_key(dealer, dealer.identifier, self.identifier)?;
+        let mut plaintext = package.ciphertext;
+        let (message, tag) = plaintext.split_at_mut(2 * KEY_LEN);
+        let opened = ChaCha20Poly1305::new(&key)
+            .decrypt_in_place_detached(
+                Nonce::<ChaCha20Poly1305>::from_slice(SHARE_NONCE),
+                &[],
+                message,
+                Tag::<ChaCha20Poly1305>::from_slice(tag),
+            )
+            .map_err(|_| ParserError::EncryptionError)
+            .and_then(|()| {
+                let share = Fr::from_bytes_checked(&plaintext[..KEY_LEN].try_into().unwrap())
+                    .map_err(|_| ParserError::InvalidSignature)?;
+                let nk =
+                    Fq::from_bytes_checked(&plaintext[KEY_LEN..2 * KEY_LEN].try_into().unwrap())
+                        .map_err(|_| ParserError::InvalidFq)?;
+                Ok((share, nk))
+            });
+        plaintext.zeroize();
+        let (mut share, nk) = opened?;
+
+        if Element::GENERATOR * share != dealer.evaluate(self.identifier)? {
+            share.zeroize();
+            return Err(ParserError::InvalidSignature);
+        }
+        if nk_commitment(&self.context, dealer.identifier, &nk) != dealer.nk_commitment {
+            share.zeroize();
+            return Err(ParserError::UnexpectedValue);
+        }
+        Ok((share, nk))
+    }
+
+    /// Checks the shares dealt to this participant against the commitments
+    /// of their dealers, and sums them up into its signing share. The group
+    /// key and the full viewing key come out the same for every participant.
+    pub fn finish(
+        &self,
+        round1: &[Round1Package],
+        round2: &[Round2Package],
+    ) -> Result<KeyPackage, ParserError> {
+        self.check_round1(round1)?;
+        if round2.len() + 1 != round1.len() {
+            return Err(ParserError::UnexpectedNumberItems);
+        }
+
+        let mut share = self.deal(self.identifier)?;
+        let mut nk = self.nk_part()?;
+        let mut group_key = Element::IDENTITY;
+
+        for dealer in round1 {
+            group_key += decompress(&dealer.commitment[0])?;
+            if dealer.identifier == self.identifier {
+                continue;
+            }
+
+            let received = round2.iter().find(|package| {
+                package.sender == dealer.identifier && package.receiver == self.identifier
+            });
+            let (mut dealt, part) = match received.map(|package| self.receive(dealer, package)) {
+                Some(Ok(received)) => received,
+                Some(Err(err)) => {
+                    share.zeroize();
+                    return Err(err);
+                }
+                None => {
+                    share.zeroize();
+                    return Err(ParserError::MissingField);
+                }
+            };
+            share += dealt;
+            dealt.zeroize();
+            nk += part;
+        }
+
+        let group_key = VerificationKey::<SpendAuth>::try_from(group_key.vartime_compress().0)
+            .map_err(|_| ParserError::InvalidPubkeyEncoding)?;
+        let fvk = FullViewingKey::from_components(group_key, NullifierKey(nk))?;
+
+        let signing_share = SigningShare::new(share);
+        share.zeroize();
+        if signing_share.verifying_share() != verifying_share(round1, self.identifier)? {
+            return Err(ParserError::InvalidSignature);
+        }
+
+        Ok(KeyPackage {
+            participant: Participant::new(self.identifier, signing_share, group_key),
+            group_key,
+            fvk,
+        })
+    }
+}
+
+/// The verifying share of any participant, from the public round one
+/// packages alone.
+pub fn verifying_share(
+    round1: &[Round1Package],
+    identifier: Identifier,
+) -> Result<VerificationKey<SpendAuth>, ParserError> {
+    let mut point = Element::IDENTITY;
+    for dealer in round1 {
+        point += dealer.evaluate(identifier)?;
+    }
+    VerificationKey::try_from(point.vartime_compress().0)
+        .map_err(|_| ParserError::InvalidPubkeyEncoding)
+}
+
+fn proof_challenge(
+    context: &[u8; KEY_LEN],
+    identifier: Identifier,
+    commitment: &[u8; KEY_LEN],
+    encryption_key: &[u8; KEY_LEN],
+    proof_r: &[u8; KEY_LEN],
+) -> Fr {
+    hash_to_scalar(
+        PROOF_PERSONALIZATION,
+        &[
+            context,
+            &identifier.to_scalar().to_bytes(),
+            commitment,
+            encryption_key,
+            proof_r,
+        ],
+    )
+}
+
+/// Hashes the round one packages of the group, which pin down whom round two
+/// deals to.
+fn transcript(round1: &[Round1Package]) -> [u8; 64] {
+    let mut state = blake2b_simd::Params::new()
+        .hash_length(64)
+        .personal(TRANSCRIPT_PERSONALIZATION)
+        .to_state();
+    for package in round1 {
+        state.update(&package.identifier.to_scalar().to_bytes());
+        for coefficient in &package.commitment {
+            state.update(coefficient);
+        }
+        state.update(&package.proof_r);
+        state.update(&package.proof_mu);
+        state.update(&package.nk_commitment);
+        state.update(&package.encryption_key);
+    }
+    *state.finalize().as_array()
+}
+
+fn nk_commitment(context: &[u8; KEY_LEN], identifier: Identifier, nk: &Fq) -> [u8; KEY_LEN] {
+    let digest = hash(
+        NK_COMMITMENT_PERSONALIZATION,
+        &[context, &identifier.to_scalar().to_bytes(), &nk.to_bytes()],
+    );
+    let mut commitment = [0u8; KEY_LEN];
+    commitment.copy_from_slice(&digest[..KEY_LEN]);
+    commitment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frost::{NonceStore, SigningCommitments, SigningPackage};
+    use crate::Trng;
+    use rand::RngCore;
+    use std::vec::Vec;
+
+    const CONTEXT: [u8; KEY_LEN] = [0x42; KEY_LEN];
+
+    fn seeds(n: u8) -> Vec<SpendKeyBytes> {
+        (1..=n).map(|i| SpendKeyBytes::from([i; 32])).collect()
+    }
+
+    fn run(seeds: &[SpendKeyBytes], parameters: DkgParameters) -> Vec<KeyPackage> {
+        let participants: Vec<DkgParticipant> = seeds
+            .iter()
+            .zip(1..)
+            .map(|(seed, index)| {
+                DkgParticipant::new(seed, Identifier::new(index).unwrap(), parameters, CONTEXT)
+                    .unwrap()
+            })
+            .collect();
+
+        let round1: Vec<Round1Package> = participants.iter().map(|p| p.round1().unwrap()).collect();
+        let round2: Vec<Round2Package> = participants
+            .iter()
+            .flat_map(|p| p.round2(&mut Round2Guard::default(), &round1).unwrap())
+            .collect();
+
+        participants
+            .iter()
+            .map(|p| {
+                let received: Vec<Round2Package> = round2
+                    .iter()
+                    .filter(|package| package.receiver == p.identifier)
+                    .copied()
+                    .collect();
+                p.finish(&round1, &received).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dkg_agrees_on_keys() {
+        let seeds = seeds(3);
+        let keys = run(&seeds, DkgParameters::new(2, 3).unwrap());
+
+        let group_key = keys[0].group_key.to_bytes();
+        let mut fvk = [0u8; 64];
+        keys[0].fvk.to_bytes_into(&mut fvk).unwrap();
+        for key in &keys[1..] {
+            assert!(key.verify_group_key(&group_key).is_ok());
+            let mut other = [0u8; 64];
+            key.fvk.to_bytes_into(&mut other).unwrap();
+            assert_eq!(fvk, other);
+        }
+        assert_eq!(&fvk[..KEY_LEN], &group_key);
+
+        // deterministic in the seeds, and no participant's own key
+        assert_eq!(
+            run(&seeds, DkgParameters::new(2, 3).unwrap())[2]
+                .group_key
+                .to_bytes(),
+            group_key
+        );
+        assert_ne!(seeds[0].verification_key().unwrap().to_bytes(), group_key);
+    }
+
+    #[test]
+    fn test_dkg_shares_sign() {
+        let keys = run(&seeds(3), DkgParameters::new(2, 3).unwrap());
+        let signers = [&keys[0].participant, &keys[2].participant];
+        let group_key = keys[0].group_key;
+
+        let mut randomizer = [0u8; 64];
+        Trng.fill_bytes(&mut randomizer);
+        let randomizer = Fr::from_le_bytes_mod_order(&randomizer);
+
+        let mut stores = [NonceStore::default(), NonceStore::default()];
+        let commitments: Vec<SigningCommitments> = signers
+            .iter()
+            .zip(stores.iter_mut())
+            .map(|(signer, store)| store.commit(signer, &mut Trng))
+            .collect();
+        let package = SigningPackage::new(&commitments, b"effect hash", randomizer).unwrap();
+        let shares: Vec<_> = signers
+            .iter()
+            .zip(stores.iter_mut())
+            .map(|(signer, store)| signer.sign(store.take().unwrap(), &package).unwrap())
+            .collect();
+
+        let signature = package.aggregate(&shares, &group_key).unwrap();
+        assert!(group_key
+            .randomize(&randomizer)
+            .verify(b"effect hash", &signature)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_dkg_rejects_cheating() {
+        let seeds = seeds(3);
+        let parameters = DkgParameters::new(2, 3).unwrap();
+        let participants: Vec<DkgParticipant> = seeds
+            .iter()
+            .zip(1..)
+            .map(|(seed, index)| {
+                DkgParticipant::new(seed, Identifier::new(index).unwrap(), parameters, CONTEXT)
+                    .unwrap()
+            })
+            .collect();
+        let round1: Vec<Round1Package> = participants.iter().map(|p| p.round1().unwrap()).collect();
+
+        // a proof of knowledge that does not check out
+        let mut forged = round1.clone();
+        forged[1].proof_mu = round1[2].proof_mu;
+        assert_eq!(
+            participants[0]
+                .round2(&mut Round2Guard::default(), &forged)
+                .err(),
+            Some(ParserError::InvalidSignature)
+        );
+
+        let received: Vec<Round2Package> = [&participants[1], &participants[2]]
+            .iter()
+            .map(|p| p.round2(&mut Round2Guard::default(), &round1).unwrap()[0])
+            .collect();
+        assert!(participants[0].finish(&round1, &received).is_ok());
+
+        // a ciphertext tampered with on the way
+        let mut tampered = received.clone();
+        tampered[0].ciphertext[0] ^= 1;
+        assert_eq!(
+            participants[0].finish(&round1, &tampered).err(),
+            Some(ParserError::EncryptionError)
+        );
+
+        // a package sealed for another receiver, relabelled
+        let mut relabelled = received.clone();
+        relabelled[0] = participants[1]
+            .round2(&mut Round2Guard::default(), &round1)
+            .unwrap()[1];
+        relabelled[0].receiver = participants[0].identifier;
+        assert_eq!(
+            participants[0].finish(&round1, &relabelled).err(),
+            Some(ParserError::EncryptionError)
+        );
+
+        // a package from the right sender to someone else is not taken
+        let mut misaddressed = received.clone();
+        misaddressed[0] = participants[1]
+            .round2(&mut Round2Guard::default(), &round1)
+            .unwrap()[1];
+        assert_eq!(
+            participants[0].finish(&round1, &misaddressed).err(),
+            Some(ParserError::MissingField)
+        );
+
+        // a share off its dealer's commitment, sealed properly
+        let mut off = received.clone();
+        let key = participants[1]
+            .share_key(
+                &round1[0],
+                participants[1].identifier,
+                participants[0].identifier,
+            )
+            .unwrap();
+        let mut plaintext = [0u8; 2 * KEY_LEN];
+        plaintext[..KEY_LEN].copy_from_slice(&Fr::ONE.to_bytes());
+        plaintext[KEY_LEN..].copy_from_slice(&participants[1].nk_part().unwrap().to_bytes());
+        let tag = ChaCha20Poly1305::new(&key)
+            .encrypt_in_place_detached(
+                Nonce::<ChaCha20Poly1305>::from_slice(SHARE_NONCE),
+                &[],
+                &mut plaintext,
+            )
+            .unwrap();
+        off[0].ciphertext[..2 * KEY_LEN].copy_from_slice(&plaintext);
+        off[0].ciphertext[2 * KEY_LEN..].copy_from_slice(&tag);
+        assert_eq!(
+            participants[0].finish(&round1, &off).err(),
+            Some(ParserError::InvalidSignature)
+        );
+
+        assert_eq!(
+            DkgParameters::new(4, 3).err(),
+            Some(ParserError::InvalidThreshold)
+        );
+    }
+
+    #[test]
+    fn test_dkg_deals_once() {
+        let parameters = DkgParameters::new(2, 3).unwrap();
+        let seeds = seeds(4);
+        let participants: Vec<DkgParticipant> = seeds
+            .iter()
+            .zip([1, 2, 3, 3])
+            .map(|(seed, index)| {
+                DkgParticipant::new(seed, Identifier::new(index).unwrap(), parameters, CONTEXT)
+                    .unwrap()
+            })
+            .collect();
+        let round1: Vec<Round1Package> = participants.iter().map(|p| p.round1().unwrap()).collect();
+
+        let mut guard = Round2Guard::default();
+        assert!(participants[0].round2(&mut guard, &round1[..3]).is_ok());
+
+        // not again for the same group, nor for another one in the same context
+        assert_eq!(
+            participants[0].round2(&mut guard, &round1[..3]).err(),
+            Some(ParserError::DuplicatedField)
+        );
+        let other = [round1[0].clone(), round1[1].clone(), round1[3].clone()];
+        assert_eq!(
+            participants[0].round2(&mut guard, &other).err(),
+            Some(ParserError::UnexpectedValue)
+        );
+
+        // a failed round one leaves the guard as it was
+        let mut forged = other.clone();
+        forged[1].proof_mu = round1[2].proof_mu;
+        let mut fresh = Round2Guard::default();
+        assert!(participants[0].round2(&mut fresh, &forged).is_err());
+        assert!(participants[0].round2(&mut fresh, &other).is_ok());
+    }
+}
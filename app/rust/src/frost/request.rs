@@ -0,0 +1,204 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+//! The requests the host sends a device taking part in a FROST group.
+//!
+//! The device keeps no key share: the host replays the key generation
+//! transcript with every request, and the device derives its share from it
+//! again. Integers are little endian, and every list has a known length:
+//!
+//! ```text
+//! transcript = threshold:u16 max_signers:u16 identifier:u16 context:[32]
+//!              round1 * max_signers
+//!              round2 * (max_signers - 1)
+//! round1     = identifier:u16 commitment:[32] * threshold
+//!              proof_r:[32] proof_mu:[32] nk_commitment:[32] encryption_key:[32]
+//! round2     = sender:u16 receiver:u16 ciphertext:[80]
+//!
+//! sign       = transcript randomizer:[32]
+//!              count:u8 (identifier:u16 hiding:[32] binding:[32]) * count
+//!              message_len:u16 message
+//! ```
+
+use super::dkg::{DkgParameters, DkgParticipant, KeyPackage, Round1Package, Round2Package};
+use super::{Identifier, SigningCommitments};
+use crate::constants::{FROST_MAX_SIGNERS, KEY_LEN};
+use crate::keys::spend_key::SpendKeyBytes;
+use crate::ParserError;
+use arrayvec::ArrayVec;
+use decaf377::Fr;
+use nom::bytes::complete::take;
+use nom::number::complete::{le_u16, le_u8};
+use nom::IResult;
+
+fn identifier(input: &[u8]) -> IResult<&[u8], Identifier, ParserError> {
+    let (rest, index) = le_u16(input)?;
+    Ok((rest, Identifier::new(index)?))
+}
+
+fn key(input: &[u8]) -> IResult<&[u8], [u8; KEY_LEN], ParserError> {
+    let (rest, bytes) = take(KEY_LEN)(input)?;
+    // take returned exactly KEY_LEN bytes
+    Ok((rest, bytes.try_into().unwrap()))
+}
+
+fn round1(input: &[u8], threshold: u16) -> IResult<&[u8], Round1Package, ParserError> {
+    let (mut rest, identifier) = identifier(input)?;
+    let mut commitment = ArrayVec::new();
+    for _ in 0..threshold {
+        let (left, coefficient) = key(rest)?;
+        commitment.push(coefficient);
+        rest = left;
+    }
+    let (rest, proof_r) = key(rest)?;
+    let (rest, proof_mu) = key(rest)?;
+    let (rest, nk_commitment) = key(rest)?;
+    let (rest, encryption_key) = key(rest)?;
+
+    Ok((
+        rest,
+        Round1Package {
+            identifier,
+            commitment,
+            proof_r,
+            proof_mu,
+            nk_commitment,
+            encryption_key,
+        },
+    ))
+}
+
+fn round2(input: &[u8]) -> IResult<&[u8], Round2Package, ParserError> {
+    let (rest, sender) = identifier(input)?;
+    let (rest, receiver) = identifier(rest)?;
+    let (rest, ciphertext) = take(Round2Package::CIPHERTEXT_LEN)(rest)?;
+
+    Ok((
+        rest,
+        Round2Package {
+            sender,
+            receiver,
+            ciphertext: ciphertext.try_into().unwrap(),
+        },
+    ))
+}
+
+fn commitments(input: &[u8]) -> IResult<&[u8], SigningCommitments, ParserError> {
+    let (rest, identifier) = identifier(input)?;
+    let (rest, hiding) = key(rest)?;
+    let (rest, binding) = key(rest)?;
+
+    Ok((
+        rest,
+        SigningCommitments {
+            identifier,
+            hiding,
+            binding,
+        },
+    ))
+}
+
+/// Everything a participant saw of the key generation, enough to derive its
+/// key package again.
+pub struct DkgTranscript {
+    pub parameters: DkgParameters,
+    pub identifier: Identifier,
+    pub context: [u8; KEY_LEN],
+    pub round1: ArrayVec<Round1Package, FROST_MAX_SIGNERS>,
+    pub round2: ArrayVec<Round2Package, FROST_MAX_SIGNERS>,
+}
+
+impl DkgTranscript {
+    pub fn parse(input: &[u8]) -> IResult<&[u8], Self, ParserError> {
+        let (rest, threshold) = le_u16(input)?;
+        let (rest, max_signers) = le_u16(rest)?;
+        let parameters = DkgParameters::new(threshold, max_signers)?;
+        let (rest, identifier) = identifier(rest)?;
+        let (mut rest, context) = key(rest)?;
+
+        let mut packages = ArrayVec::new();
+        for _ in 0..max_signers {
+            let (left, package) = round1(rest, threshold)?;
+            packages.push(package);
+            rest = left;
+        }
+        let mut received = ArrayVec::new();
+        for _ in 1..max_signers {
+            let (left, package) = round2(rest)?;
+            received.push(package);
+            rest = left;
+        }
+
+        Ok((
+            rest,
+            Self {
+                parameters,
+                identifier,
+                context,
+                round1: packages,
+                round2: received,
+            },
+        ))
+    }
+
+    /// Finishes the key generation again as the participant seeded by
+    /// `seed`, checking the whole transcript on the way.
+    pub fn key_package(&self, seed: &SpendKeyBytes) -> Result<KeyPackage, ParserError> {
+        DkgParticipant::new(seed, self.identifier, self.parameters, self.context)?
+            .finish(&self.round1, &self.round2)
+    }
+}
+
+/// A request for a signature share: the message, the spend randomizer and
+/// the commitments of the signing set.
+pub struct SignRequest<'a> {
+    pub transcript: DkgTranscript,
+    pub randomizer: Fr,
+    pub commitments: ArrayVec<SigningCommitments, FROST_MAX_SIGNERS>,
+    pub message: &'a [u8],
+}
+
+impl<'a> SignRequest<'a> {
+    pub fn parse(input: &'a [u8]) -> Result<Self, ParserError> {
+        let (rest, transcript) = DkgTranscript::parse(input)?;
+        let (rest, randomizer) = key(rest)?;
+        let (mut rest, count) = le_u8(rest)?;
+
+        let parameters = transcript.parameters;
+        if (count as u16) < parameters.threshold || (count as u16) > parameters.max_signers {
+            return Err(ParserError::UnexpectedNumberItems);
+        }
+        let mut signers = ArrayVec::new();
+        for _ in 0..count {
+            let (left, signer) = commitments(rest)?;
+            signers.push(signer);
+            rest = left;
+        }
+
+        let (rest, message_len) = le_u16(rest)?;
+        let (rest, message) = take(message_len)(rest)?;
+        if !rest.is_empty() {
+            return Err(ParserError::UnexpectedData);
+        }
+
+        Ok(Self {
+            transcript,
+            randomizer: Fr::from_le_bytes_mod_order(&randomizer),
+            commitments: signers,
+            message,
+        })
+    }
+}
@@ -67,6 +67,11 @@ impl Ivk {
         self.ivk.diversified_public(address.diversified_generator()) == *address.transmission_key()
     }
 
+    /// The ivk scalar, the discrete log of every `pk_d` with respect to its `g_d`.
+    pub fn secret(&self) -> &Secret {
+        &self.ivk
+    }
+
     /// Returns the index used to create the given diversifier (if it was
     /// created using this incoming viewing key)
     pub fn index_for_diversifier(&self, diversifier: &Diversifier) -> AddressIndex {
@@ -1,5 +1,6 @@
 //! The Key agreement implementations taken from Penumbra code base.
 
+use rand::{CryptoRng, RngCore};
 use zeroize::Zeroize;
 
 use crate::ParserError;
@@ -19,12 +20,25 @@ pub struct Secret(decaf377::Fr);
 pub struct SharedSecret(pub [u8; 32]);
 
 impl Secret {
+    /// Generate a new secret key using `rng`.
+    pub fn new<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        Self(decaf377::Fr::from_le_bytes_mod_order(&bytes))
+    }
+
     /// Generate a new secret key using the provided `decaf377` field element.
     /// Meant to be used with the SigningKey abstraction.
     pub fn new_from_field(sk: decaf377::Fr) -> Self {
         Self(sk)
     }
 
+    /// Derive a public key for this secret key, using the conventional
+    /// `decaf377` generator.
+    pub fn public(&self) -> Public {
+        self.diversified_public(&decaf377::Element::GENERATOR)
+    }
+
     /// Derive a diversified public key for this secret key, using the provided
     /// `diversified_generator`.
     ///
@@ -35,6 +49,12 @@ impl Secret {
         Public((self.0 * diversified_generator).vartime_compress().into())
     }
 
+    /// Answers the Schnorr `challenge` for the commitment to `nonce`,
+    /// proving knowledge of this secret key without revealing it.
+    pub fn schnorr_response(&self, nonce: &decaf377::Fr, challenge: &decaf377::Fr) -> decaf377::Fr {
+        *nonce + *challenge * self.0
+    }
+
     /// Convert this shared secret to bytes.
     ///
     /// Convenience wrapper around an [`Into`] impl.
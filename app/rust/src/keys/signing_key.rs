@@ -20,9 +20,13 @@ impl Sk {
     pub fn derive_from(spend_bytes: &SpendKeyBytes) -> Result<Self, ParserError> {
         // compute Fr field
         let ask = expand_ff(Self::LABEL, spend_bytes.key_bytes(), &[0; 1])?;
-        let signing_key = SigningKey::new_from_field(ask);
 
-        Ok(Self(signing_key))
+        Ok(Self::from_field(ask))
+    }
+
+    /// Wraps an ask that does not come from a seed, like a FROST share
+    pub fn from_field(ask: Fr) -> Self {
+        Self(SigningKey::new_from_field(ask))
     }
 
     /// Returns the 32-byte encoding of the ask component
@@ -18,7 +18,10 @@
 #![allow(dead_code)]
 // Only enable the unused_crate_dependencies lint when not building for ARM bare metal (Ledger targets)
 // This avoids false positives with compiler_builtins. See: https://github.com/rust-lang/rust/issues/106665
-#![cfg_attr(not(all(target_arch = "arm", target_os = "none")), warn(unused_crate_dependencies))]
+#![cfg_attr(
+    not(all(target_arch = "arm", target_os = "none")),
+    warn(unused_crate_dependencies)
+)]
 
 extern crate no_std_compat as std;
 
@@ -29,6 +32,7 @@ pub(crate) mod address;
 mod bolos;
 pub mod constants;
 pub mod ffi;
+pub(crate) mod frost;
 pub(crate) mod keys;
 pub mod network;
 pub mod parser;
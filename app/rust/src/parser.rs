@@ -22,26 +22,40 @@ use core::mem::MaybeUninit;
 pub mod action;
 mod address;
 pub mod amount;
+pub mod auction;
+pub mod authorization_data;
+pub mod authorize_request;
 pub mod backref;
 pub mod balance;
+pub mod binding;
 pub mod bytes;
 mod clue_plan;
 pub mod commitment;
+pub mod confirm_address;
+pub mod delegation_token;
+pub mod denom_metadata;
 pub mod detection;
 pub mod effect_hash;
+pub mod epoch;
 mod error;
+pub mod export_fvk;
 pub mod fee;
 pub mod fixpoint;
+pub mod flow_summary;
 pub mod id;
 pub mod identity_key;
+pub mod known_assets;
 pub mod memo;
 pub mod memo_plain_text;
+pub mod message_signature;
 mod note;
 mod note_payload;
 pub mod nullifier;
 mod parameters;
 pub mod penalty;
 mod plans;
+pub mod position;
+pub mod rate_data;
 pub mod reserves;
 pub mod rk;
 pub mod rseed;
@@ -49,7 +63,9 @@ pub mod swap_ciphertext;
 pub mod swap_payload;
 pub mod swap_plaintext;
 pub mod symmetric;
+pub mod trading_function;
 pub mod trading_pair;
+pub mod unbonding_token;
 pub mod validator_identity;
 pub mod value;
 
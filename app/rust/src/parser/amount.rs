@@ -77,6 +77,128 @@ impl Amount {
 
         Ok((output_buffer, total_length))
     }
+
+    /// Writes the amount as a decimal number with `exponent` fractional
+    /// digits, e.g. `1234500` with exponent 6 becomes `1.2345`. Trailing
+    /// fractional zeros are trimmed, as is the point when nothing follows it.
+    pub fn format(&self, exponent: u8, out: &mut [u8]) -> Result<usize, ParserError> {
+        self.write_decimal(exponent, None, out)
+    }
+
+    /// Same as [`Amount::format`], with `separator` inserted between each
+    /// group of three integral digits, e.g. `1,234.5`.
+    pub fn format_with_separator(
+        &self,
+        exponent: u8,
+        separator: u8,
+        out: &mut [u8],
+    ) -> Result<usize, ParserError> {
+        self.write_decimal(exponent, Some(separator), out)
+    }
+
+    fn write_decimal(
+        &self,
+        exponent: u8,
+        separator: Option<u8>,
+        out: &mut [u8],
+    ) -> Result<usize, ParserError> {
+        let mut buffer = itoa::Buffer::new();
+        let digits = buffer.format(self.inner).as_bytes();
+        let exponent = exponent as usize;
+
+        // Split the digits at the decimal point, the integral part being
+        // empty when every digit is fractional.
+        let split = digits.len().saturating_sub(exponent);
+        let (integral, fractional) = digits.split_at(split);
+        let leading_zeros = exponent - fractional.len();
+        let fractional = match fractional.iter().rposition(|d| *d != b'0') {
+            Some(last) => &fractional[..=last],
+            None => &[],
+        };
+
+        let mut pos = 0;
+        let mut push = |byte: u8| -> Result<(), ParserError> {
+            *out.get_mut(pos).ok_or(ParserError::UnexpectedBufferEnd)? = byte;
+            pos += 1;
+            Ok(())
+        };
+
+        if integral.is_empty() {
+            push(b'0')?;
+        }
+        for (i, digit) in integral.iter().enumerate() {
+            if i > 0 && (integral.len() - i) % 3 == 0 {
+                if let Some(separator) = separator {
+                    push(separator)?;
+                }
+            }
+            push(*digit)?;
+        }
+
+        if !fractional.is_empty() {
+            push(b'.')?;
+            for _ in 0..leading_zeros {
+                push(b'0')?;
+            }
+            for digit in fractional {
+                push(*digit)?;
+            }
+        }
+
+        Ok(pos)
+    }
+
+    /// Parses a decimal string as written by [`Amount::format`] or
+    /// [`Amount::format_with_separator`] back into base units.
+    #[cfg(test)]
+    pub fn parse(input: &str, exponent: u8, separator: Option<u8>) -> Result<Self, ParserError> {
+        let (integral, fractional) = match input.split_once('.') {
+            Some((_, "")) => return Err(ParserError::UnexpectedCharacters),
+            Some((integral, fractional)) => (integral, fractional),
+            None => (input, ""),
+        };
+        if integral.is_empty() || fractional.len() > exponent as usize {
+            return Err(ParserError::UnexpectedCharacters);
+        }
+
+        let mut inner: u128 = 0;
+        let mut push = |digit: u8| -> Result<(), ParserError> {
+            if !digit.is_ascii_digit() {
+                return Err(ParserError::UnexpectedCharacters);
+            }
+            inner = inner
+                .checked_mul(10)
+                .and_then(|v| v.checked_add((digit - b'0') as u128))
+                .ok_or(ParserError::ValueOutOfRange)?;
+            Ok(())
+        };
+
+        match separator {
+            Some(separator) => {
+                let separator = [separator];
+                let separator = std::str::from_utf8(&separator)
+                    .map_err(|_| ParserError::UnexpectedCharacters)?;
+                for (i, group) in integral.split(separator).enumerate() {
+                    let valid_len = if i == 0 {
+                        (1..=3).contains(&group.len())
+                    } else {
+                        group.len() == 3
+                    };
+                    if !valid_len {
+                        return Err(ParserError::UnexpectedCharacters);
+                    }
+                    group.bytes().try_for_each(&mut push)?;
+                }
+            }
+            None => integral.bytes().try_for_each(&mut push)?,
+        }
+        fractional.bytes().try_for_each(&mut push)?;
+        for _ in fractional.len()..exponent as usize {
+            push(b'0')?;
+        }
+
+        Ok(Amount { inner })
+    }
 }
 
 impl TryFrom<AmountC> for Amount {
@@ -154,3 +276,100 @@ pub struct AmountC {
     pub lo: u64,
     pub hi: u64,
 }
+
+impl From<Amount> for AmountC {
+    fn from(amount: Amount) -> Self {
+        AmountC {
+            lo: amount.inner as u64,
+            hi: (amount.inner >> 64) as u64,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(inner: u128, exponent: u8, separator: Option<u8>) -> std::string::String {
+        let mut out = [0u8; 64];
+        let amount = Amount { inner };
+        let len = match separator {
+            Some(separator) => amount.format_with_separator(exponent, separator, &mut out),
+            None => amount.format(exponent, &mut out),
+        }
+        .unwrap();
+        std::str::from_utf8(&out[..len]).unwrap().into()
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format(0, 0, None), "0");
+        assert_eq!(format(0, 6, None), "0");
+        assert_eq!(format(1, 6, None), "0.000001");
+        assert_eq!(format(1_000_000, 6, None), "1");
+        assert_eq!(format(1_234_500, 6, None), "1.2345");
+        assert_eq!(format(120, 0, None), "120");
+        assert_eq!(
+            format(5, 40, None),
+            "0.0000000000000000000000000000000000000005"
+        );
+        assert_eq!(format(999_999, 3, Some(b',')), "999.999");
+        assert_eq!(format(1_000_000_000, 3, Some(b',')), "1,000,000");
+        assert_eq!(
+            format(123_456_789_012_345, 6, Some(b',')),
+            "123,456,789.012345"
+        );
+        assert_eq!(
+            format(u128::MAX, 0, Some(b',')),
+            "340,282,366,920,938,463,463,374,607,431,768,211,455"
+        );
+        assert_eq!(
+            format(u128::MAX, 18, None),
+            "340282366920938463463.374607431768211455"
+        );
+
+        let mut out = [0u8; 4];
+        assert_eq!(
+            Amount { inner: 1_234_500 }.format(6, &mut out),
+            Err(ParserError::UnexpectedBufferEnd)
+        );
+    }
+
+    #[test]
+    fn test_format_roundtrip() {
+        for inner in [0, 1, 10, 999, 1000, 1_234_500, 10u128.pow(20), u128::MAX] {
+            for exponent in [0, 1, 3, 6, 18, 38] {
+                for separator in [None, Some(b',')] {
+                    let formatted = format(inner, exponent, separator);
+                    let parsed = Amount::parse(&formatted, exponent, separator).unwrap();
+                    assert_eq!(parsed.inner, inner, "{formatted}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed() {
+        for (input, separator) in [
+            ("", None),
+            (".5", None),
+            ("1.", None),
+            ("1.0000001", None),
+            ("1,000", None),
+            ("-1", None),
+            ("1,00", Some(b',')),
+            ("1000,000", Some(b',')),
+            (",100", Some(b',')),
+        ] {
+            assert_eq!(
+                Amount::parse(input, 6, separator).err(),
+                Some(ParserError::UnexpectedCharacters),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            Amount::parse("340282366920938463463.374607431768211456", 18, None).err(),
+            Some(ParserError::ValueOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,363 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::{DUTCH_AUCTION_NONCE_LEN_BYTES, ID_LEN_BYTES};
+use crate::ffi::bech32::bech32_encode;
+use crate::parser::{
+    amount::{Amount, AmountC},
+    bytes::BytesC,
+    fixpoint::U128x128,
+    id::{Id, IdC},
+    value::{Value, ValueC},
+};
+use crate::protobuf_h::auction_pb::{
+    penumbra_core_component_auction_v1_AuctionId_inner_tag,
+    penumbra_core_component_auction_v1_DutchAuctionDescription_end_height_tag,
+    penumbra_core_component_auction_v1_DutchAuctionDescription_input_tag,
+    penumbra_core_component_auction_v1_DutchAuctionDescription_max_output_tag,
+    penumbra_core_component_auction_v1_DutchAuctionDescription_min_output_tag,
+    penumbra_core_component_auction_v1_DutchAuctionDescription_nonce_tag,
+    penumbra_core_component_auction_v1_DutchAuctionDescription_output_id_tag,
+    penumbra_core_component_auction_v1_DutchAuctionDescription_start_height_tag,
+    penumbra_core_component_auction_v1_DutchAuctionDescription_step_count_tag,
+};
+use crate::utils::protobuf::{encode_proto_bytes_field, encode_proto_number};
+use crate::ParserError;
+use itoa::Buffer;
+
+/// A hash of an auction's immutable description, identifying the auction and its NFT.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct AuctionId(pub [u8; ID_LEN_BYTES]);
+
+impl AuctionId {
+    pub const LEN: usize = ID_LEN_BYTES;
+    pub const PROTO_LEN: usize = Self::LEN + 2;
+    pub const BECH32_PREFIX: &'static str = "pauctid";
+    // prefix, separator, 52 data characters and 6 checksum characters
+    pub const BECH32_LEN: usize = 7 + 1 + 52 + 6;
+
+    pub fn to_proto(&self) -> Result<[u8; Self::PROTO_LEN], ParserError> {
+        let mut proto = [0u8; Self::PROTO_LEN];
+
+        let len = encode_proto_bytes_field(
+            penumbra_core_component_auction_v1_AuctionId_inner_tag as u64,
+            &self.0,
+            &mut proto,
+        )?;
+
+        if len != Self::PROTO_LEN {
+            return Err(ParserError::InvalidLength);
+        }
+
+        Ok(proto)
+    }
+
+    /// Writes the `pauctid1…` bech32m encoding of this ID, returning the number of bytes written.
+    pub fn to_bech32m(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        bech32_encode(Self::BECH32_PREFIX, &self.0, output)
+            .map_err(|_| ParserError::UnexpectedBufferEnd)
+    }
+}
+
+impl TryFrom<IdC> for AuctionId {
+    type Error = ParserError;
+
+    fn try_from(value: IdC) -> Result<Self, Self::Error> {
+        let bytes = value
+            .inner
+            .get_bytes()?
+            .try_into()
+            .map_err(|_| ParserError::InvalidLength)?;
+
+        Ok(AuctionId(bytes))
+    }
+}
+
+/// The NFT tracking ownership and state of an auction.
+///
+/// Its denom is `auctionnft_<seq>_<pauctid1…>`. Sequence `0` marks an opened
+/// auction, `1` a closed one, and every later sequence a withdrawal.
+#[derive(Clone, Copy)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct AuctionNft {
+    pub id: AuctionId,
+    pub seq: u64,
+}
+
+impl AuctionNft {
+    const DENOM_PREFIX: &'static [u8] = b"auctionnft_";
+    // prefix, up to 20 sequence digits, separator and the auction ID
+    pub const MAX_DENOM_LEN: usize = Self::DENOM_PREFIX.len() + 20 + 1 + AuctionId::BECH32_LEN;
+
+    pub fn new(id: AuctionId, seq: u64) -> Self {
+        AuctionNft { id, seq }
+    }
+
+    /// Writes the denom of this NFT, returning the number of bytes written.
+    pub fn denom(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        let mut buffer = Buffer::new();
+        let seq = buffer.format(self.seq).as_bytes();
+
+        let id_start = Self::DENOM_PREFIX.len() + seq.len() + 1;
+        if output.len() < id_start {
+            return Err(ParserError::UnexpectedBufferEnd);
+        }
+
+        output[..Self::DENOM_PREFIX.len()].copy_from_slice(Self::DENOM_PREFIX);
+        output[Self::DENOM_PREFIX.len()..id_start - 1].copy_from_slice(seq);
+        output[id_start - 1] = b'_';
+
+        let id_len = self.id.to_bech32m(&mut output[id_start..])?;
+        Ok(id_start + id_len)
+    }
+
+    pub fn asset_id(&self) -> Result<Id, ParserError> {
+        let mut denom = [0u8; Self::MAX_DENOM_LEN];
+        let len = self.denom(&mut denom)?;
+        let denom = std::str::from_utf8(&denom[..len]).map_err(|_| ParserError::InvalidUtf8)?;
+
+        Id::from_base_denom(denom)
+    }
+
+    /// The single unit of this NFT that an auction action mints or burns.
+    pub fn value(&self) -> Result<Value, ParserError> {
+        Ok(Value {
+            amount: Amount { inner: 1 },
+            asset_id: self.asset_id()?,
+        })
+    }
+}
+
+/// The immutable description of a Dutch auction.
+///
+/// The auction sells `input` for `output_id`, offering `max_output` at
+/// `start_height` and lowering the price in `step_count` steps down to
+/// `min_output` by `end_height`.
+pub struct DutchAuctionDescription {
+    pub input: Value,
+    pub output_id: Id,
+    pub max_output: Amount,
+    pub min_output: Amount,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub step_count: u64,
+    /// A random value used to disambiguate auctions with the same parameters.
+    pub nonce: [u8; DUTCH_AUCTION_NONCE_LEN_BYTES],
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct DutchAuctionDescriptionC {
+    pub has_input: bool,
+    pub input: ValueC,
+    pub has_output_id: bool,
+    pub output_id: IdC,
+    pub has_max_output: bool,
+    pub max_output: AmountC,
+    pub has_min_output: bool,
+    pub min_output: AmountC,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub step_count: u64,
+    pub nonce: BytesC,
+}
+
+impl DutchAuctionDescription {
+    pub const PROTO_LEN: usize = 2
+        + Value::PROTO_LEN
+        + 2
+        + Id::PROTO_LEN
+        + 2 * (1 + Amount::PROTO_LEN)
+        + 3 * 11
+        + 2
+        + DUTCH_AUCTION_NONCE_LEN_BYTES;
+
+    /// Computes the ID of this auction, as in upstream's `DutchAuctionDescription::id()`.
+    pub fn id(&self) -> Result<AuctionId, ParserError> {
+        let mut state = blake2b_simd::Params::new()
+            .personal(b"penumbra_DA_nft")
+            .to_state();
+
+        state.update(&self.nonce);
+        state.update(&self.input.asset_id.to_bytes());
+        state.update(&self.input.amount.to_le_bytes());
+        state.update(&self.max_output.to_le_bytes());
+        state.update(&self.start_height.to_le_bytes());
+        state.update(&self.end_height.to_le_bytes());
+        state.update(&self.step_count.to_le_bytes());
+
+        let hash = state.finalize();
+        let id = hash.as_bytes()[..AuctionId::LEN]
+            .try_into()
+            .map_err(|_| ParserError::InvalidLength)?;
+
+        Ok(AuctionId(id))
+    }
+
+    /// Computes the price offered at `step_index`, in units of output per
+    /// unit of input.
+    ///
+    /// As in upstream's `compute_pq_at_step`, the price falls linearly from
+    /// `max_output / input` at the first step to `min_output / input` at
+    /// step `step_count - 1`, so the last step is the worst price accepted.
+    pub fn price_at_step(&self, step_index: u64) -> Result<U128x128, ParserError> {
+        if self.step_count < 2 || step_index >= self.step_count {
+            return Err(ParserError::ValueOutOfRange);
+        }
+
+        let steps_left = U128x128::from((self.step_count - step_index - 1) as u128);
+        let steps_done = U128x128::from(step_index as u128);
+        let last_step = U128x128::from((self.step_count - 1) as u128);
+
+        // Both sides are scaled up by `step_count - 1` to interpolate without
+        // dividing twice.
+        let target_output_scaled = (steps_left * U128x128::from(self.max_output))?
+            .checked_add(&(steps_done * U128x128::from(self.min_output))?)?;
+        let input_scaled = (last_step * U128x128::from(self.input.amount))?;
+
+        target_output_scaled / input_scaled
+    }
+
+    /// Computes the block height at which step `step_index` takes effect.
+    pub fn step_height(&self, step_index: u64) -> Result<u64, ParserError> {
+        if step_index >= self.step_count {
+            return Err(ParserError::ValueOutOfRange);
+        }
+
+        let step_size = self
+            .end_height
+            .checked_sub(self.start_height)
+            .ok_or(ParserError::ValueOutOfRange)?
+            / self.step_count;
+
+        step_size
+            .checked_mul(step_index)
+            .and_then(|offset| self.start_height.checked_add(offset))
+            .ok_or(ParserError::Overflow)
+    }
+
+    pub fn to_proto(&self) -> Result<([u8; Self::PROTO_LEN], usize), ParserError> {
+        let mut proto = [0u8; Self::PROTO_LEN];
+        let mut offset = 0;
+
+        let (input, input_len) = self.input.to_proto()?;
+        offset += encode_proto_bytes_field(
+            penumbra_core_component_auction_v1_DutchAuctionDescription_input_tag as u64,
+            &input[..input_len],
+            &mut proto[offset..],
+        )?;
+
+        offset += encode_proto_bytes_field(
+            penumbra_core_component_auction_v1_DutchAuctionDescription_output_id_tag as u64,
+            &self.output_id.to_proto()?,
+            &mut proto[offset..],
+        )?;
+
+        // amounts are already length-prefixed
+        for (tag, amount) in [
+            (
+                penumbra_core_component_auction_v1_DutchAuctionDescription_max_output_tag,
+                &self.max_output,
+            ),
+            (
+                penumbra_core_component_auction_v1_DutchAuctionDescription_min_output_tag,
+                &self.min_output,
+            ),
+        ] {
+            let (amount, amount_len) = amount.to_proto()?;
+            if offset + 1 + amount_len > proto.len() {
+                return Err(ParserError::InvalidLength);
+            }
+            proto[offset] = ((tag << 3) | 2) as u8;
+            proto[offset + 1..offset + 1 + amount_len].copy_from_slice(&amount[..amount_len]);
+            offset += 1 + amount_len;
+        }
+
+        for (tag, value) in [
+            (
+                penumbra_core_component_auction_v1_DutchAuctionDescription_start_height_tag,
+                self.start_height,
+            ),
+            (
+                penumbra_core_component_auction_v1_DutchAuctionDescription_end_height_tag,
+                self.end_height,
+            ),
+            (
+                penumbra_core_component_auction_v1_DutchAuctionDescription_step_count_tag,
+                self.step_count,
+            ),
+        ] {
+            if value != 0 {
+                offset += encode_proto_number(tag as u64, value, &mut proto[offset..])?;
+            }
+        }
+
+        offset += encode_proto_bytes_field(
+            penumbra_core_component_auction_v1_DutchAuctionDescription_nonce_tag as u64,
+            &self.nonce,
+            &mut proto[offset..],
+        )?;
+
+        Ok((proto, offset))
+    }
+}
+
+impl TryFrom<DutchAuctionDescriptionC> for DutchAuctionDescription {
+    type Error = ParserError;
+
+    fn try_from(value: DutchAuctionDescriptionC) -> Result<Self, Self::Error> {
+        if !value.has_input
+            || !value.has_output_id
+            || !value.has_max_output
+            || !value.has_min_output
+        {
+            return Err(ParserError::MissingField);
+        }
+
+        let nonce = value
+            .nonce
+            .get_bytes()?
+            .try_into()
+            .map_err(|_| ParserError::InvalidLength)?;
+
+        let description = DutchAuctionDescription {
+            input: Value::try_from(value.input)?,
+            output_id: Id::try_from(value.output_id)?,
+            max_output: Amount::try_from(value.max_output)?,
+            min_output: Amount::try_from(value.min_output)?,
+            start_height: value.start_height,
+            end_height: value.end_height,
+            step_count: value.step_count,
+            nonce,
+        };
+
+        // An auction must sell a non-zero input for a different asset, over
+        // at least two steps of a non-empty height range, at a price that
+        // falls from max_output to min_output.
+        if description.input.amount.inner == 0
+            || description.input.asset_id.0 == description.output_id.0
+            || description.max_output.inner <= description.min_output.inner
+            || description.step_count < 2
+            || description.end_height <= description.start_height
+        {
+            return Err(ParserError::InvalidDutchAuction);
+        }
+
+        Ok(description)
+    }
+}
@@ -0,0 +1,178 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::{EFFECT_HASH_LEN, SIGNATURE_LEN};
+use crate::protobuf_h::num_pb::PB_LTYPE_UVARINT;
+use crate::protobuf_h::transaction_pb::{
+    penumbra_core_transaction_v1_AuthorizationData_delegator_vote_auths_tag,
+    penumbra_core_transaction_v1_AuthorizationData_effect_hash_tag,
+    penumbra_core_transaction_v1_AuthorizationData_lqt_vote_auths_tag,
+    penumbra_core_transaction_v1_AuthorizationData_spend_auths_tag,
+    penumbra_core_txhash_v1_EffectHash_inner_tag,
+    penumbra_crypto_decaf377_rdsa_v1_SpendAuthSignature_inner_tag,
+};
+use crate::utils::protobuf::{encode_proto_bytes_field, encode_proto_field};
+use crate::ParserError;
+
+/// The authorizing signatures of a transaction plan, encoded as a
+/// `penumbra.core.transaction.v1.AuthorizationData` message.
+///
+/// Every field has the same length, so the message can also be encoded a
+/// few fields at a time: leaving out the effect hash encodes a later part
+/// of it, and the parts concatenate to the whole message.
+pub struct AuthorizationData<'a> {
+    pub effect_hash: Option<&'a [u8; EFFECT_HASH_LEN]>,
+    pub spend_auths: &'a [[u8; SIGNATURE_LEN]],
+    pub delegator_vote_auths: &'a [[u8; SIGNATURE_LEN]],
+    pub lqt_vote_auths: &'a [[u8; SIGNATURE_LEN]],
+}
+
+impl<'a> AuthorizationData<'a> {
+    // Both the effect hash and the signatures are 64 bytes wrapped in a
+    // message with a single bytes field, which all take two bytes of
+    // tag and length, inside and out.
+    const FIELD_LEN: usize = 2 + 2 + SIGNATURE_LEN;
+
+    pub fn encoded_len(&self) -> usize {
+        let signatures =
+            self.spend_auths.len() + self.delegator_vote_auths.len() + self.lqt_vote_auths.len();
+        Self::FIELD_LEN * (self.effect_hash.is_some() as usize + signatures)
+    }
+
+    /// Writes the message into `output`, returning the number of bytes written.
+    /// Fields come in tag order and empty repeated fields are left out, so
+    /// the encoding is canonical.
+    pub fn encode(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        if output.len() < self.encoded_len() {
+            return Err(ParserError::InvalidLength);
+        }
+
+        let mut offset = 0;
+        if let Some(effect_hash) = self.effect_hash {
+            offset += encode_wrapped(
+                penumbra_core_transaction_v1_AuthorizationData_effect_hash_tag,
+                penumbra_core_txhash_v1_EffectHash_inner_tag,
+                effect_hash,
+                output,
+            )?;
+        }
+
+        let repeated = [
+            (
+                penumbra_core_transaction_v1_AuthorizationData_spend_auths_tag,
+                self.spend_auths,
+            ),
+            (
+                penumbra_core_transaction_v1_AuthorizationData_delegator_vote_auths_tag,
+                self.delegator_vote_auths,
+            ),
+            (
+                penumbra_core_transaction_v1_AuthorizationData_lqt_vote_auths_tag,
+                self.lqt_vote_auths,
+            ),
+        ];
+        for (tag, signatures) in repeated {
+            for signature in signatures {
+                offset += encode_wrapped(
+                    tag,
+                    penumbra_crypto_decaf377_rdsa_v1_SpendAuthSignature_inner_tag,
+                    signature,
+                    &mut output[offset..],
+                )?;
+            }
+        }
+
+        Ok(offset)
+    }
+}
+
+/// Encodes `inner` as the only bytes field of a message, itself the field
+/// `tag` of the enclosing message.
+fn encode_wrapped(
+    tag: u32,
+    inner_tag: u32,
+    inner: &[u8],
+    output: &mut [u8],
+) -> Result<usize, ParserError> {
+    let mut message = [0u8; AuthorizationData::FIELD_LEN];
+    let message_len = encode_proto_bytes_field(inner_tag as u64, inner, &mut message)?;
+
+    let len = encode_proto_field(tag as u64, PB_LTYPE_UVARINT as u64, message_len, output)?;
+    if len + message_len > output.len() {
+        return Err(ParserError::InvalidLength);
+    }
+
+    output[len..len + message_len].copy_from_slice(&message[..message_len]);
+    Ok(len + message_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_authorization_data_encoding() {
+        let effect_hash = [0xeeu8; EFFECT_HASH_LEN];
+        let spend_auths = [[0x01u8; SIGNATURE_LEN], [0x02u8; SIGNATURE_LEN]];
+        let lqt_vote_auths = [[0x04u8; SIGNATURE_LEN]];
+        let data = AuthorizationData {
+            effect_hash: Some(&effect_hash),
+            spend_auths: &spend_auths,
+            delegator_vote_auths: &[],
+            lqt_vote_auths: &lqt_vote_auths,
+        };
+
+        let mut output = [0u8; 512];
+        let len = data.encode(&mut output).unwrap();
+        assert_eq!(len, data.encoded_len());
+        assert_eq!(len, 4 * 68);
+
+        let field = |index: usize| &output[index * 68..(index + 1) * 68];
+        let expected = |key: u8, byte: u8| {
+            let mut field = [byte; 68];
+            field[..4].copy_from_slice(&[key, 66, 0x0a, 64]);
+            field
+        };
+        assert_eq!(field(0), expected(0x0a, 0xee));
+        assert_eq!(field(1), expected(0x12, 0x01));
+        assert_eq!(field(2), expected(0x12, 0x02));
+        assert_eq!(field(3), expected(0x22, 0x04));
+
+        assert_eq!(
+            data.encode(&mut output[..len - 1]),
+            Err(ParserError::InvalidLength)
+        );
+
+        // the same message, encoded in two parts
+        let head = AuthorizationData {
+            effect_hash: Some(&effect_hash),
+            spend_auths: &spend_auths[..1],
+            delegator_vote_auths: &[],
+            lqt_vote_auths: &[],
+        };
+        let tail = AuthorizationData {
+            effect_hash: None,
+            spend_auths: &spend_auths[1..],
+            delegator_vote_auths: &[],
+            lqt_vote_auths: &lqt_vote_auths,
+        };
+        let mut parts = [0u8; 512];
+        let head_len = head.encode(&mut parts).unwrap();
+        let tail_len = tail.encode(&mut parts[head_len..]).unwrap();
+        assert_eq!(head_len + tail_len, len);
+        assert_eq!(parts[..len], output[..len]);
+    }
+}
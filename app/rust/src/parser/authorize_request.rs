@@ -0,0 +1,291 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+//! A custody `AuthorizeRequest`: a transaction plan together with the
+//! pre-authorizations of co-signers who approved it beforehand.
+
+use crate::constants::{ED25519_SIGNATURE_LEN, ED25519_VK_LEN, PRE_AUTHORIZATIONS_QTY};
+use crate::protobuf_h::custody_pb::{
+    penumbra_custody_v1_AuthorizeRequest_plan_tag,
+    penumbra_custody_v1_AuthorizeRequest_pre_authorizations_tag,
+    penumbra_custody_v1_PreAuthorization_Ed25519_sig_tag,
+    penumbra_custody_v1_PreAuthorization_Ed25519_vk_tag,
+    penumbra_custody_v1_PreAuthorization_ed25519_tag,
+};
+use crate::utils::protobuf::{fields, set_once, FieldValue};
+use crate::ParserError;
+use arrayvec::ArrayVec;
+
+// Field tags as consts so they can be matched on.
+const PLAN_TAG: u32 = penumbra_custody_v1_AuthorizeRequest_plan_tag;
+const PRE_AUTHORIZATIONS_TAG: u32 = penumbra_custody_v1_AuthorizeRequest_pre_authorizations_tag;
+const ED25519_TAG: u32 = penumbra_custody_v1_PreAuthorization_ed25519_tag;
+const ED25519_VK_TAG: u32 = penumbra_custody_v1_PreAuthorization_Ed25519_vk_tag;
+const ED25519_SIG_TAG: u32 = penumbra_custody_v1_PreAuthorization_Ed25519_sig_tag;
+
+/// An Ed25519 signature by a co-signer over the encoded transaction plan.
+#[derive(Clone, Copy)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct PreAuthorization<'a> {
+    pub vk: &'a [u8; ED25519_VK_LEN],
+    pub sig: &'a [u8; ED25519_SIGNATURE_LEN],
+}
+
+impl<'a> PreAuthorization<'a> {
+    fn parse(input: &'a [u8]) -> Result<Self, ParserError> {
+        let mut ed25519 = None;
+        for field in fields(input) {
+            if let (ED25519_TAG, value) = field? {
+                let FieldValue::Bytes(bytes) = value else {
+                    return Err(ParserError::UnexpectedType);
+                };
+                set_once(&mut ed25519, bytes)?;
+            }
+        }
+        // Ed25519 is the only kind of pre-authorization there is.
+        let ed25519 = ed25519.ok_or(ParserError::MissingField)?;
+
+        let mut vk = None;
+        let mut sig = None;
+        for field in fields(ed25519) {
+            match field? {
+                (ED25519_VK_TAG, FieldValue::Bytes(bytes)) => set_once(
+                    &mut vk,
+                    <&[u8; ED25519_VK_LEN]>::try_from(bytes)
+                        .map_err(|_| ParserError::InvalidPubkeyEncoding)?,
+                )?,
+                (ED25519_SIG_TAG, FieldValue::Bytes(bytes)) => set_once(
+                    &mut sig,
+                    <&[u8; ED25519_SIGNATURE_LEN]>::try_from(bytes)
+                        .map_err(|_| ParserError::InvalidSignatureLen)?,
+                )?,
+                (ED25519_VK_TAG | ED25519_SIG_TAG, _) => return Err(ParserError::UnexpectedType),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            vk: vk.ok_or(ParserError::MissingField)?,
+            sig: sig.ok_or(ParserError::MissingField)?,
+        })
+    }
+}
+
+/// A `penumbra.custody.v1.AuthorizeRequest`, split into the encoded plan and
+/// the pre-authorizations attached to it.
+pub struct AuthorizeRequest<'a> {
+    pub plan: &'a [u8],
+    pre_authorizations: ArrayVec<PreAuthorization<'a>, PRE_AUTHORIZATIONS_QTY>,
+}
+
+impl<'a> AuthorizeRequest<'a> {
+    /// Parses the request. A co-signer may appear only once, and the plan is
+    /// left encoded for the regular plan parser.
+    pub fn parse(input: &'a [u8]) -> Result<Self, ParserError> {
+        let mut plan = None;
+        let mut pre_authorizations = ArrayVec::<PreAuthorization, PRE_AUTHORIZATIONS_QTY>::new();
+
+        for field in fields(input) {
+            match field? {
+                (PLAN_TAG, FieldValue::Bytes(bytes)) => set_once(&mut plan, bytes)?,
+                (PRE_AUTHORIZATIONS_TAG, FieldValue::Bytes(bytes)) => {
+                    let pre_authorization = PreAuthorization::parse(bytes)?;
+                    if pre_authorizations
+                        .iter()
+                        .any(|existing| existing.vk == pre_authorization.vk)
+                    {
+                        return Err(ParserError::DuplicatedField);
+                    }
+                    pre_authorizations
+                        .try_push(pre_authorization)
+                        .map_err(|_| ParserError::Overflow)?;
+                }
+                (PLAN_TAG | PRE_AUTHORIZATIONS_TAG, _) => return Err(ParserError::UnexpectedType),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            plan: plan.ok_or(ParserError::MissingField)?,
+            pre_authorizations,
+        })
+    }
+
+    pub fn pre_authorizations(&self) -> &[PreAuthorization<'a>] {
+        &self.pre_authorizations
+    }
+
+    /// Checks every pre-authorization signature over the encoded plan with
+    /// `verify`, which takes a key, a message and a signature.
+    pub fn verify(
+        &self,
+        verify: impl Fn(&[u8; ED25519_VK_LEN], &[u8], &[u8; ED25519_SIGNATURE_LEN]) -> bool,
+    ) -> Result<(), ParserError> {
+        for pre_authorization in self.pre_authorizations() {
+            if !verify(pre_authorization.vk, self.plan, pre_authorization.sig) {
+                return Err(ParserError::InvalidSignature);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The keys of the co-signers whose pre-authorizations checked out, mirroring
+/// `pre_authorizations_t`.
+#[repr(C)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct PreAuthorizationsC {
+    pub qty: u8,
+    pub vks: [[u8; ED25519_VK_LEN]; PRE_AUTHORIZATIONS_QTY],
+}
+
+impl PreAuthorizationsC {
+    pub fn fill(&mut self, request: &AuthorizeRequest) {
+        let pre_authorizations = request.pre_authorizations();
+        for (vk, pre_authorization) in self.vks.iter_mut().zip(pre_authorizations) {
+            *vk = *pre_authorization.vk;
+        }
+        self.qty = pre_authorizations.len() as u8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bolos::verify_ed25519;
+    use crate::utils::encode_varint;
+    use crate::utils::protobuf::WIRE_LEN;
+    use std::vec::Vec;
+
+    fn field(tag: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut field = encode_varint(((tag as u64) << 3) | WIRE_LEN);
+        field.extend(encode_varint(bytes.len() as u64));
+        field.extend_from_slice(bytes);
+        field
+    }
+
+    fn pre_authorization(vk: u8, sig: u8) -> Vec<u8> {
+        let mut ed25519 = field(ED25519_VK_TAG, &[vk; ED25519_VK_LEN]);
+        ed25519.extend(field(ED25519_SIG_TAG, &[sig; ED25519_SIGNATURE_LEN]));
+        field(PRE_AUTHORIZATIONS_TAG, &field(ED25519_TAG, &ed25519))
+    }
+
+    // Stands in for Ed25519: accepts a signature whose bytes equal the key's.
+    fn verify(vk: &[u8; ED25519_VK_LEN], _plan: &[u8], sig: &[u8; ED25519_SIGNATURE_LEN]) -> bool {
+        sig.iter().all(|byte| *byte == vk[0])
+    }
+
+    #[test]
+    fn test_parse_authorize_request() {
+        let plan = [0x0a, 0x02, 0x08, 0x01];
+        let mut request = field(PLAN_TAG, &plan);
+        request.extend(pre_authorization(1, 1));
+        request.extend(pre_authorization(2, 2));
+
+        let parsed = AuthorizeRequest::parse(&request).unwrap();
+        assert_eq!(parsed.plan, &plan);
+        assert_eq!(parsed.pre_authorizations().len(), 2);
+        assert_eq!(parsed.pre_authorizations()[1].vk, &[2; ED25519_VK_LEN]);
+        assert!(parsed.verify(verify).is_ok());
+
+        let mut out = PreAuthorizationsC {
+            qty: 0,
+            vks: [[0; ED25519_VK_LEN]; PRE_AUTHORIZATIONS_QTY],
+        };
+        out.fill(&parsed);
+        assert_eq!(out.qty, 2);
+        assert_eq!(out.vks[0], [1; ED25519_VK_LEN]);
+
+        // A bare plan is a request without pre-authorizations.
+        let bare = field(PLAN_TAG, &plan);
+        let bare = AuthorizeRequest::parse(&bare).unwrap();
+        assert!(bare.pre_authorizations().is_empty());
+    }
+
+    #[test]
+    fn test_authorize_request_ed25519() {
+        // RFC 8032, section 7.1, test 3, with its message standing in for the plan
+        let plan = hex::decode("af82").unwrap();
+        let vk = hex::decode("fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025")
+            .unwrap();
+        let mut sig = hex::decode("6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a").unwrap();
+
+        let request = |sig: &[u8]| {
+            let mut ed25519 = field(ED25519_VK_TAG, &vk);
+            ed25519.extend(field(ED25519_SIG_TAG, sig));
+            let mut request = field(PLAN_TAG, &plan);
+            request.extend(field(PRE_AUTHORIZATIONS_TAG, &field(ED25519_TAG, &ed25519)));
+            request
+        };
+
+        let signed = request(&sig);
+        let parsed = AuthorizeRequest::parse(&signed).unwrap();
+        assert!(parsed.verify(verify_ed25519).is_ok());
+
+        sig[63] ^= 0x01;
+        let forged = request(&sig);
+        let parsed = AuthorizeRequest::parse(&forged).unwrap();
+        assert_eq!(
+            parsed.verify(verify_ed25519),
+            Err(ParserError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_parse_authorize_request_rejected() {
+        let plan = field(PLAN_TAG, &[0x0a, 0x00]);
+
+        let mut forged = plan.clone();
+        forged.extend(pre_authorization(1, 1));
+        forged.extend(pre_authorization(2, 3));
+        let parsed = AuthorizeRequest::parse(&forged).unwrap();
+        assert_eq!(parsed.verify(verify), Err(ParserError::InvalidSignature));
+
+        let mut duplicated = plan.clone();
+        duplicated.extend(pre_authorization(1, 1));
+        duplicated.extend(pre_authorization(1, 1));
+        assert_eq!(
+            AuthorizeRequest::parse(&duplicated).err(),
+            Some(ParserError::DuplicatedField)
+        );
+
+        let mut too_many = plan.clone();
+        for vk in 0..=PRE_AUTHORIZATIONS_QTY as u8 {
+            too_many.extend(pre_authorization(vk, vk));
+        }
+        assert_eq!(
+            AuthorizeRequest::parse(&too_many).err(),
+            Some(ParserError::Overflow)
+        );
+
+        let mut short_vk = field(ED25519_VK_TAG, &[1; ED25519_VK_LEN - 1]);
+        short_vk.extend(field(ED25519_SIG_TAG, &[1; ED25519_SIGNATURE_LEN]));
+        let mut request = plan.clone();
+        request.extend(field(
+            PRE_AUTHORIZATIONS_TAG,
+            &field(ED25519_TAG, &short_vk),
+        ));
+        assert_eq!(
+            AuthorizeRequest::parse(&request).err(),
+            Some(ParserError::InvalidPubkeyEncoding)
+        );
+
+        assert_eq!(
+            AuthorizeRequest::parse(&pre_authorization(1, 1)).err(),
+            Some(ParserError::MissingField)
+        );
+    }
+}
@@ -14,58 +14,108 @@
 *  limitations under the License.
 ********************************************************************************/
 
+use crate::constants::{BALANCE_ASSETS_QTY, ID_LEN_BYTES};
 use crate::parser::{
+    amount::{Amount, AmountC},
     commitment::Commitment,
+    id::Id,
     value::{Imbalance, Sign, Value},
 };
 use crate::ParserError;
-use decaf377::Fr;
-
-// Only ten imbalances are supported for now
-const IMBALANCES_SIZE: usize = 10;
+use arrayvec::ArrayVec;
+use decaf377::{Fq, Fr};
 
+/// A multi-asset value balance.
+///
+/// Required and provided amounts of the same asset are netted against each
+/// other, so every asset appears at most once. An asset whose amounts cancel
+/// out keeps a zero entry: it no longer shows up as an imbalance, but the
+/// balance still counts as populated when it is committed to.
 #[derive(Clone)]
 #[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
 pub struct Balance {
-    pub imbalances: [Option<Imbalance>; IMBALANCES_SIZE],
+    imbalances: ArrayVec<Imbalance, BALANCE_ASSETS_QTY>,
 }
 
 impl Balance {
     pub fn new() -> Self {
         Balance {
-            imbalances: [const { None }; IMBALANCES_SIZE],
+            imbalances: ArrayVec::new(),
         }
     }
 
+    pub fn imbalances(&self) -> impl Iterator<Item = &Imbalance> {
+        self.imbalances
+            .iter()
+            .filter(|imbalance| imbalance.value.amount.inner != 0)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.imbalances().next().is_none()
+    }
+
     pub fn insert(&mut self, imbalance: Imbalance) -> Result<(), ParserError> {
-        for slot in &mut self.imbalances {
-            if slot.is_none() {
-                *slot = Some(imbalance);
-                return Ok(());
-            }
+        self.add(&imbalance.value, imbalance.sign)
+    }
+
+    pub fn add(&mut self, rhs: &Value, sign: Sign) -> Result<(), ParserError> {
+        let Some(index) = self
+            .imbalances
+            .iter()
+            .position(|imbalance| imbalance.value.asset_id == rhs.asset_id)
+        else {
+            return self
+                .imbalances
+                .try_push(Imbalance {
+                    value: rhs.clone(),
+                    sign,
+                })
+                .map_err(|_| ParserError::Overflow);
+        };
+
+        let existing = &mut self.imbalances[index];
+        let (current, added) = (existing.value.amount.inner, rhs.amount.inner);
+        if existing.sign == sign {
+            existing.value.amount.inner = current
+                .checked_add(added)
+                .ok_or(ParserError::OperationOverflows)?;
+        } else if current > added {
+            existing.value.amount.inner = current - added;
+        } else {
+            existing.value.amount.inner = added - current;
+            existing.sign = sign;
         }
-        Err(ParserError::InvalidLength)
+
+        Ok(())
     }
 
+    /// Nets every imbalance of `other` into `self`.
+    pub fn merge(&mut self, other: &Balance) -> Result<(), ParserError> {
+        for imbalance in other.imbalances() {
+            self.add(&imbalance.value, imbalance.sign.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Commits to the balance. A zero value still commits to its blinding
+    /// factor, but a balance nothing was ever added to is rejected.
     pub fn commit(&self, blinding_factor: Fr) -> Result<Commitment, ParserError> {
-        if !self.has_valid_imbalance() {
+        if self.imbalances.is_empty() {
             return Err(ParserError::InvalidLength);
         }
 
         let mut commitment = decaf377::Element::IDENTITY;
 
-        for imbalance in self.imbalances.iter().flatten() {
+        for imbalance in self.imbalances() {
             let g_v = imbalance.value.asset_id.value_generator();
             let amount_fr: Fr = Into::into(imbalance.value.amount);
 
-            if amount_fr.ne(&Fr::ZERO) {
-                match imbalance.sign {
-                    Sign::Required => {
-                        commitment -= g_v * amount_fr;
-                    }
-                    Sign::Provided => {
-                        commitment += g_v * amount_fr;
-                    }
+            match imbalance.sign {
+                Sign::Required => {
+                    commitment -= g_v * amount_fr;
+                }
+                Sign::Provided => {
+                    commitment += g_v * amount_fr;
                 }
             }
         }
@@ -75,33 +125,170 @@ impl Balance {
 
         Ok(commitment.into())
     }
+}
 
-    fn has_valid_imbalance(&self) -> bool {
-        self.imbalances.iter().any(|slot| slot.is_some())
+impl Default for Balance {
+    fn default() -> Self {
+        Self::new()
     }
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct ImbalanceC {
+    pub asset_id: [u8; ID_LEN_BYTES],
+    pub amount: AmountC,
+    pub provided: bool,
+}
 
-    pub fn add(&self, rhs: &Value, sign: Sign) -> Result<Balance, ParserError> {
-        let mut new_balance = self.clone();
+/// A `Balance` laid out so that C can keep it across calls.
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct BalanceC {
+    pub qty: u8,
+    pub imbalances: [ImbalanceC; BALANCE_ASSETS_QTY],
+}
 
-        for existing_imbalance in &mut new_balance.imbalances.iter_mut().flatten() {
-            if existing_imbalance.value.asset_id == rhs.asset_id && existing_imbalance.sign == sign
-            {
-                existing_imbalance.value.amount = existing_imbalance.value.amount + rhs.amount;
-                return Ok(new_balance);
-            }
+impl TryFrom<&BalanceC> for Balance {
+    type Error = ParserError;
+
+    fn try_from(value: &BalanceC) -> Result<Self, Self::Error> {
+        let qty = value.qty as usize;
+        if qty > BALANCE_ASSETS_QTY {
+            return Err(ParserError::InvalidLength);
         }
 
-        new_balance.insert(Imbalance {
-            value: rhs.clone(),
-            sign,
-        })?;
+        let mut balance = Balance::new();
+        for imbalance in &value.imbalances[..qty] {
+            let value = Value {
+                amount: Amount::try_from(imbalance.amount.clone())?,
+                asset_id: Id(Fq::from_le_bytes_mod_order(&imbalance.asset_id)),
+            };
+            let sign = if imbalance.provided {
+                Sign::Provided
+            } else {
+                Sign::Required
+            };
+            balance.add(&value, sign)?;
+        }
+        Ok(balance)
+    }
+}
 
-        Ok(new_balance)
+impl From<&Balance> for BalanceC {
+    fn from(balance: &Balance) -> Self {
+        let mut imbalances = balance.imbalances();
+        BalanceC {
+            qty: balance.imbalances().count() as u8,
+            imbalances: core::array::from_fn(|_| match imbalances.next() {
+                Some(imbalance) => ImbalanceC {
+                    asset_id: imbalance.value.asset_id.to_bytes(),
+                    amount: imbalance.value.amount.into(),
+                    provided: imbalance.sign == Sign::Provided,
+                },
+                None => ImbalanceC {
+                    asset_id: [0; ID_LEN_BYTES],
+                    amount: AmountC { lo: 0, hi: 0 },
+                    provided: false,
+                },
+            }),
+        }
     }
 }
 
-impl Default for Balance {
-    fn default() -> Self {
-        Self::new()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(asset: u64, amount: u128) -> Value {
+        Value {
+            amount: Amount { inner: amount },
+            asset_id: Id(Fq::from(asset)),
+        }
+    }
+
+    #[test]
+    fn test_balance_nets_per_asset() {
+        let mut balance = Balance::new();
+        balance.add(&value(1, 100), Sign::Provided).unwrap();
+        balance.add(&value(2, 40), Sign::Required).unwrap();
+        balance.add(&value(1, 30), Sign::Required).unwrap();
+        balance.add(&value(3, 0), Sign::Required).unwrap();
+
+        let imbalances: std::vec::Vec<_> = balance.imbalances().collect();
+        assert_eq!(imbalances.len(), 2);
+        assert_eq!(imbalances[0].value.amount.inner, 70);
+        assert_eq!(imbalances[0].sign, Sign::Provided);
+
+        balance.add(&value(1, 100), Sign::Required).unwrap();
+        let flipped = balance.imbalances().next().unwrap();
+        assert_eq!(flipped.value.amount.inner, 30);
+        assert_eq!(flipped.sign, Sign::Required);
+
+        let mut other = Balance::new();
+        other.add(&value(1, 30), Sign::Provided).unwrap();
+        other.add(&value(2, 40), Sign::Provided).unwrap();
+        balance.merge(&other).unwrap();
+        assert!(balance.is_zero());
+    }
+
+    #[test]
+    fn test_balance_commit() {
+        assert_eq!(
+            Balance::new().commit(Fr::from(7u64)).err(),
+            Some(ParserError::InvalidLength)
+        );
+
+        let mut zero = Balance::new();
+        zero.add(&value(1, 0), Sign::Required).unwrap();
+        assert!(zero.is_zero());
+
+        let mut cancelled = Balance::new();
+        cancelled.add(&value(2, 5), Sign::Provided).unwrap();
+        cancelled.add(&value(2, 5), Sign::Required).unwrap();
+        assert!(cancelled.is_zero());
+
+        let expected = zero.commit(Fr::from(7u64)).unwrap().bytes_compress();
+        let actual = cancelled.commit(Fr::from(7u64)).unwrap().bytes_compress();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_balance_limits() {
+        let mut balance = Balance::new();
+        balance.add(&value(1, u128::MAX), Sign::Provided).unwrap();
+        assert_eq!(
+            balance.add(&value(1, 1), Sign::Provided),
+            Err(ParserError::OperationOverflows)
+        );
+        assert!(balance.add(&value(1, u128::MAX), Sign::Required).is_ok());
+        assert!(balance.is_zero());
+
+        for asset in 0..BALANCE_ASSETS_QTY as u64 {
+            balance.add(&value(asset, 1), Sign::Required).unwrap();
+        }
+        assert_eq!(
+            balance.add(&value(BALANCE_ASSETS_QTY as u64, 1), Sign::Required),
+            Err(ParserError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_balance_c_roundtrip() {
+        let mut balance = Balance::new();
+        balance.add(&value(7, 5), Sign::Provided).unwrap();
+        balance.add(&value(9, 1 << 70), Sign::Required).unwrap();
+
+        let balance_c = BalanceC::from(&balance);
+        assert_eq!(balance_c.qty, 2);
+        assert!(balance_c.imbalances[0].provided);
+
+        let roundtrip = Balance::try_from(&balance_c).unwrap();
+        assert_eq!(
+            roundtrip.commit(Fr::ZERO).unwrap().bytes_compress(),
+            balance.commit(Fr::ZERO).unwrap().bytes_compress()
+        );
     }
 }
@@ -0,0 +1,152 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+//! Binding signature over a whole transaction.
+//!
+//! Every action commits to its balance with a synthetic blinding factor. The
+//! sum of those factors is the binding signing key, and the sum of the balance
+//! commitments, minus the unblinded fee, is its verification key exactly when
+//! the transaction balances.
+
+use crate::constants::KEY_LEN;
+use crate::parser::{commitment::Commitment, fee::Fee};
+use crate::ParserError;
+use decaf377::{Element, Encoding, Fr};
+use decaf377_rdsa::{Binding, Signature, SigningKey, VerificationKey};
+
+/// Running sums of the blinding factors and balance commitments of the
+/// actions seen so far. An all-zero value is the empty sum, as zero encodes
+/// the identity element.
+#[repr(C)]
+#[derive(Clone, Default)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct BindingC {
+    pub blinding: [u8; KEY_LEN],
+    pub commitment: [u8; Commitment::LEN],
+}
+
+impl BindingC {
+    pub fn add(&mut self, commitment: &Commitment, blinding: Fr) -> Result<(), ParserError> {
+        let commitment = self.commitment()? + decompress(commitment.bytes_compress())?;
+        self.commitment = commitment.vartime_compress().0;
+        self.blinding = (self.blinding() + blinding).to_bytes();
+        Ok(())
+    }
+
+    fn blinding(&self) -> Fr {
+        Fr::from_le_bytes_mod_order(&self.blinding)
+    }
+
+    fn commitment(&self) -> Result<Element, ParserError> {
+        decompress(self.commitment)
+    }
+
+    pub fn signing_key(&self) -> SigningKey<Binding> {
+        SigningKey::new_from_field(self.blinding())
+    }
+
+    /// The sum of the balance commitments together with the unblinded fee
+    /// commitment, which is what validators check the binding signature
+    /// against. The fee commits as required value, so adding it takes the fee
+    /// out of the balance.
+    pub fn verification_key(&self, fee: &Fee) -> Result<VerificationKey<Binding>, ParserError> {
+        let fee = decompress(fee.commit(Fr::ZERO)?.bytes_compress())?;
+        let bvk = (self.commitment()? + fee).vartime_compress().0;
+
+        VerificationKey::try_from(bvk).map_err(|_| ParserError::InvalidSignature)
+    }
+
+    /// Signs the effect hash and checks the signature against the verification
+    /// key, so a transaction that does not balance is caught on the device.
+    pub fn sign(&self, fee: &Fee, effect_hash: &[u8]) -> Result<Signature<Binding>, ParserError> {
+        use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+        let mut rng = ChaCha20Rng::from_seed(self.blinding);
+        let signature = self.signing_key().sign(&mut rng, effect_hash);
+
+        self.verification_key(fee)?
+            .verify(effect_hash, &signature)
+            .map_err(|_| ParserError::BindingSignatureMismatch)?;
+
+        Ok(signature)
+    }
+}
+
+fn decompress(bytes: [u8; Commitment::LEN]) -> Result<Element, ParserError> {
+    Encoding(bytes)
+        .vartime_decompress()
+        .map_err(|_| ParserError::InvalidSignature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::amount::Amount;
+    use crate::parser::balance::Balance;
+    use crate::parser::id::Id;
+    use crate::parser::value::{Sign, Value};
+    use decaf377::Fq;
+
+    fn value(amount: u128) -> Value {
+        Value {
+            amount: Amount { inner: amount },
+            asset_id: Id(Fq::from(1u64)),
+        }
+    }
+
+    fn commitment(amount: u128, sign: Sign, blinding: u64) -> Commitment {
+        let mut balance = Balance::new();
+        balance.add(&value(amount), sign).unwrap();
+        balance.commit(Fr::from(blinding)).unwrap()
+    }
+
+    #[test]
+    fn test_binding_signature() {
+        let effect_hash = [0x42u8; 64];
+
+        let mut binding = BindingC::default();
+        binding
+            .add(&commitment(1500, Sign::Provided, 11), Fr::from(11u64))
+            .unwrap();
+        binding
+            .add(&commitment(1000, Sign::Required, 29), Fr::from(29u64))
+            .unwrap();
+        binding
+            .add(&commitment(0, Sign::Required, 0), Fr::ZERO)
+            .unwrap();
+
+        let signature = binding.sign(&Fee(value(500)), &effect_hash).unwrap();
+        binding
+            .verification_key(&Fee(value(500)))
+            .unwrap()
+            .verify(&effect_hash, &signature)
+            .unwrap();
+
+        assert_eq!(
+            binding.sign(&Fee(value(400)), &effect_hash).err(),
+            Some(ParserError::BindingSignatureMismatch)
+        );
+
+        // A blinding factor that does not match its commitment also breaks it.
+        binding
+            .add(&commitment(0, Sign::Required, 5), Fr::from(6u64))
+            .unwrap();
+        assert_eq!(
+            binding.sign(&Fee(value(500)), &effect_hash).err(),
+            Some(ParserError::BindingSignatureMismatch)
+        );
+    }
+}
@@ -0,0 +1,226 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+//! The custody `ConfirmAddress` exchange: the host names an address index,
+//! and the device derives the address, shows it and hands it back.
+
+use crate::address::{Address, AddressIndex};
+use crate::keys::fvk::FullViewingKey;
+use crate::protobuf_h::custody_pb::{
+    penumbra_core_keys_v1_AddressIndex_account_tag,
+    penumbra_core_keys_v1_AddressIndex_randomizer_tag, penumbra_core_keys_v1_Address_inner_tag,
+    penumbra_custody_v1_ConfirmAddressRequest_address_index_tag,
+    penumbra_custody_v1_ConfirmAddressResponse_address_tag,
+};
+use crate::protobuf_h::num_pb::PB_LTYPE_UVARINT;
+use crate::utils::protobuf::{
+    encode_proto_bytes_field, encode_proto_field, fields, set_once, FieldValue,
+};
+use crate::ParserError;
+
+// Field tags as consts so they can be matched on.
+const ADDRESS_INDEX_TAG: u32 = penumbra_custody_v1_ConfirmAddressRequest_address_index_tag;
+const ACCOUNT_TAG: u32 = penumbra_core_keys_v1_AddressIndex_account_tag;
+const RANDOMIZER_TAG: u32 = penumbra_core_keys_v1_AddressIndex_randomizer_tag;
+
+/// A `penumbra.custody.v1.ConfirmAddressRequest`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct ConfirmAddressRequest {
+    pub address_index: AddressIndex,
+}
+
+impl ConfirmAddressRequest {
+    pub fn parse(input: &[u8]) -> Result<Self, ParserError> {
+        let mut address_index = None;
+        for field in fields(input) {
+            match field? {
+                (ADDRESS_INDEX_TAG, FieldValue::Bytes(bytes)) => {
+                    set_once(&mut address_index, parse_address_index(bytes)?)?
+                }
+                (ADDRESS_INDEX_TAG, _) => return Err(ParserError::UnexpectedType),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            address_index: address_index.ok_or(ParserError::MissingField)?,
+        })
+    }
+
+    /// Derives the requested address, which is what gets shown and returned.
+    pub fn confirm(&self, fvk: &FullViewingKey) -> Result<ConfirmAddressResponse, ParserError> {
+        let (address, _) = fvk.payment_address(self.address_index)?;
+        Ok(ConfirmAddressResponse { address })
+    }
+}
+
+/// Parses a `penumbra.core.keys.v1.AddressIndex`. An absent randomizer is
+/// the zero randomizer of the account's main address.
+fn parse_address_index(input: &[u8]) -> Result<AddressIndex, ParserError> {
+    let mut account = None;
+    let mut randomizer = None;
+    for field in fields(input) {
+        match field? {
+            (ACCOUNT_TAG, FieldValue::Varint(value)) => set_once(
+                &mut account,
+                u32::try_from(value).map_err(|_| ParserError::ValueOutOfRange)?,
+            )?,
+            (RANDOMIZER_TAG, FieldValue::Bytes(bytes)) => set_once(&mut randomizer, bytes)?,
+            (ACCOUNT_TAG | RANDOMIZER_TAG, _) => return Err(ParserError::UnexpectedType),
+            _ => {}
+        }
+    }
+
+    let mut index = AddressIndex::new(account.unwrap_or_default());
+    match randomizer {
+        None | Some([]) => {}
+        Some(bytes) => {
+            index.randomizer = bytes.try_into().map_err(|_| ParserError::InvalidLength)?;
+        }
+    }
+    Ok(index)
+}
+
+/// A `penumbra.custody.v1.ConfirmAddressResponse` carrying the derived address.
+pub struct ConfirmAddressResponse {
+    pub address: Address,
+}
+
+impl ConfirmAddressResponse {
+    // The address is 80 bytes wrapped in a message with a single bytes field,
+    // taking two bytes of tag and length, inside and out.
+    pub const ENCODED_LEN: usize = 2 + 2 + Address::LEN;
+
+    /// Writes the message into `output`, returning the number of bytes written.
+    pub fn encode(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        if output.len() < Self::ENCODED_LEN {
+            return Err(ParserError::InvalidLength);
+        }
+
+        let len = encode_proto_field(
+            penumbra_custody_v1_ConfirmAddressResponse_address_tag as u64,
+            PB_LTYPE_UVARINT as u64,
+            Self::ENCODED_LEN - 2,
+            output,
+        )?;
+        let inner_len = encode_proto_bytes_field(
+            penumbra_core_keys_v1_Address_inner_tag as u64,
+            &self.address.to_bytes()?,
+            &mut output[len..],
+        )?;
+
+        Ok(len + inner_len)
+    }
+
+    /// Writes the address as shown on screen, returning the number of bytes written.
+    pub fn ui_address(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        self.address.to_short_form(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::spend_key::SpendKeyBytes;
+    use crate::utils::encode_varint;
+    use crate::utils::protobuf::{WIRE_LEN, WIRE_VARINT};
+    use std::vec::Vec;
+
+    const SPEND_KEY: &str = "ff726c71bcec76abc6a88cba71df655b28de6580edbd33c7415fdfded2e422e7";
+    // The address of account 1, as computed by `rs_compute_address`.
+    const EXPECTED_ADDR: &str = "70c4d192ddf3c4cdf97fddc4c4aa07d112b5a7bf6d0810da37ae777990913737babcaa57fd4031d19260d88f1ec0c357a375c289f9943e7efa242ae963abcce749543a22039d687d8a027cb05b33438c";
+
+    fn field(tag: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut field = encode_varint(((tag as u64) << 3) | WIRE_LEN);
+        field.extend(encode_varint(bytes.len() as u64));
+        field.extend_from_slice(bytes);
+        field
+    }
+
+    fn request(account: u64, randomizer: &[u8]) -> Vec<u8> {
+        let mut index = encode_varint(((ACCOUNT_TAG as u64) << 3) | WIRE_VARINT);
+        index.extend(encode_varint(account));
+        index.extend(field(RANDOMIZER_TAG, randomizer));
+        field(ADDRESS_INDEX_TAG, &index)
+    }
+
+    fn fvk() -> FullViewingKey {
+        let mut spend_key = [0u8; SpendKeyBytes::LEN];
+        spend_key.copy_from_slice(&hex::decode(SPEND_KEY).unwrap());
+        SpendKeyBytes::from(spend_key).fvk().unwrap()
+    }
+
+    #[test]
+    fn test_confirm_address() {
+        let request = ConfirmAddressRequest::parse(&request(1, &[])).unwrap();
+        assert_eq!(request.address_index, AddressIndex::new(1));
+
+        let response = request.confirm(&fvk()).unwrap();
+        let mut output = [0u8; ConfirmAddressResponse::ENCODED_LEN];
+        let len = response.encode(&mut output).unwrap();
+        assert_eq!(len, ConfirmAddressResponse::ENCODED_LEN);
+        assert_eq!(output[..4], [0x0a, 82, 0x0a, 80]);
+        assert_eq!(hex::encode(&output[4..]), EXPECTED_ADDR);
+
+        let mut encoded = [0u8; Address::BECH32_LEN];
+        response.address.to_bech32m(&mut encoded).unwrap();
+        let mut ui = [0u8; Address::SHORT_FORM_LEN];
+        assert_eq!(response.ui_address(&mut ui).unwrap(), ui.len());
+        let ui = core::str::from_utf8(&ui).unwrap();
+        assert!(ui.starts_with("penumbra1"));
+        assert!(ui.ends_with("…"));
+        assert_eq!(ui.as_bytes()[..33], encoded[..33]);
+    }
+
+    #[test]
+    fn test_confirm_randomized_address() {
+        let randomizer = [7u8; AddressIndex::RAND_LEN];
+        let request = ConfirmAddressRequest::parse(&request(1, &randomizer)).unwrap();
+        assert_eq!(request.address_index.randomizer, randomizer);
+        assert!(request.address_index.is_ephemeral());
+
+        let fvk = fvk();
+        let response = request.confirm(&fvk).unwrap();
+        assert_eq!(
+            fvk.address_index(&response.address),
+            Some(request.address_index)
+        );
+    }
+
+    #[test]
+    fn test_confirm_address_rejected() {
+        assert_eq!(
+            ConfirmAddressRequest::parse(&[]).err(),
+            Some(ParserError::MissingField)
+        );
+        assert_eq!(
+            ConfirmAddressRequest::parse(&request(1, &[7; 11])).err(),
+            Some(ParserError::InvalidLength)
+        );
+        assert_eq!(
+            ConfirmAddressRequest::parse(&request(u32::MAX as u64 + 1, &[])).err(),
+            Some(ParserError::ValueOutOfRange)
+        );
+
+        let mut duplicated = request(1, &[]);
+        duplicated.extend(request(2, &[]));
+        assert_eq!(
+            ConfirmAddressRequest::parse(&duplicated).err(),
+            Some(ParserError::DuplicatedField)
+        );
+    }
+}
@@ -0,0 +1,97 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::parser::{
+    amount::Amount, fixpoint::U128x128, id::Id, validator_identity::ValidatorIdentity,
+};
+use crate::ParserError;
+
+/// A share of a validator's delegation pool, with denom `udelegation_<penumbravalid1…>`.
+pub struct DelegationToken {
+    pub validator_identity: ValidatorIdentity,
+}
+
+impl DelegationToken {
+    pub const DENOM_PREFIX: &'static str = "udelegation_";
+    pub const MAX_DENOM_LEN: usize = Self::DENOM_PREFIX.len() + ValidatorIdentity::BECH32_LEN;
+
+    pub fn new(validator_identity: ValidatorIdentity) -> Self {
+        DelegationToken { validator_identity }
+    }
+
+    /// Writes the base denom of this token, returning the number of bytes written.
+    pub fn denom(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        let prefix_len = Self::DENOM_PREFIX.len();
+        if output.len() < prefix_len {
+            return Err(ParserError::UnexpectedBufferEnd);
+        }
+
+        output[..prefix_len].copy_from_slice(Self::DENOM_PREFIX.as_bytes());
+        let identity_len = self
+            .validator_identity
+            .to_bech32m(&mut output[prefix_len..])?;
+
+        Ok(prefix_len + identity_len)
+    }
+
+    /// Parses a `udelegation_<penumbravalid1…>` base denom.
+    pub fn from_denom(denom: &str) -> Result<Self, ParserError> {
+        let identity = denom
+            .strip_prefix(Self::DENOM_PREFIX)
+            .ok_or(ParserError::InvalidMetadata)?;
+
+        Ok(Self::new(ValidatorIdentity::from_bech32m(identity)?))
+    }
+
+    /// Writes "delegation to <validator>", returning the number of bytes written.
+    pub fn render(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        const LABEL: &[u8] = b"delegation to ";
+        if output.len() < LABEL.len() {
+            return Err(ParserError::UnexpectedBufferEnd);
+        }
+
+        output[..LABEL.len()].copy_from_slice(LABEL);
+        let identity_len = self
+            .validator_identity
+            .to_bech32m(&mut output[LABEL.len()..])?;
+
+        Ok(LABEL.len() + identity_len)
+    }
+
+    pub fn asset_id(&self) -> Result<Id, ParserError> {
+        let mut denom = [0u8; Self::MAX_DENOM_LEN];
+        let len = self.denom(&mut denom)?;
+        let denom = std::str::from_utf8(&denom[..len]).map_err(|_| ParserError::InvalidUtf8)?;
+
+        Id::from_base_denom(denom)
+    }
+}
+
+/// Computes how many staking tokens a single delegation token is worth, as
+/// implied by the two sides of a delegation or undelegation.
+///
+/// The validator's exchange rate is positive, so a conversion that creates
+/// or destroys value on only one side can never be consistent with it.
+pub fn implied_exchange_rate(
+    unbonded_amount: Amount,
+    delegation_amount: Amount,
+) -> Result<U128x128, ParserError> {
+    if unbonded_amount.inner == 0 || delegation_amount.inner == 0 {
+        return Err(ParserError::InvalidExchangeRate);
+    }
+
+    U128x128::from(unbonded_amount) / U128x128::from(delegation_amount)
+}
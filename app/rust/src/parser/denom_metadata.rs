@@ -0,0 +1,330 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::{ID_LEN_BYTES, MAX_DENOM_LEN};
+use crate::parser::id::AssetId;
+use crate::parser::known_assets;
+use crate::protobuf_h::asset_pb::{
+    penumbra_core_asset_v1_AssetId_inner_tag, penumbra_core_asset_v1_DenomUnit_denom_tag,
+    penumbra_core_asset_v1_DenomUnit_exponent_tag, penumbra_core_asset_v1_Metadata_base_tag,
+    penumbra_core_asset_v1_Metadata_denom_units_tag, penumbra_core_asset_v1_Metadata_display_tag,
+    penumbra_core_asset_v1_Metadata_penumbra_asset_id_tag,
+    penumbra_core_asset_v1_Metadata_symbol_tag,
+};
+use crate::utils::protobuf::{fields, set_once, FieldValue};
+use crate::ParserError;
+
+// Field tags as consts so they can be matched on.
+const BASE_TAG: u32 = penumbra_core_asset_v1_Metadata_base_tag;
+const DISPLAY_TAG: u32 = penumbra_core_asset_v1_Metadata_display_tag;
+const SYMBOL_TAG: u32 = penumbra_core_asset_v1_Metadata_symbol_tag;
+const DENOM_UNITS_TAG: u32 = penumbra_core_asset_v1_Metadata_denom_units_tag;
+const ASSET_ID_TAG: u32 = penumbra_core_asset_v1_Metadata_penumbra_asset_id_tag;
+const ASSET_ID_INNER_TAG: u32 = penumbra_core_asset_v1_AssetId_inner_tag;
+const UNIT_DENOM_TAG: u32 = penumbra_core_asset_v1_DenomUnit_denom_tag;
+const UNIT_EXPONENT_TAG: u32 = penumbra_core_asset_v1_DenomUnit_exponent_tag;
+
+fn as_str(value: FieldValue<'_>) -> Result<&str, ParserError> {
+    match value {
+        FieldValue::Bytes(bytes) => {
+            core::str::from_utf8(bytes).map_err(|_| ParserError::InvalidUtf8)
+        }
+        _ => Err(ParserError::InvalidMetadata),
+    }
+}
+
+/// The parts of a `penumbra.core.asset.v1.Metadata` the device relies on.
+///
+/// Parsing checks that `base` hashes to the claimed `penumbra_asset_id` and
+/// takes the display exponent from the denom unit named by `display`, so the
+/// host cannot attach metadata to the wrong asset. Assets in the known asset
+/// registry always take their display denom and exponent from the registry.
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct DenomMetadata<'a> {
+    pub base: &'a str,
+    pub display: &'a str,
+    pub symbol: &'a str,
+    pub display_exponent: u8,
+    pub asset_id: [u8; ID_LEN_BYTES],
+}
+
+impl<'a> DenomMetadata<'a> {
+    /// An amount has at most 39 digits, so larger exponents only add zeros.
+    pub const MAX_EXPONENT: u8 = 38;
+
+    pub fn parse(input: &'a [u8]) -> Result<Self, ParserError> {
+        let mut base = None;
+        let mut display = None;
+        let mut symbol = None;
+        let mut claimed_id = None;
+
+        for field in fields(input) {
+            let (tag, value) = field?;
+            match tag {
+                BASE_TAG => set_once(&mut base, as_str(value)?)?,
+                DISPLAY_TAG => set_once(&mut display, as_str(value)?)?,
+                SYMBOL_TAG => set_once(&mut symbol, as_str(value)?)?,
+                ASSET_ID_TAG => {
+                    let FieldValue::Bytes(asset_id) = value else {
+                        return Err(ParserError::InvalidMetadata);
+                    };
+                    set_once(&mut claimed_id, Self::parse_asset_id(asset_id)?)?
+                }
+                _ => {}
+            }
+        }
+
+        let base = base.ok_or(ParserError::MissingField)?;
+        if base.is_empty() || base.len() >= MAX_DENOM_LEN {
+            return Err(ParserError::InvalidMetadata);
+        }
+
+        let asset_id = AssetId::new(base)?.to_bytes();
+        if claimed_id.ok_or(ParserError::MissingField)? != asset_id {
+            return Err(ParserError::InvalidAssetId);
+        }
+
+        // Registered assets are always shown the registered way, whatever
+        // display denom and units the host sent along.
+        let (display, display_exponent) = match known_assets::lookup(&asset_id) {
+            Some(known) => (known.display_denom, known.exponent),
+            None => {
+                // Without a display denom amounts are shown in base units.
+                let display = display.unwrap_or(base);
+                if display.len() >= MAX_DENOM_LEN {
+                    return Err(ParserError::InvalidMetadata);
+                }
+                let display_exponent = Self::display_exponent(input, base, display)?;
+                if display_exponent > Self::MAX_EXPONENT {
+                    return Err(ParserError::InvalidMetadata);
+                }
+                (display, display_exponent)
+            }
+        };
+
+        Ok(Self {
+            base,
+            display,
+            symbol: symbol.unwrap_or_default(),
+            display_exponent,
+            asset_id,
+        })
+    }
+
+    fn parse_asset_id(input: &[u8]) -> Result<[u8; ID_LEN_BYTES], ParserError> {
+        let mut inner = None;
+        for field in fields(input) {
+            if let (ASSET_ID_INNER_TAG, value) = field? {
+                let FieldValue::Bytes(bytes) = value else {
+                    return Err(ParserError::InvalidAssetId);
+                };
+                let bytes = <[u8; ID_LEN_BYTES]>::try_from(bytes)
+                    .map_err(|_| ParserError::InvalidAssetId)?;
+                set_once(&mut inner, bytes)?;
+            }
+        }
+        inner.ok_or(ParserError::InvalidAssetId)
+    }
+
+    /// Finds the exponent of the denom unit named `display`. The base denom
+    /// needs no unit of its own, and exponents that disagree are rejected.
+    fn display_exponent(input: &[u8], base: &str, display: &str) -> Result<u8, ParserError> {
+        let mut found = None;
+        for field in fields(input) {
+            let (DENOM_UNITS_TAG, value) = field? else {
+                continue;
+            };
+            let FieldValue::Bytes(unit) = value else {
+                return Err(ParserError::InvalidMetadata);
+            };
+
+            let mut denom = None;
+            let mut exponent = 0;
+            for field in fields(unit) {
+                match field? {
+                    (UNIT_DENOM_TAG, value) => set_once(&mut denom, as_str(value)?)?,
+                    (UNIT_EXPONENT_TAG, FieldValue::Varint(value)) => {
+                        exponent = u8::try_from(value).map_err(|_| ParserError::InvalidMetadata)?
+                    }
+                    (UNIT_EXPONENT_TAG, _) => return Err(ParserError::InvalidMetadata),
+                    _ => {}
+                }
+            }
+
+            if denom == Some(display) {
+                match found.replace(exponent) {
+                    Some(previous) if previous != exponent => {
+                        return Err(ParserError::InvalidMetadata)
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        match found {
+            Some(exponent) => Ok(exponent),
+            None if display == base => Ok(0),
+            None => Err(ParserError::InvalidMetadata),
+        }
+    }
+}
+
+/// The parsed form of one `INS_TX_METADATA` entry, mirroring `tx_metadata_t`.
+#[repr(C)]
+pub struct DenomMetadataC {
+    pub denom: [u8; MAX_DENOM_LEN],
+    pub len: u8,
+    pub display: [u8; MAX_DENOM_LEN],
+    pub display_len: u8,
+    pub display_exponent: u8,
+    pub asset_id: [u8; ID_LEN_BYTES],
+}
+
+impl DenomMetadataC {
+    pub fn fill(&mut self, metadata: &DenomMetadata) {
+        self.denom = [0; MAX_DENOM_LEN];
+        self.denom[..metadata.base.len()].copy_from_slice(metadata.base.as_bytes());
+        self.len = metadata.base.len() as u8;
+        self.display = [0; MAX_DENOM_LEN];
+        self.display[..metadata.display.len()].copy_from_slice(metadata.display.as_bytes());
+        self.display_len = metadata.display.len() as u8;
+        self.display_exponent = metadata.display_exponent;
+        self.asset_id = metadata.asset_id;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::encode_varint;
+    use crate::utils::protobuf::WIRE_LEN;
+    use std::vec::Vec;
+
+    fn field(tag: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut out = encode_varint(((tag as u64) << 3) | WIRE_LEN);
+        out.extend(encode_varint(bytes.len() as u64));
+        out.extend_from_slice(bytes);
+        out
+    }
+
+    fn unit(denom: &str, exponent: u64) -> Vec<u8> {
+        let mut unit = field(UNIT_DENOM_TAG, denom.as_bytes());
+        if exponent != 0 {
+            unit.extend(encode_varint((UNIT_EXPONENT_TAG as u64) << 3));
+            unit.extend(encode_varint(exponent));
+        }
+        field(DENOM_UNITS_TAG, &unit)
+    }
+
+    fn metadata(base: &str, display: &str, units: &[(&str, u64)], id: &[u8]) -> Vec<u8> {
+        let mut out = field(1, b"a description that is skipped");
+        for (denom, exponent) in units {
+            out.extend(unit(denom, *exponent));
+        }
+        out.extend(field(BASE_TAG, base.as_bytes()));
+        out.extend(field(DISPLAY_TAG, display.as_bytes()));
+        out.extend(field(SYMBOL_TAG, b"SYM"));
+        let id = field(ASSET_ID_INNER_TAG, id);
+        out.extend(field(ASSET_ID_TAG, &id));
+        out
+    }
+
+    fn id(base: &str) -> [u8; ID_LEN_BYTES] {
+        AssetId::new(base).unwrap().to_bytes()
+    }
+
+    #[test]
+    fn test_parse_denom_metadata() {
+        let units = [("uatom", 0), ("matom", 3), ("atom", 6)];
+        let encoded = metadata("uatom", "atom", &units, &id("uatom"));
+        let parsed = DenomMetadata::parse(&encoded).unwrap();
+        assert_eq!(parsed.base, "uatom");
+        assert_eq!(parsed.display, "atom");
+        assert_eq!(parsed.symbol, "SYM");
+        assert_eq!(parsed.display_exponent, 6);
+        assert_eq!(parsed.asset_id, id("uatom"));
+
+        let encoded = metadata("uatom", "matom", &units, &id("uatom"));
+        assert_eq!(DenomMetadata::parse(&encoded).unwrap().display_exponent, 3);
+
+        // The base unit needs no exponent of its own.
+        let encoded = metadata("uatom", "uatom", &[("atom", 6)], &id("uatom"));
+        assert_eq!(DenomMetadata::parse(&encoded).unwrap().display_exponent, 0);
+
+        // Known assets keep their registered display denom and decimals.
+        for (display, units) in [
+            ("penumbra", &[("upenumbra", 0), ("penumbra", 6)][..]),
+            ("penumbra", &[("penumbra", 18)][..]),
+            ("upenumbra", &[("upenumbra", 0)][..]),
+            ("mpenumbra", &[("mpenumbra", 3)][..]),
+        ] {
+            let encoded = metadata("upenumbra", display, units, &id("upenumbra"));
+            let parsed = DenomMetadata::parse(&encoded).unwrap();
+            assert_eq!(parsed.display, "penumbra");
+            assert_eq!(parsed.display_exponent, 6);
+        }
+    }
+
+    #[test]
+    fn test_parse_denom_metadata_rejected() {
+        let units = [("uatom", 0), ("atom", 6)];
+        for (encoded, expected) in [
+            (
+                metadata("uatom", "atom", &units, &id("uosmo")),
+                ParserError::InvalidAssetId,
+            ),
+            (
+                metadata("uatom", "atom", &units, &id("uatom")[1..]),
+                ParserError::InvalidAssetId,
+            ),
+            (
+                metadata("uatom", "natom", &units, &id("uatom")),
+                ParserError::InvalidMetadata,
+            ),
+            (
+                metadata("uatom", "atom", &[("atom", 6), ("atom", 9)], &id("uatom")),
+                ParserError::InvalidMetadata,
+            ),
+            (
+                metadata("uatom", "atom", &[("atom", 39)], &id("uatom")),
+                ParserError::InvalidMetadata,
+            ),
+            (
+                metadata("", "atom", &units, &id("")),
+                ParserError::InvalidMetadata,
+            ),
+        ] {
+            assert_eq!(DenomMetadata::parse(&encoded).err(), Some(expected));
+        }
+
+        let mut missing_id = field(BASE_TAG, b"uatom");
+        missing_id.extend(unit("uatom", 0));
+        assert_eq!(
+            DenomMetadata::parse(&missing_id).err(),
+            Some(ParserError::MissingField)
+        );
+
+        let mut duplicated = metadata("uatom", "atom", &units, &id("uatom"));
+        duplicated.extend(field(BASE_TAG, b"uosmo"));
+        assert_eq!(
+            DenomMetadata::parse(&duplicated).err(),
+            Some(ParserError::DuplicatedField)
+        );
+
+        let encoded = metadata("uatom", "atom", &units, &id("uatom"));
+        assert!(DenomMetadata::parse(&encoded[..encoded.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::protobuf_h::sct_pb::{
+    penumbra_core_component_sct_v1_Epoch_index_tag,
+    penumbra_core_component_sct_v1_Epoch_start_height_tag,
+};
+use crate::utils::protobuf::encode_proto_number;
+use crate::ParserError;
+
+#[derive(Clone, Copy)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct Epoch {
+    pub index: u64,
+    pub start_height: u64,
+}
+
+impl Epoch {
+    // two tagged varints of at most 10 bytes each
+    pub const PROTO_LEN: usize = 2 * 11;
+
+    pub fn to_proto(&self) -> Result<([u8; Self::PROTO_LEN], usize), ParserError> {
+        let mut proto = [0u8; Self::PROTO_LEN];
+        let mut len = 0;
+
+        if self.index != 0 {
+            len += encode_proto_number(
+                penumbra_core_component_sct_v1_Epoch_index_tag as u64,
+                self.index,
+                &mut proto[len..],
+            )?;
+        }
+        if self.start_height != 0 {
+            len += encode_proto_number(
+                penumbra_core_component_sct_v1_Epoch_start_height_tag as u64,
+                self.start_height,
+                &mut proto[len..],
+            )?;
+        }
+
+        Ok((proto, len))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct EpochC {
+    pub index: u64,
+    pub start_height: u64,
+}
+
+impl From<EpochC> for Epoch {
+    fn from(value: EpochC) -> Self {
+        Epoch {
+            index: value.index,
+            start_height: value.start_height,
+        }
+    }
+}
@@ -112,6 +112,17 @@ pub enum ParserError {
     EncryptionError,
     ActionDecodeError,
     CluePlanDecodeError,
+    DivisionByZero,
+    InvalidTradingPair,
+    InvalidExchangeRate,
+    UnspecifiedVote,
+    StakedNoteNotDelegationToken,
+    StakedNoteNotOwned,
+    ZeroUnbondedAmount,
+    UnbalancedPlan,
+    BindingSignatureMismatch,
+    PositionMetadataUnsupported,
+    InvalidDutchAuction,
 }
 
 impl From<ErrorKind> for ParserError {
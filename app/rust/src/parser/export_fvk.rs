@@ -0,0 +1,78 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::{ENCODED_FVK_RESPONSE_LEN, FVK_LEN};
+use crate::protobuf_h::custody_pb::{
+    penumbra_core_keys_v1_FullViewingKey_inner_tag,
+    penumbra_custody_v1_ExportFullViewingKeyResponse_full_viewing_key_tag,
+};
+use crate::protobuf_h::num_pb::PB_LTYPE_UVARINT;
+use crate::utils::protobuf::{encode_proto_bytes_field, encode_proto_field};
+use crate::ParserError;
+
+/// A `penumbra.custody.v1.ExportFullViewingKeyResponse`, wrapping the
+/// `ak || nk` bytes that make up the inner of a `FullViewingKey`.
+pub struct ExportFullViewingKeyResponse<'a> {
+    pub fvk: &'a [u8; FVK_LEN],
+}
+
+impl<'a> ExportFullViewingKeyResponse<'a> {
+    // The key is 64 bytes wrapped in a message with a single bytes field,
+    // taking two bytes of tag and length, inside and out.
+    pub const ENCODED_LEN: usize = ENCODED_FVK_RESPONSE_LEN;
+
+    /// Writes the message into `output`, returning the number of bytes written.
+    pub fn encode(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        if output.len() < Self::ENCODED_LEN {
+            return Err(ParserError::InvalidLength);
+        }
+
+        let len = encode_proto_field(
+            penumbra_custody_v1_ExportFullViewingKeyResponse_full_viewing_key_tag as u64,
+            PB_LTYPE_UVARINT as u64,
+            Self::ENCODED_LEN - 2,
+            output,
+        )?;
+        let inner_len = encode_proto_bytes_field(
+            penumbra_core_keys_v1_FullViewingKey_inner_tag as u64,
+            self.fvk,
+            &mut output[len..],
+        )?;
+
+        Ok(len + inner_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_fvk_response_encoding() {
+        let fvk = [0x5au8; FVK_LEN];
+        let response = ExportFullViewingKeyResponse { fvk: &fvk };
+
+        let mut output = [0u8; ExportFullViewingKeyResponse::ENCODED_LEN];
+        assert_eq!(response.encode(&mut output), Ok(output.len()));
+        assert_eq!(output[..4], [0x0a, 66, 0x0a, 64]);
+        assert_eq!(output[4..], fvk);
+
+        assert_eq!(
+            response.encode(&mut output[..ExportFullViewingKeyResponse::ENCODED_LEN - 1]),
+            Err(ParserError::InvalidLength)
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use crate::parser::amount::Amount;
 use crate::parser::ParserError;
 use ethnum::U256;
-use std::ops::Mul;
+use std::ops::{Add, Div, Mul, Sub};
 
 #[derive(Copy, Clone)]
 pub struct U128x128(U256);
@@ -57,6 +57,22 @@ impl U128x128 {
         Self(U256::from_words(integral_word, 0u128))
     }
 
+    /// Performs checked addition, returning `Ok` if no overflow occurred.
+    pub fn checked_add(self, rhs: &Self) -> Result<Self, ParserError> {
+        self.0
+            .checked_add(rhs.0)
+            .map(U128x128)
+            .ok_or(ParserError::Overflow)
+    }
+
+    /// Performs checked subtraction, returning `Ok` if no underflow occurred.
+    pub fn checked_sub(self, rhs: &Self) -> Result<Self, ParserError> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(U128x128)
+            .ok_or(ParserError::Overflow)
+    }
+
     /// Performs checked multiplication, returning `Ok` if no overflow occurred.
     pub fn checked_mul(self, rhs: &Self) -> Result<Self, ParserError> {
         // It's important to use `into_words` because the `U256` type has an
@@ -93,6 +109,74 @@ impl U128x128 {
             .map(U128x128)
             .ok_or(ParserError::Overflow)
     }
+
+    /// Performs checked division, returning `Ok` if no overflow occurred.
+    ///
+    /// This computes `(x * 2^128) / y` over 384 bits with a shift-subtract
+    /// long division, rounding the quotient down.
+    pub fn checked_div(self, rhs: &Self) -> Result<Self, ParserError> {
+        if rhs.0 == U256::ZERO {
+            return Err(ParserError::DivisionByZero);
+        }
+
+        let (x1, x0) = self.0.into_words();
+        // The dividend is x * 2^128, i.e. the words (x1, x0, 0) from most to
+        // least significant.
+        let dividend = [x1, x0, 0u128];
+
+        let mut quotient = U256::ZERO;
+        let mut remainder = U256::ZERO;
+        for word in dividend {
+            for bit in (0..128).rev() {
+                // The remainder is always below the divisor, but shifting it
+                // may carry out of 256 bits, in which case it certainly
+                // exceeds the divisor.
+                let carry = remainder >> 255u32 != U256::ZERO;
+                remainder = (remainder << 1u32) | U256::from((word >> bit) & 1);
+
+                if quotient >> 255u32 != U256::ZERO {
+                    return Err(ParserError::Overflow);
+                }
+                quotient <<= 1u32;
+
+                if carry || remainder >= rhs.0 {
+                    remainder = remainder.wrapping_sub(rhs.0);
+                    quotient |= U256::ONE;
+                }
+            }
+        }
+
+        Ok(U128x128(quotient))
+    }
+
+    /// Writes this number as a decimal string with exactly `precision`
+    /// fractional digits, rounding down. Returns the number of bytes written.
+    pub fn to_decimal_str(&self, precision: usize, out: &mut [u8]) -> Result<usize, ParserError> {
+        let (integral, mut fractional) = self.0.into_words();
+
+        let mut buffer = itoa::Buffer::new();
+        let integral_str = buffer.format(integral).as_bytes();
+
+        let len = integral_str.len() + if precision > 0 { precision + 1 } else { 0 };
+        if len > out.len() {
+            return Err(ParserError::UnexpectedBufferEnd);
+        }
+
+        out[..integral_str.len()].copy_from_slice(integral_str);
+        if precision == 0 {
+            return Ok(len);
+        }
+
+        out[integral_str.len()] = b'.';
+        for digit in out[integral_str.len() + 1..len].iter_mut() {
+            // fractional * 10 fits in 132 bits, so widen before multiplying.
+            let (carry, rest) = (U256::from(fractional) * 10).into_words();
+            *digit = b'0' + carry as u8;
+            fractional = rest;
+        }
+
+        Ok(len)
+    }
 }
 
 impl TryFrom<[u8; 32]> for U128x128 {
@@ -127,9 +211,30 @@ impl From<u128> for U128x128 {
     }
 }
 
+impl Add<U128x128> for U128x128 {
+    type Output = Result<U128x128, ParserError>;
+    fn add(self, rhs: U128x128) -> Self::Output {
+        self.checked_add(&rhs)
+    }
+}
+
+impl Sub<U128x128> for U128x128 {
+    type Output = Result<U128x128, ParserError>;
+    fn sub(self, rhs: U128x128) -> Self::Output {
+        self.checked_sub(&rhs)
+    }
+}
+
 impl Mul<U128x128> for U128x128 {
     type Output = Result<U128x128, ParserError>;
     fn mul(self, rhs: U128x128) -> Self::Output {
         self.checked_mul(&rhs)
     }
 }
+
+impl Div<U128x128> for U128x128 {
+    type Output = Result<U128x128, ParserError>;
+    fn div(self, rhs: U128x128) -> Self::Output {
+        self.checked_div(&rhs)
+    }
+}
@@ -0,0 +1,339 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+//! Per-asset summary of the value a transaction moves out of and into the wallet.
+//!
+//! Spends and outputs back to one of our own addresses only move value
+//! between the wallet's notes, so they are left out. Every other action
+//! contributes its balance: what it requires leaves the wallet and is counted
+//! as sent, what it provides comes back and is counted as received, netted
+//! per asset. An output to someone else requires its value like any other
+//! action. The fee is kept apart. For a balanced plan this is exactly the net
+//! change of the wallet's holdings.
+//!
+//! When the transaction touches more assets than the summary can hold it is
+//! marked incomplete rather than failing.
+
+use crate::address::Address;
+use crate::constants::{FLOW_ASSETS_QTY, ID_LEN_BYTES};
+use crate::keys::FullViewingKey;
+use crate::parser::{
+    amount::{Amount, AmountC},
+    balance::Balance,
+    fee::Fee,
+    plans::output::OutputPlanC,
+    value::{Sign, Value},
+};
+use crate::ParserError;
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct AssetFlowC {
+    pub asset_id: [u8; ID_LEN_BYTES],
+    pub sent: AmountC,
+    pub received: AmountC,
+    pub fee: AmountC,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct FlowSummaryC {
+    pub qty: u8,
+    /// Set when some asset did not fit in `flows`.
+    pub incomplete: bool,
+    pub flows: [AssetFlowC; FLOW_ASSETS_QTY],
+}
+
+impl AssetFlowC {
+    fn empty() -> Self {
+        AssetFlowC {
+            asset_id: [0; ID_LEN_BYTES],
+            sent: AmountC { lo: 0, hi: 0 },
+            received: AmountC { lo: 0, hi: 0 },
+            fee: AmountC { lo: 0, hi: 0 },
+        }
+    }
+
+    /// Moves `amount` towards `into`, first cancelling whatever is pending
+    /// in the opposite direction.
+    fn net(into: &mut AmountC, against: &mut AmountC, amount: Amount) -> Result<(), ParserError> {
+        let pending = Amount::try_from(against.clone())?.inner;
+        if pending >= amount.inner {
+            *against = Amount {
+                inner: pending - amount.inner,
+            }
+            .into();
+        } else {
+            let current = Amount::try_from(into.clone())?.inner;
+            let inner = current
+                .checked_add(amount.inner - pending)
+                .ok_or(ParserError::OperationOverflows)?;
+            *into = Amount { inner }.into();
+            *against = Amount { inner: 0 }.into();
+        }
+        Ok(())
+    }
+}
+
+impl FlowSummaryC {
+    pub fn new() -> Self {
+        FlowSummaryC {
+            qty: 0,
+            incomplete: false,
+            flows: core::array::from_fn(|_| AssetFlowC::empty()),
+        }
+    }
+
+    pub fn flows(&self) -> &[AssetFlowC] {
+        &self.flows[..self.qty as usize]
+    }
+
+    /// The flow of `value`'s asset, or `None` once the summary is full.
+    fn flow(&mut self, value: &Value) -> Option<&mut AssetFlowC> {
+        let asset_id = value.asset_id.to_bytes();
+        let qty = self.qty as usize;
+
+        match self.flows[..qty]
+            .iter()
+            .position(|flow| flow.asset_id == asset_id)
+        {
+            Some(index) => Some(&mut self.flows[index]),
+            None if qty < FLOW_ASSETS_QTY => {
+                self.qty += 1;
+                let flow = &mut self.flows[qty];
+                *flow = AssetFlowC::empty();
+                flow.asset_id = asset_id;
+                Some(flow)
+            }
+            None => {
+                self.incomplete = true;
+                None
+            }
+        }
+    }
+
+    /// Adds the balance of an action: required value as sent and provided
+    /// value as received.
+    pub fn add_balance(&mut self, balance: &Balance) -> Result<(), ParserError> {
+        for imbalance in balance.imbalances() {
+            let Some(flow) = self.flow(&imbalance.value) else {
+                continue;
+            };
+            let amount = imbalance.value.amount;
+            match imbalance.sign {
+                Sign::Required => AssetFlowC::net(&mut flow.sent, &mut flow.received, amount)?,
+                Sign::Provided => AssetFlowC::net(&mut flow.received, &mut flow.sent, amount)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds an output to someone else as value sent. Outputs back to one of
+    /// our own addresses are change and are left out. Returns whether the
+    /// output was ours.
+    pub fn add_output(
+        &mut self,
+        plan: &OutputPlanC,
+        fvk: &FullViewingKey,
+    ) -> Result<bool, ParserError> {
+        let address = Address::try_from(plan.dest_address.inner.get_bytes()?)?;
+        if fvk.address_index(&address).is_some() {
+            return Ok(true);
+        }
+
+        self.add_balance(&plan.balance()?)?;
+        Ok(false)
+    }
+
+    /// Records the fee, which is shown apart from what the actions send.
+    pub fn add_fee(&mut self, fee: &Fee) -> Result<(), ParserError> {
+        let amount = fee.0.amount;
+        if amount.inner == 0 {
+            return Ok(());
+        }
+
+        let Some(flow) = self.flow(&fee.0) else {
+            return Ok(());
+        };
+        let inner = Amount::try_from(flow.fee.clone())?
+            .inner
+            .checked_add(amount.inner)
+            .ok_or(ParserError::OperationOverflows)?;
+        flow.fee = Amount { inner }.into();
+        Ok(())
+    }
+}
+
+impl Default for FlowSummaryC {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::AddressIndex;
+    use crate::keys::spend_key::SpendKeyBytes;
+    use crate::parser::address::AddressC;
+    use crate::parser::bytes::BytesC;
+    use crate::parser::id::{Id, IdC};
+    use crate::parser::value::ValueC;
+    use decaf377::Fq;
+
+    const FOREIGN: &str = "7616f6c402371db1fa79eca16f1892132bbc1ea65e133fa67388049719f62f45c36fe666cc95ecc4444f6561a36d30fa6aad47a89032c8966f05a7cb098f9fd9ee392d0d337f3c35a33284ed4317f392";
+
+    fn asset_ids() -> [[u8; ID_LEN_BYTES]; FLOW_ASSETS_QTY + 1] {
+        core::array::from_fn(|asset| Id(Fq::from(asset as u64)).to_bytes())
+    }
+
+    fn value(asset_id: &[u8; ID_LEN_BYTES], lo: u64) -> ValueC {
+        ValueC {
+            has_amount: true,
+            amount: AmountC { lo, hi: 0 },
+            has_asset_id: true,
+            asset_id: IdC {
+                inner: BytesC::from_slice(asset_id),
+            },
+        }
+    }
+
+    fn balance(imbalances: &[(&[u8; ID_LEN_BYTES], u64, Sign)]) -> Balance {
+        let mut balance = Balance::new();
+        for (asset_id, lo, sign) in imbalances {
+            let value = Value::try_from(value(asset_id, *lo)).unwrap();
+            balance.add(&value, sign.clone()).unwrap();
+        }
+        balance
+    }
+
+    fn output(address: &[u8], asset_id: &[u8; ID_LEN_BYTES], lo: u64) -> OutputPlanC {
+        OutputPlanC {
+            value: value(asset_id, lo),
+            dest_address: AddressC {
+                inner: BytesC::from_slice(address),
+                alt_bech32m: BytesC::default(),
+            },
+            rseed: BytesC::default(),
+            value_blinding: BytesC::default(),
+        }
+    }
+
+    fn fee(asset_id: &[u8; ID_LEN_BYTES], lo: u64) -> Fee {
+        Fee(Value::try_from(value(asset_id, lo)).unwrap())
+    }
+
+    fn fvk() -> FullViewingKey {
+        SpendKeyBytes::from([
+            0xa1, 0xff, 0xba, 0x0c, 0x37, 0x93, 0x1f, 0x0a, 0x62, 0x61, 0x37, 0x52, 0x0d, 0xa6,
+            0x50, 0x63, 0x2d, 0x35, 0x85, 0x3b, 0xf5, 0x91, 0xb3, 0x6b, 0xb4, 0x28, 0x63, 0x0a,
+            0x4d, 0x87, 0xc4, 0xdc,
+        ])
+        .fvk()
+        .unwrap()
+    }
+
+    fn amount(amount: &AmountC) -> u128 {
+        Amount::try_from(amount.clone()).unwrap().inner
+    }
+
+    #[test]
+    fn test_flow_summary_nets_per_asset() {
+        let fvk = fvk();
+        let ids = asset_ids();
+        let (owned, _) = fvk.payment_address(AddressIndex::new(3)).unwrap();
+        let owned = owned.to_bytes().unwrap();
+        let foreign = hex::decode(FOREIGN).unwrap();
+
+        let mut summary = FlowSummaryC::new();
+        assert!(!summary
+            .add_output(&output(&foreign, &ids[1], 600), &fvk)
+            .unwrap());
+        assert!(summary
+            .add_output(&output(&owned, &ids[1], 395), &fvk)
+            .unwrap());
+        // a withdrawal paying back part of what is sent
+        summary
+            .add_balance(&balance(&[(&ids[1], 100, Sign::Provided)]))
+            .unwrap();
+        // a delegation trading one asset for another
+        summary
+            .add_balance(&balance(&[
+                (&ids[2], 40, Sign::Required),
+                (&ids[3], 38, Sign::Provided),
+            ]))
+            .unwrap();
+        summary
+            .add_balance(&balance(&[(&ids[4], 0, Sign::Required)]))
+            .unwrap();
+        summary.add_fee(&fee(&ids[1], 5)).unwrap();
+
+        assert!(!summary.incomplete);
+        let flows = summary.flows();
+        assert_eq!(flows.len(), 3);
+        assert_eq!(flows[0].asset_id, ids[1]);
+        assert_eq!(amount(&flows[0].sent), 500);
+        assert_eq!(amount(&flows[0].received), 0);
+        assert_eq!(amount(&flows[0].fee), 5);
+        assert_eq!(flows[1].asset_id, ids[2]);
+        assert_eq!(amount(&flows[1].sent), 40);
+        assert_eq!(flows[2].asset_id, ids[3]);
+        assert_eq!(amount(&flows[2].received), 38);
+        assert_eq!(amount(&flows[2].fee), 0);
+    }
+
+    #[test]
+    fn test_flow_summary_fee_kept_apart() {
+        let ids = asset_ids();
+
+        // a claim pays both the fee and the output back to us
+        let mut summary = FlowSummaryC::new();
+        summary
+            .add_balance(&balance(&[(&ids[1], 100, Sign::Provided)]))
+            .unwrap();
+        summary.add_fee(&fee(&ids[1], 5)).unwrap();
+
+        let flows = summary.flows();
+        assert_eq!(amount(&flows[0].sent), 0);
+        assert_eq!(amount(&flows[0].received), 100);
+        assert_eq!(amount(&flows[0].fee), 5);
+    }
+
+    #[test]
+    fn test_flow_summary_capacity() {
+        let ids = asset_ids();
+        let mut summary = FlowSummaryC::new();
+        for asset_id in &ids[..FLOW_ASSETS_QTY] {
+            summary
+                .add_balance(&balance(&[(asset_id, 1, Sign::Required)]))
+                .unwrap();
+        }
+        assert!(!summary.incomplete);
+
+        summary
+            .add_balance(&balance(&[(&ids[FLOW_ASSETS_QTY], 1, Sign::Required)]))
+            .unwrap();
+        assert!(summary.incomplete);
+        assert_eq!(summary.flows().len(), FLOW_ASSETS_QTY);
+
+        summary
+            .add_balance(&balance(&[(&ids[0], 1, Sign::Required)]))
+            .unwrap();
+        assert_eq!(amount(&summary.flows()[0].sent), 2);
+    }
+}
@@ -68,6 +68,13 @@ impl Id {
     }
 }
 
+impl Id {
+    /// Computes the asset ID of a base denom.
+    pub fn from_base_denom(denom: &str) -> Result<Self, ParserError> {
+        Ok(AssetId::new(denom)?.0)
+    }
+}
+
 impl PartialEq for Id {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
@@ -112,30 +119,3 @@ impl AssetId {
         self.0.to_bytes()
     }
 }
-
-#[derive(Clone, Default)]
-#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
-pub struct IdRaw(pub [u8; 32]);
-
-impl IdRaw {
-    pub const PROTO_LEN: usize = ID_LEN_BYTES + 2;
-
-    pub fn to_proto(&self) -> Result<[u8; Self::PROTO_LEN], ParserError> {
-        let mut proto = [0u8; Self::PROTO_LEN];
-
-        let bytes = self.0;
-        let len = encode_proto_field(
-            penumbra_core_asset_v1_AssetId_inner_tag as u64,
-            PB_LTYPE_UVARINT as u64,
-            bytes.len(),
-            &mut proto,
-        )?;
-
-        if len + bytes.len() != Self::PROTO_LEN {
-            return Err(ParserError::InvalidLength);
-        }
-
-        proto[len..].copy_from_slice(&bytes);
-        Ok(proto)
-    }
-}
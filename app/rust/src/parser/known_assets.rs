@@ -0,0 +1,68 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::ID_LEN_BYTES;
+
+/// An asset from `assets/registry.json`, whose ID was checked against its
+/// base denom by the build script.
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct KnownAsset {
+    pub id: [u8; ID_LEN_BYTES],
+    pub base_denom: &'static str,
+    pub display_denom: &'static str,
+    pub exponent: u8,
+}
+
+// `KNOWN_ASSETS`, sorted by ID.
+include!(concat!(env!("OUT_DIR"), "/known_assets.rs"));
+
+/// Finds the registry entry for an asset ID.
+pub fn lookup(asset_id: &[u8; ID_LEN_BYTES]) -> Option<&'static KnownAsset> {
+    KNOWN_ASSETS
+        .binary_search_by(|asset| asset.id.cmp(asset_id))
+        .ok()
+        .map(|index| &KNOWN_ASSETS[index])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::id::AssetId;
+
+    #[test]
+    fn test_known_assets_match_base_denoms() {
+        for asset in KNOWN_ASSETS.iter() {
+            let id = AssetId::new(asset.base_denom).unwrap();
+            assert_eq!(id.to_bytes(), asset.id, "{}", asset.base_denom);
+        }
+        assert!(KNOWN_ASSETS.windows(2).all(|pair| pair[0].id < pair[1].id));
+    }
+
+    #[test]
+    fn test_lookup() {
+        let staking_token = AssetId::new("upenumbra").unwrap().to_bytes();
+        let asset = lookup(&staking_token).unwrap();
+        assert_eq!(asset.display_denom, "penumbra");
+        assert_eq!(asset.exponent, 6);
+
+        for asset in KNOWN_ASSETS.iter() {
+            assert_eq!(lookup(&asset.id).unwrap().base_denom, asset.base_denom);
+        }
+
+        let unknown = AssetId::new("unknown").unwrap().to_bytes();
+        assert!(lookup(&unknown).is_none());
+    }
+}
@@ -0,0 +1,145 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+//! Off-chain message signing with the spend authorization key.
+//!
+//! Messages are hashed under their own personalization, with their length
+//! in front, so a signed message can never pass for an effect hash or any
+//! other payload the spend key signs.
+
+use crate::constants::{KEY_LEN, SIGNATURE_LEN};
+use crate::ParserError;
+use decaf377_rdsa::{Signature, SigningKey, SpendAuth, VerificationKey};
+
+pub const MESSAGE_DIGEST_LEN: usize = 64;
+
+/// The digest a message is signed as: BLAKE2b-512 over the little-endian
+/// `u64` length of the message followed by the message, which must be UTF-8.
+pub fn message_digest(message: &[u8]) -> Result<[u8; MESSAGE_DIGEST_LEN], ParserError> {
+    core::str::from_utf8(message).map_err(|_| ParserError::InvalidUtf8)?;
+
+    let digest = blake2b_simd::Params::new()
+        .hash_length(MESSAGE_DIGEST_LEN)
+        .personal(b"Penumbra_SignMsg")
+        .to_state()
+        .update(&(message.len() as u64).to_le_bytes())
+        .update(message)
+        .finalize();
+
+    let mut output = [0u8; MESSAGE_DIGEST_LEN];
+    output.copy_from_slice(digest.as_bytes());
+    Ok(output)
+}
+
+/// A signature over a message together with the randomized key that
+/// verifies it, which does not link back to the wallet's other signatures.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct MessageSignature {
+    pub signature: [u8; SIGNATURE_LEN],
+    pub rk: [u8; KEY_LEN],
+}
+
+impl MessageSignature {
+    pub const LEN: usize = SIGNATURE_LEN + KEY_LEN;
+
+    pub fn sign(rsk: &SigningKey<SpendAuth>, message: &[u8]) -> Result<Self, ParserError> {
+        use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+        let digest = message_digest(message)?;
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(&digest[..32]);
+        let mut rng = ChaCha20Rng::from_seed(seed);
+
+        let rk: VerificationKey<SpendAuth> = rsk.into();
+        Ok(Self {
+            signature: rsk.sign(&mut rng, &digest).to_bytes(),
+            rk: rk.to_bytes(),
+        })
+    }
+
+    /// Checks the signature over `message` against `rk`.
+    pub fn verify(&self, message: &[u8]) -> Result<(), ParserError> {
+        let digest = message_digest(message)?;
+        let rk = VerificationKey::<SpendAuth>::try_from(self.rk)
+            .map_err(|_| ParserError::InvalidPubkeyEncoding)?;
+
+        rk.verify(&digest, &Signature::from(self.signature))
+            .map_err(|_| ParserError::InvalidSignature)
+    }
+
+    /// Writes the signature followed by `rk`.
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+        bytes[..SIGNATURE_LEN].copy_from_slice(&self.signature);
+        bytes[SIGNATURE_LEN..].copy_from_slice(&self.rk);
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::sign::sign_message;
+    use crate::keys::spend_key::SpendKeyBytes;
+    use crate::parser::BytesC;
+
+    const SPEND_KEY: &str = "ff726c71bcec76abc6a88cba71df655b28de6580edbd33c7415fdfded2e422e7";
+
+    fn spend_key() -> SpendKeyBytes {
+        SpendKeyBytes::try_from(hex::decode(SPEND_KEY).unwrap().as_slice()).unwrap()
+    }
+
+    #[test]
+    fn test_sign_and_verify_message() {
+        let message = "I control this wallet: 2024-10-01".as_bytes();
+        let randomizer = [3u8; 32];
+        let randomizer = BytesC::from_slice(&randomizer);
+
+        let signed = sign_message(message, &randomizer, &spend_key()).unwrap();
+        assert!(signed.verify(message).is_ok());
+        assert_eq!(
+            signed.verify(b"I control this wallet: 2024-10-02"),
+            Err(ParserError::InvalidSignature)
+        );
+
+        // another randomizer gives an unlinkable key
+        let other = BytesC::from_slice(&[4u8; 32]);
+        let other = sign_message(message, &other, &spend_key()).unwrap();
+        assert_ne!(signed.rk, other.rk);
+        assert!(other.verify(message).is_ok());
+
+        // the message is not signed as is, so it is no effect hash either
+        let rk = VerificationKey::<SpendAuth>::try_from(signed.rk).unwrap();
+        assert!(rk
+            .verify(message, &Signature::from(signed.signature))
+            .is_err());
+    }
+
+    #[test]
+    fn test_message_digest() {
+        let expected = blake2b_simd::Params::new()
+            .hash_length(MESSAGE_DIGEST_LEN)
+            .personal(b"Penumbra_SignMsg")
+            .hash(b"\x03\x00\x00\x00\x00\x00\x00\x00abc");
+        assert_eq!(message_digest(b"abc").unwrap(), expected.as_bytes());
+        assert_ne!(message_digest(b"a").unwrap(), message_digest(b"b").unwrap());
+        assert_eq!(
+            message_digest(&[0xff, 0xfe]).err(),
+            Some(ParserError::InvalidUtf8)
+        );
+    }
+}
@@ -36,6 +36,53 @@ pub struct Penalty(U128x128);
 impl Penalty {
     pub const PROTO_LEN: usize = PENALTY_BYTES + 2;
 
+    /// A rate representing how much of an asset remains after applying a penalty.
+    ///
+    /// e.g. a 1% penalty will yield a rate of 0.99 here.
+    pub fn kept_rate(&self) -> U128x128 {
+        self.0
+    }
+
+    /// Whether this `Penalty` takes anything away, i.e. the kept rate is below one.
+    pub fn is_slashing(&self) -> Result<bool, ParserError> {
+        Ok(self.slashed_rate()?.to_bytes() != [0u8; 32])
+    }
+
+    /// The fraction taken away by this `Penalty`, e.g. 0.01 for a 1% penalty.
+    ///
+    /// Fails if the kept rate exceeds one, as such a penalty would create value.
+    pub fn slashed_rate(&self) -> Result<U128x128, ParserError> {
+        (U128x128::from(1u128) - self.0).map_err(|_| ParserError::ValueOutOfRange)
+    }
+
+    /// Writes this `Penalty` as a percentage with exactly `precision` fractional
+    /// digits, rounded to nearest. Returns the number of bytes written.
+    pub fn to_percent_str(&self, precision: usize, out: &mut [u8]) -> Result<usize, ParserError> {
+        // A kept rate derived from bps^2 can land just above the intended
+        // value (a 1% penalty keeps 0.99 as ..a3d70a3e), so the slashed rate
+        // sits just below it; rounding to nearest keeps such a penalty from
+        // showing as 0.999999%.
+        let mut half_ulp = U128x128::from(10u128);
+        for _ in 0..precision {
+            half_ulp = (half_ulp * U128x128::from(10u128))?;
+        }
+        let half_ulp = (U128x128::from(5u128) / half_ulp)?;
+
+        let percent = (self.slashed_rate()? * U128x128::from(100u128))?;
+        (percent + half_ulp)?.to_decimal_str(precision, out)
+    }
+
+    /// The amount of staking tokens lost to this `Penalty` when claiming
+    /// `unbonding_amount`, i.e. the difference to an unslashed claim.
+    pub fn slashed_amount(&self, unbonding_amount: Amount) -> Result<Amount, ParserError> {
+        let claimed = self.apply_to_amount(unbonding_amount)?;
+        let inner = unbonding_amount
+            .inner
+            .checked_sub(claimed.inner)
+            .ok_or(ParserError::ValueOutOfRange)?;
+        Ok(Amount { inner })
+    }
+
     /// Apply this `Penalty` to an `Amount` of unbonding tokens.
     pub fn apply_to_amount(&self, amount: Amount) -> Result<Amount, ParserError> {
         self.0.apply_to_amount(&amount)
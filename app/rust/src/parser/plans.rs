@@ -17,24 +17,41 @@
 use crate::parser::{
     action::ActionPlan, action::ActionsHashC, detection::DetectionDataPlanC, memo::MemoPlanC,
 };
+use core::ffi::c_void;
+use decaf377::Fr;
 
 use crate::constants::{
-    ACTION_DUTCH_AUCTION_END_PERSONALIZED, ACTION_DUTCH_AUCTION_SCHEDULE_PERSONALIZED,
-    DELEGATE_PERSONALIZED, EFFECT_HASH_LEN, ICS20_WITHDRAWAL_PERSONALIZED,
-    POSITION_CLOSE_PERSONALIZED, POSITION_OPEN_PERSONALIZED, UNDELEGATE_PERSONALIZED,
+    EFFECT_HASH_LEN, ICS20_WITHDRAWAL_PERSONALIZED, PENALTY_DISPLAY_PRECISION,
+    PRICE_DISPLAY_PRECISION,
 };
 use crate::ffi::c_api::c_fvk_bytes;
+use crate::parser::amount::AmountC;
+use crate::parser::balance::{Balance, BalanceC};
+use crate::parser::binding::BindingC;
 use crate::parser::bytes::BytesC;
 use crate::parser::effect_hash::EffectHash;
+use crate::parser::fee::{Fee, FeeC};
+use crate::parser::flow_summary::FlowSummaryC;
 use crate::parser::parameters::ParametersHash;
+use crate::parser::penalty::Penalty;
+use crate::parser::rate_data::{RateData, RateDataC};
+use crate::parser::trading_pair::{TradingPair, TradingPairC};
+use crate::parser::value::Sign;
 use crate::ParserError;
 
+pub mod action_dutch_auction_end;
+pub mod action_dutch_auction_schedule;
 pub mod action_dutch_auction_withdraw;
+pub mod delegate;
 pub mod delegator_vote;
+pub mod ics20_withdrawal;
 pub mod output;
+pub mod position_close;
+pub mod position_open;
 pub mod position_withdraw;
 pub mod spend;
 pub mod swap;
+pub mod undelegate;
 pub mod undelegate_claim;
 
 #[repr(C)]
@@ -226,6 +243,60 @@ pub unsafe extern "C" fn rs_swap_action_hash(
     ParserError::Ok as u32
 }
 
+#[no_mangle]
+/// Use to compute an address and write it back into output
+/// argument.
+pub unsafe extern "C" fn rs_delegate_action_hash(
+    plan: &delegate::DelegatePlanC,
+    output: *mut u8,
+    output_len: usize,
+) -> u32 {
+    crate::zlog("rs_delegate_action_hash\x00");
+    let output = std::slice::from_raw_parts_mut(output, output_len);
+
+    if output.len() < EFFECT_HASH_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    match plan.effect_hash() {
+        Ok(body_hash_bytes) => {
+            let body_hash_array = body_hash_bytes.as_array();
+            let copy_len: usize = core::cmp::min(output.len(), body_hash_array.len());
+            output[..copy_len].copy_from_slice(&body_hash_array[..copy_len]);
+        }
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+#[no_mangle]
+/// Use to compute an address and write it back into output
+/// argument.
+pub unsafe extern "C" fn rs_undelegate_action_hash(
+    plan: &undelegate::UndelegatePlanC,
+    output: *mut u8,
+    output_len: usize,
+) -> u32 {
+    crate::zlog("rs_undelegate_action_hash\x00");
+    let output = std::slice::from_raw_parts_mut(output, output_len);
+
+    if output.len() < EFFECT_HASH_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    match plan.effect_hash() {
+        Ok(body_hash_bytes) => {
+            let body_hash_array = body_hash_bytes.as_array();
+            let copy_len: usize = core::cmp::min(output.len(), body_hash_array.len());
+            output[..copy_len].copy_from_slice(&body_hash_array[..copy_len]);
+        }
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
 #[no_mangle]
 /// Use to compute an address and write it back into output
 /// argument.
@@ -253,6 +324,48 @@ pub unsafe extern "C" fn rs_undelegate_claim_action_hash(
     ParserError::Ok as u32
 }
 
+#[no_mangle]
+/// Writes the slashing penalty of the claim as a null-terminated percentage,
+/// along with the staking tokens lost to it and the staking tokens claimed.
+/// `is_slashed` is set whenever the penalty is nonzero.
+pub unsafe extern "C" fn rs_undelegate_claim_penalty(
+    plan: &undelegate_claim::UndelegateClaimPlanC,
+    output: *mut u8,
+    output_len: u16,
+    slashed_amount: &mut AmountC,
+    claimed_amount: &mut AmountC,
+    is_slashed: &mut bool,
+) -> u32 {
+    let output = std::slice::from_raw_parts_mut(output, output_len as usize);
+    if output.is_empty() {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let Ok(penalty) = Penalty::try_from(plan.penalty.clone()) else {
+        return ParserError::UndelegateClaimPlanError as u32;
+    };
+
+    let last = output.len() - 1;
+    match penalty.to_percent_str(PENALTY_DISPLAY_PRECISION, &mut output[..last]) {
+        Ok(len) => output[len] = 0,
+        Err(err) => return err as u32,
+    }
+
+    let amounts = plan
+        .slashed_amount()
+        .and_then(|slashed| Ok((slashed, plan.claimed_amount()?)));
+    match (penalty.is_slashing(), amounts) {
+        (Ok(slashing), Ok((slashed, claimed))) => {
+            *is_slashed = slashing;
+            *slashed_amount = slashed.into();
+            *claimed_amount = claimed.into();
+        }
+        (Err(err), _) | (_, Err(err)) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
 #[no_mangle]
 /// Use to compute an address and write it back into output
 /// argument.
@@ -284,6 +397,218 @@ pub unsafe extern "C" fn rs_delegator_vote_action_hash(
     ParserError::Ok as u32
 }
 
+#[no_mangle]
+/// Checks that the delegator vote casts an actual vote with a delegation token
+/// note controlled by this device. `staked_denom` is the base denom of the
+/// staked note from the transaction metadata, empty when none was provided.
+pub unsafe extern "C" fn rs_delegator_vote_validate(
+    plan: &delegator_vote::DelegatorVotePlanC,
+    staked_denom: &BytesC,
+) -> u32 {
+    crate::zlog("rs_delegator_vote_validate\x00");
+
+    let Ok(fvk) = c_fvk_bytes() else {
+        return ParserError::InvalidFvk as u32;
+    };
+
+    let staked_denom = staked_denom
+        .get_bytes()
+        .ok()
+        .filter(|denom| !denom.is_empty())
+        .and_then(|denom| std::str::from_utf8(denom).ok());
+
+    match plan.validate(&fvk, staked_denom) {
+        Ok(()) => ParserError::Ok as u32,
+        Err(err) => err as u32,
+    }
+}
+
+#[no_mangle]
+/// Checks the delegation tokens minted by the delegation against the
+/// validator's rate data from the transaction metadata.
+pub unsafe extern "C" fn rs_delegate_check_rate(
+    plan: &delegate::DelegatePlanC,
+    rate: &RateDataC,
+) -> u32 {
+    crate::zlog("rs_delegate_check_rate\x00");
+
+    match RateData::try_from(rate).and_then(|rate| {
+        plan.to_action()
+            .and_then(|delegate| delegate.check_rate(&rate))
+    }) {
+        Ok(()) => ParserError::Ok as u32,
+        Err(err) => err as u32,
+    }
+}
+
+#[no_mangle]
+/// Checks the staking tokens released by the undelegation against the
+/// validator's rate data from the transaction metadata.
+pub unsafe extern "C" fn rs_undelegate_check_rate(
+    plan: &undelegate::UndelegatePlanC,
+    rate: &RateDataC,
+) -> u32 {
+    crate::zlog("rs_undelegate_check_rate\x00");
+
+    match RateData::try_from(rate).and_then(|rate| {
+        plan.to_action()
+            .and_then(|undelegate| undelegate.check_rate(&rate))
+    }) {
+        Ok(()) => ParserError::Ok as u32,
+        Err(err) => err as u32,
+    }
+}
+
+#[no_mangle]
+/// Use to compute an address and write it back into output
+/// argument.
+pub unsafe extern "C" fn rs_position_open_action_hash(
+    plan: &position_open::PositionOpenPlanC,
+    output: *mut u8,
+    output_len: usize,
+) -> u32 {
+    crate::zlog("rs_position_open_action_hash\x00");
+    let output = std::slice::from_raw_parts_mut(output, output_len);
+
+    if output.len() < EFFECT_HASH_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    match plan.effect_hash() {
+        Ok(body_hash_bytes) => {
+            let body_hash_array = body_hash_bytes.as_array();
+            let copy_len: usize = core::cmp::min(output.len(), body_hash_array.len());
+            output[..copy_len].copy_from_slice(&body_hash_array[..copy_len]);
+        }
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+#[no_mangle]
+/// Writes the price implied by the position's trading function as a
+/// null-terminated decimal string, in units of asset 2 per asset 1, or of
+/// asset 1 per asset 2 if `inverse` is set.
+pub unsafe extern "C" fn rs_position_open_implied_price(
+    plan: &position_open::PositionOpenPlanC,
+    inverse: bool,
+    output: *mut u8,
+    output_len: u16,
+) -> u32 {
+    let output = std::slice::from_raw_parts_mut(output, output_len as usize);
+    if output.is_empty() {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let position_open = match plan.position_open() {
+        Ok(position_open) => position_open,
+        Err(err) => return err as u32,
+    };
+
+    let component = &position_open.position.phi.component;
+    let price = if inverse {
+        component.implied_price_2_to_1()
+    } else {
+        component.implied_price_1_to_2()
+    };
+
+    let last = output.len() - 1;
+    match price.and_then(|price| price.to_decimal_str(PRICE_DISPLAY_PRECISION, &mut output[..last]))
+    {
+        Ok(len) => output[len] = 0,
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+#[no_mangle]
+/// Checks that a trading pair is canonically ordered and not cyclic.
+pub unsafe extern "C" fn rs_trading_pair_validate(pair: &TradingPairC) -> u32 {
+    match TradingPair::try_from(pair.clone()) {
+        Ok(_) => ParserError::Ok as u32,
+        Err(err) => err as u32,
+    }
+}
+
+#[no_mangle]
+/// Sets `sells_asset_1` and `sells_asset_2` to the assets of its trading pair
+/// that the swap sells. Both are set for a two-sided swap.
+pub unsafe extern "C" fn rs_swap_direction(
+    plan: &swap::SwapPlanC,
+    sells_asset_1: &mut bool,
+    sells_asset_2: &mut bool,
+) -> u32 {
+    match plan.direction() {
+        Ok(Some(direction)) => {
+            // asset 1 is the smaller asset of a canonical pair
+            *sells_asset_1 = direction.start.0 < direction.end.0;
+            *sells_asset_2 = !*sells_asset_1;
+            ParserError::Ok as u32
+        }
+        Ok(None) => {
+            *sells_asset_1 = true;
+            *sells_asset_2 = true;
+            ParserError::Ok as u32
+        }
+        Err(err) => err as u32,
+    }
+}
+
+#[no_mangle]
+/// Writes the `plpid1…` ID of the position being opened as a null-terminated string.
+pub unsafe extern "C" fn rs_position_open_position_id(
+    plan: &position_open::PositionOpenPlanC,
+    output: *mut u8,
+    output_len: u16,
+) -> u32 {
+    let output = std::slice::from_raw_parts_mut(output, output_len as usize);
+    if output.is_empty() {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let last = output.len() - 1;
+    let encoded = plan
+        .position_open()
+        .and_then(|position_open| position_open.position.id())
+        .and_then(|id| id.to_bech32m(&mut output[..last]));
+
+    match encoded {
+        Ok(len) => output[len] = 0,
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+#[no_mangle]
+/// Use to compute an address and write it back into output
+/// argument.
+pub unsafe extern "C" fn rs_position_close_action_hash(
+    plan: &position_close::PositionClosePlanC,
+    output: *mut u8,
+    output_len: usize,
+) -> u32 {
+    crate::zlog("rs_position_close_action_hash\x00");
+    let output = std::slice::from_raw_parts_mut(output, output_len);
+
+    if output.len() < EFFECT_HASH_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    match plan.effect_hash() {
+        Ok(body_hash_bytes) => {
+            let body_hash_array = body_hash_bytes.as_array();
+            let copy_len: usize = core::cmp::min(output.len(), body_hash_array.len());
+            output[..copy_len].copy_from_slice(&body_hash_array[..copy_len]);
+        }
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
 #[no_mangle]
 /// Use to compute an address and write it back into output
 /// argument.
@@ -311,6 +636,33 @@ pub unsafe extern "C" fn rs_position_withdraw_action_hash(
     ParserError::Ok as u32
 }
 
+#[no_mangle]
+/// Use to compute an address and write it back into output
+/// argument.
+pub unsafe extern "C" fn rs_action_dutch_auction_end_action_hash(
+    plan: &action_dutch_auction_end::ActionDutchAuctionEndPlanC,
+    output: *mut u8,
+    output_len: usize,
+) -> u32 {
+    crate::zlog("rs_action_dutch_auction_end_action_hash\x00");
+    let output = std::slice::from_raw_parts_mut(output, output_len);
+
+    if output.len() < EFFECT_HASH_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    match plan.effect_hash() {
+        Ok(body_hash_bytes) => {
+            let body_hash_array = body_hash_bytes.as_array();
+            let copy_len: usize = core::cmp::min(output.len(), body_hash_array.len());
+            output[..copy_len].copy_from_slice(&body_hash_array[..copy_len]);
+        }
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
 #[no_mangle]
 /// Use to compute an address and write it back into output
 /// argument.
@@ -338,6 +690,85 @@ pub unsafe extern "C" fn rs_action_dutch_auction_withdraw_action_hash(
     ParserError::Ok as u32
 }
 
+#[no_mangle]
+/// Use to compute an address and write it back into output
+/// argument.
+pub unsafe extern "C" fn rs_action_dutch_auction_schedule_action_hash(
+    plan: &action_dutch_auction_schedule::ActionDutchAuctionSchedulePlanC,
+    output: *mut u8,
+    output_len: usize,
+) -> u32 {
+    crate::zlog("rs_action_dutch_auction_schedule_action_hash\x00");
+    let output = std::slice::from_raw_parts_mut(output, output_len);
+
+    if output.len() < EFFECT_HASH_LEN {
+        return ParserError::InvalidLength as u32;
+    }
+
+    match plan.effect_hash() {
+        Ok(body_hash_bytes) => {
+            let body_hash_array = body_hash_bytes.as_array();
+            let copy_len: usize = core::cmp::min(output.len(), body_hash_array.len());
+            output[..copy_len].copy_from_slice(&body_hash_array[..copy_len]);
+        }
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+#[no_mangle]
+/// Writes the `pauctid1…` ID of the auction being scheduled as a null-terminated string.
+pub unsafe extern "C" fn rs_action_dutch_auction_schedule_auction_id(
+    plan: &action_dutch_auction_schedule::ActionDutchAuctionSchedulePlanC,
+    output: *mut u8,
+    output_len: u16,
+) -> u32 {
+    let output = std::slice::from_raw_parts_mut(output, output_len as usize);
+    if output.is_empty() {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let last = output.len() - 1;
+    match plan
+        .to_action()
+        .and_then(|action| action.description.id())
+        .and_then(|id| id.to_bech32m(&mut output[..last]))
+    {
+        Ok(len) => output[len] = 0,
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
+#[no_mangle]
+/// Writes the price offered at `step_index` of the auction, in units of output
+/// per unit of input, as a null-terminated decimal string.
+pub unsafe extern "C" fn rs_action_dutch_auction_schedule_price(
+    plan: &action_dutch_auction_schedule::ActionDutchAuctionSchedulePlanC,
+    step_index: u64,
+    output: *mut u8,
+    output_len: u16,
+) -> u32 {
+    let output = std::slice::from_raw_parts_mut(output, output_len as usize);
+    if output.is_empty() {
+        return ParserError::InvalidLength as u32;
+    }
+
+    let last = output.len() - 1;
+    match plan
+        .to_action()
+        .and_then(|action| action.description.price_at_step(step_index))
+        .and_then(|price| price.to_decimal_str(PRICE_DISPLAY_PRECISION, &mut output[..last]))
+    {
+        Ok(len) => output[len] = 0,
+        Err(err) => return err as u32,
+    }
+
+    ParserError::Ok as u32
+}
+
 #[no_mangle]
 /// Use to compute an address and write it back into output
 /// argument.
@@ -358,86 +789,223 @@ pub unsafe extern "C" fn rs_generic_action_hash(
     let effect_hash: EffectHash;
     if let Ok(data_to_hash) = data.get_bytes() {
         match action_type {
-            ActionPlan::Delegate => {
-                let Ok(personalized) = std::str::from_utf8(DELEGATE_PERSONALIZED) else {
-                    return ParserError::InvalidUtf8 as u32;
-                };
-                effect_hash = EffectHash::from_proto_effecting_data(personalized, data_to_hash);
-            }
-            ActionPlan::Undelegate => {
-                let Ok(personalized) = std::str::from_utf8(UNDELEGATE_PERSONALIZED) else {
-                    return ParserError::InvalidUtf8 as u32;
-                };
-                effect_hash = EffectHash::from_proto_effecting_data(personalized, data_to_hash);
-            }
             ActionPlan::Ics20Withdrawal => {
                 let Ok(personalized) = std::str::from_utf8(ICS20_WITHDRAWAL_PERSONALIZED) else {
                     return ParserError::InvalidUtf8 as u32;
                 };
                 effect_hash = EffectHash::from_proto_effecting_data(personalized, data_to_hash);
             }
-            ActionPlan::PositionOpenPlan => {
-                let Ok(personalized) = std::str::from_utf8(POSITION_OPEN_PERSONALIZED) else {
-                    return ParserError::InvalidUtf8 as u32;
-                };
-                effect_hash = EffectHash::from_proto_effecting_data(personalized, data_to_hash);
-            }
-            ActionPlan::PositionClose => {
-                let Ok(personalized) = std::str::from_utf8(POSITION_CLOSE_PERSONALIZED) else {
-                    return ParserError::InvalidUtf8 as u32;
-                };
-                effect_hash = EffectHash::from_proto_effecting_data(personalized, data_to_hash);
-            }
-            ActionPlan::ActionDutchAuctionSchedule => {
-                let Ok(personalized) =
-                    std::str::from_utf8(ACTION_DUTCH_AUCTION_SCHEDULE_PERSONALIZED)
-                else {
-                    return ParserError::InvalidUtf8 as u32;
-                };
-                effect_hash = EffectHash::from_proto_effecting_data(personalized, data_to_hash);
-            }
-            ActionPlan::ActionDutchAuctionEnd => {
-                let Ok(personalized) = std::str::from_utf8(ACTION_DUTCH_AUCTION_END_PERSONALIZED)
-                else {
-                    return ParserError::InvalidUtf8 as u32;
-                };
-                effect_hash = EffectHash::from_proto_effecting_data(personalized, data_to_hash);
-            }
             _ => {
                 return ParserError::InvalidActionType as u32;
             }
         }
 
-        let body_hash_array = effect_hash.as_bytes();
-        let copy_len: usize = core::cmp::min(output.len(), body_hash_array.len());
-        output[..copy_len].copy_from_slice(&body_hash_array[..copy_len]);
+        let body_hash_array = effect_hash.as_bytes();
+        let copy_len: usize = core::cmp::min(output.len(), body_hash_array.len());
+        output[..copy_len].copy_from_slice(&body_hash_array[..copy_len]);
+    }
+
+    ParserError::Ok as u32
+}
+
+#[no_mangle]
+/// Adds the balance of a single action to the flow summary. Spends and
+/// outputs back to one of our own addresses stay within the wallet and are
+/// left out.
+///
+/// `action` points at the plan matching `action_type`, as laid out in the C
+/// `action_t` union.
+pub unsafe extern "C" fn rs_flow_summary_add_action(
+    action_type: u8,
+    action: *const c_void,
+    summary: &mut FlowSummaryC,
+) -> u32 {
+    crate::zlog("rs_flow_summary_add_action\x00");
+
+    let added = match ActionPlan::from(action_type) {
+        ActionPlan::Spend => Ok(()),
+        ActionPlan::Output => c_fvk_bytes()
+            .map_err(|_| ParserError::InvalidFvk)
+            .and_then(|fvk| summary.add_output(&*action.cast::<output::OutputPlanC>(), &fvk))
+            .map(|_| ()),
+        _ => action_balance(action_type, action).and_then(|balance| summary.add_balance(&balance)),
+    };
+    match added {
+        Ok(()) => ParserError::Ok as u32,
+        Err(err) => err as u32,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn rs_flow_summary_add_fee(fee: &FeeC, summary: &mut FlowSummaryC) -> u32 {
+    match Fee::try_from(fee.clone()).and_then(|fee| summary.add_fee(&fee)) {
+        Ok(()) => ParserError::Ok as u32,
+        Err(err) => err as u32,
+    }
+}
+
+/// Balance of the plan matching `action_type`, as laid out in the C
+/// `action_t` union.
+unsafe fn action_balance(action_type: u8, action: *const c_void) -> Result<Balance, ParserError> {
+    use action_dutch_auction_end::ActionDutchAuctionEndPlanC;
+    use action_dutch_auction_schedule::ActionDutchAuctionSchedulePlanC;
+    use action_dutch_auction_withdraw::ActionDutchAuctionWithdrawPlanC;
+
+    match ActionPlan::from(action_type) {
+        ActionPlan::Spend => (*action.cast::<spend::SpendPlanC>()).balance(),
+        ActionPlan::Output => (*action.cast::<output::OutputPlanC>()).balance(),
+        ActionPlan::Swap => (*action.cast::<swap::SwapPlanC>()).balance(),
+        ActionPlan::Ics20Withdrawal => {
+            (*action.cast::<ics20_withdrawal::Ics20WithdrawalPlanC>()).balance()
+        }
+        ActionPlan::Delegate => (*action.cast::<delegate::DelegatePlanC>())
+            .to_action()
+            .and_then(|delegate| delegate.balance()),
+        ActionPlan::Undelegate => (*action.cast::<undelegate::UndelegatePlanC>())
+            .to_action()
+            .and_then(|undelegate| undelegate.balance()),
+        ActionPlan::UndelegateClaim => {
+            (*action.cast::<undelegate_claim::UndelegateClaimPlanC>()).balance()
+        }
+        ActionPlan::PositionOpenPlan => {
+            (*action.cast::<position_open::PositionOpenPlanC>()).balance()
+        }
+        ActionPlan::PositionClose => {
+            (*action.cast::<position_close::PositionClosePlanC>()).balance()
+        }
+        ActionPlan::PositionWithdraw => {
+            (*action.cast::<position_withdraw::PositionWithdrawPlanC>()).balance()
+        }
+        ActionPlan::ActionDutchAuctionSchedule => {
+            (*action.cast::<ActionDutchAuctionSchedulePlanC>()).balance()
+        }
+        ActionPlan::ActionDutchAuctionEnd => {
+            (*action.cast::<ActionDutchAuctionEndPlanC>()).balance()
+        }
+        ActionPlan::ActionDutchAuctionWithdraw => {
+            (*action.cast::<ActionDutchAuctionWithdrawPlanC>()).balance()
+        }
+        // Votes only prove the voting power of a note, they move no value
+        ActionPlan::DelegatorVote => Ok(Balance::new()),
+        _ => Err(ParserError::InvalidActionType),
+    }
+}
+
+/// Synthetic blinding factor the plan matching `action_type` commits to its
+/// balance with. Actions whose balance is public commit with zero.
+unsafe fn action_blinding(action_type: u8, action: *const c_void) -> Result<Fr, ParserError> {
+    match ActionPlan::from(action_type) {
+        ActionPlan::Spend => (*action.cast::<spend::SpendPlanC>()).get_value_blinding_fr(),
+        ActionPlan::Output => (*action.cast::<output::OutputPlanC>()).get_value_blinding_fr(),
+        ActionPlan::Swap => (*action.cast::<swap::SwapPlanC>()).get_fee_blinding_fr(),
+        ActionPlan::UndelegateClaim => {
+            (*action.cast::<undelegate_claim::UndelegateClaimPlanC>()).get_balance_blinding_fr()
+        }
+        _ => Ok(Fr::ZERO),
+    }
+}
+
+#[no_mangle]
+/// Nets the balance of a single action into `plan_balance`. Unlike the flow
+/// summary, spends and change outputs count here, since the chain checks the
+/// whole transaction.
+///
+/// `action` points at the plan matching `action_type`, as laid out in the C
+/// `action_t` union.
+pub unsafe extern "C" fn rs_plan_balance_add(
+    action_type: u8,
+    action: *const c_void,
+    plan_balance: &mut BalanceC,
+) -> u32 {
+    crate::zlog("rs_plan_balance_add\x00");
+
+    let total = action_balance(action_type, action).and_then(|balance| {
+        let mut total = Balance::try_from(&*plan_balance)?;
+        total.merge(&balance)?;
+        Ok(total)
+    });
+    match total {
+        Ok(total) => {
+            *plan_balance = BalanceC::from(&total);
+            ParserError::Ok as u32
+        }
+        Err(err) => err as u32,
+    }
+}
+
+#[no_mangle]
+/// Adds the balance commitment of a single action, and the blinding factor
+/// it was made with, to the running sums for the binding signature.
+///
+/// Delegator votes carry no balance commitment and are skipped.
+pub unsafe extern "C" fn rs_binding_add_action(
+    action_type: u8,
+    action: *const c_void,
+    binding: &mut BindingC,
+) -> u32 {
+    crate::zlog("rs_binding_add_action\x00");
+
+    if ActionPlan::from(action_type) == ActionPlan::DelegatorVote {
+        return ParserError::Ok as u32;
     }
 
-    ParserError::Ok as u32
+    let added = action_balance(action_type, action).and_then(|balance| {
+        let blinding = action_blinding(action_type, action)?;
+        binding.add(&balance.commit(blinding)?, blinding)
+    });
+    match added {
+        Ok(()) => ParserError::Ok as u32,
+        Err(err) => err as u32,
+    }
+}
+
+#[no_mangle]
+/// Checks that the actions of a plan, minus its fee, balance to zero for
+/// every asset. This is the same invariant the binding signature proves.
+pub unsafe extern "C" fn rs_plan_balance_check(plan_balance: &BalanceC, fee: &FeeC) -> u32 {
+    crate::zlog("rs_plan_balance_check\x00");
+
+    let total = Balance::try_from(plan_balance).and_then(|mut total| {
+        let fee = Fee::try_from(fee.clone())?;
+        total.add(&fee.0, Sign::Required)?;
+        Ok(total)
+    });
+    match total {
+        Ok(total) if total.is_zero() => ParserError::Ok as u32,
+        Ok(_) => ParserError::UnbalancedPlan as u32,
+        Err(err) => err as u32,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::address::AddressIndex;
     use crate::keys::spend_key::SpendKeyBytes;
     use crate::parser::action::ActionHash;
     use crate::parser::action::ActionsHashC;
     use crate::parser::address::AddressC;
     use crate::parser::amount::AmountC;
+    use crate::parser::auction::{AuctionId, AuctionNft, DutchAuctionDescriptionC};
     use crate::parser::bytes::BytesC;
     use crate::parser::clue_plan::CluePlanC;
+    use crate::parser::delegation_token::DelegationToken;
     use crate::parser::detection::DetectionDataPlanC;
+    use crate::parser::epoch::EpochC;
     use crate::parser::fee::FeeC;
-    use crate::parser::id::IdC;
+    use crate::parser::id::{Id, IdC};
     use crate::parser::identity_key::IdentityKeyC;
     use crate::parser::memo::MemoPlanC;
     use crate::parser::memo_plain_text::MemoPlaintextC;
     use crate::parser::note::NoteC;
     use crate::parser::penalty::PenaltyC;
+    use crate::parser::position::{LpNft, PositionC, PositionId, PositionState, PositionStateC};
     use crate::parser::reserves::ReservesC;
     use crate::parser::swap_plaintext::SwapPlaintextC;
+    use crate::parser::trading_function::{BareTradingFunctionC, TradingFunctionC};
     use crate::parser::trading_pair::TradingPairC;
-    use crate::parser::value::ValueC;
+    use crate::parser::validator_identity::ValidatorIdentity;
+    use crate::parser::value::{Sign, Value, ValueC};
 
     #[test]
     fn test_transaction_plan_hash() {
@@ -657,11 +1225,14 @@ mod tests {
         let asset_1_id_bytes =
             hex::decode("29ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10")
                 .unwrap();
+        let asset_2_id_bytes =
+            hex::decode("9f03c3910ab73af2e70701930fe9e6bf521f6f61849850a0347ad4fbef41b111")
+                .unwrap();
         let dummy_trading_pair = TradingPairC {
             has_asset_1: true,
             asset_1: BytesC::from_slice(&asset_1_id_bytes),
             has_asset_2: true,
-            asset_2: BytesC::from_slice(&asset_1_id_bytes),
+            asset_2: BytesC::from_slice(&asset_2_id_bytes),
         };
 
         // Create dummy delta_1_i
@@ -729,13 +1300,71 @@ mod tests {
         let fvk = spend_key.fvk().unwrap();
 
         let swap_action_hash = dummy_action.effect_hash(&fvk);
-        let expected_hash = "b648fd6fb4b3801586a0d3c6881338a9da1aef1d5def434340b32b719ba7e890d65673ec5423e99668606d11f51bafd11e0158556f7c958809e9dd2b01921d7a";
+        let expected_hash = "d9c5fdefded37ed797c64a85760b68319dcf5c32dd511d9d33c51ee2338fa7c64ed20ed62ebffcc86e0aeed10e83abceb58b81f242627a52103416ac9d5d4dcf";
         if let Ok(swap_action_hash_bytes) = swap_action_hash {
             let computed_hash = hex::encode(swap_action_hash_bytes.as_array());
             assert_eq!(computed_hash, expected_hash);
         } else {
             panic!("swap_action_hash is not Ok");
         }
+
+        // the swap sells both assets of the pair
+        let mut sells_asset_1 = false;
+        let mut sells_asset_2 = false;
+        let err =
+            unsafe { rs_swap_direction(&dummy_action, &mut sells_asset_1, &mut sells_asset_2) };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert!(sells_asset_1 && sells_asset_2);
+
+        // and only asset 2 once delta_1_i is dropped
+        let mut one_sided = dummy_action.clone();
+        one_sided.swap_plaintext.delta_1_i = AmountC { lo: 0, hi: 0 };
+        let err = unsafe { rs_swap_direction(&one_sided, &mut sells_asset_1, &mut sells_asset_2) };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert!(!sells_asset_1 && sells_asset_2);
+    }
+
+    #[test]
+    fn test_trading_pair_validation() {
+        let asset_1_id_bytes =
+            hex::decode("29ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10")
+                .unwrap();
+        let asset_2_id_bytes =
+            hex::decode("9f03c3910ab73af2e70701930fe9e6bf521f6f61849850a0347ad4fbef41b111")
+                .unwrap();
+        let pair = |asset_1: &[u8], asset_2: &[u8]| TradingPairC {
+            has_asset_1: true,
+            asset_1: BytesC::from_slice(asset_1),
+            has_asset_2: true,
+            asset_2: BytesC::from_slice(asset_2),
+        };
+
+        let canonical = pair(&asset_1_id_bytes, &asset_2_id_bytes);
+        assert_eq!(
+            unsafe { rs_trading_pair_validate(&canonical) },
+            ParserError::Ok as u32
+        );
+
+        let reversed = pair(&asset_2_id_bytes, &asset_1_id_bytes);
+        assert_eq!(
+            unsafe { rs_trading_pair_validate(&reversed) },
+            ParserError::InvalidTradingPair as u32
+        );
+
+        let cyclic = pair(&asset_1_id_bytes, &asset_1_id_bytes);
+        assert_eq!(
+            unsafe { rs_trading_pair_validate(&cyclic) },
+            ParserError::InvalidTradingPair as u32
+        );
+
+        let trading_pair = TradingPair::try_from(canonical).unwrap();
+        let sell_2_for_1 = trading_pair.orient(trading_pair.asset_2()).unwrap();
+        assert!(sell_2_for_1.start == *trading_pair.asset_2());
+        assert!(sell_2_for_1.end == *trading_pair.asset_1());
+        let sell_1_for_2 = sell_2_for_1.flip();
+        assert!(sell_1_for_2.start == *trading_pair.asset_1());
+        let canonical_again = sell_2_for_1.to_canonical().unwrap();
+        assert!(canonical_again.asset_1() == trading_pair.asset_1());
     }
 
     #[test]
@@ -820,6 +1449,90 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_undelegate_claim_penalty() {
+        let ik_bytes =
+            hex::decode("1e32c63102334a0fdfed9fdd4aa6b088824d1d42ad40109f4a56f8845dfb0e32")
+                .unwrap();
+        let blinding_bytes =
+            hex::decode("02a147e3c45b43f4f0cc9d8d6e2940c6927cbb5141b6062aae8ac3ba10ac4504")
+                .unwrap();
+
+        // a slashed claim, a 1% penalty built from bps^2 and an unslashed claim
+        for (penalty, percent, slashed, claimed, is_slashing) in [
+            (
+                "00000000000000000000000000000000fecbfb15b573eab367a0f9096bb98c7f",
+                "0.470000",
+                349831353119732u64,
+                74082371438312499u64,
+                true,
+            ),
+            (
+                "00000000000000000000000000000000fd70a3d70a3d70a3d70a3d70a3d70a3e",
+                "1.000000",
+                744322027914323,
+                73687880763517908,
+                true,
+            ),
+            (
+                "0000000000000000000000000000000100000000000000000000000000000000",
+                "0.000000",
+                0,
+                74432202791432231,
+                false,
+            ),
+        ] {
+            let penalty_bytes = hex::decode(penalty).unwrap();
+            let plan = undelegate_claim::UndelegateClaimPlanC {
+                has_validator_identity: true,
+                validator_identity: IdentityKeyC {
+                    ik: BytesC::from_slice(&ik_bytes),
+                },
+                has_penalty: true,
+                penalty: PenaltyC {
+                    inner: BytesC::from_slice(&penalty_bytes),
+                },
+                has_unbonding_amount: true,
+                unbonding_amount: AmountC {
+                    lo: 74432202791432231,
+                    hi: 0,
+                },
+                balance_blinding: BytesC::from_slice(&blinding_bytes),
+                unbonding_start_height: 25928,
+            };
+
+            let mut output = [0u8; 16];
+            let mut slashed_amount = AmountC { lo: 0, hi: 0 };
+            let mut claimed_amount = AmountC { lo: 0, hi: 0 };
+            let mut is_slashed = !is_slashing;
+            let err = unsafe {
+                rs_undelegate_claim_penalty(
+                    &plan,
+                    output.as_mut_ptr(),
+                    output.len() as u16,
+                    &mut slashed_amount,
+                    &mut claimed_amount,
+                    &mut is_slashed,
+                )
+            };
+            assert_eq!(err, ParserError::Ok as u32);
+            assert_eq!(&output[..percent.len()], percent.as_bytes());
+            assert_eq!(output[percent.len()], 0);
+            assert_eq!(slashed_amount.lo, slashed);
+            assert_eq!(claimed_amount.lo, claimed);
+            assert_eq!(is_slashed, is_slashing);
+        }
+
+        // a penalty keeping more than the claimed amount is rejected
+        let penalty = Penalty::try_from(
+            hex::decode("0000000000000000000000000000000100000000000000000000000000000001")
+                .unwrap()
+                .as_slice(),
+        )
+        .unwrap();
+        assert!(penalty.is_slashing().is_err());
+    }
+
     #[test]
     fn test_delegator_vote_action_hash() {
         // Create dummy ActionC
@@ -895,6 +1608,260 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_delegator_vote_validate() {
+        let spend_key = SpendKeyBytes::from([
+            0xa1, 0xff, 0xba, 0x0c, 0x37, 0x93, 0x1f, 0x0a, 0x62, 0x61, 0x37, 0x52, 0x0d, 0xa6,
+            0x50, 0x63, 0x2d, 0x35, 0x85, 0x3b, 0xf5, 0x91, 0xb3, 0x6b, 0xb4, 0x28, 0x63, 0x0a,
+            0x4d, 0x87, 0xc4, 0xdc,
+        ]);
+        let fvk = spend_key.fvk().unwrap();
+
+        let token = DelegationToken::new(ValidatorIdentity([3u8; 32]));
+        let mut denom = [0u8; DelegationToken::MAX_DENOM_LEN];
+        let denom_len = token.denom(&mut denom).unwrap();
+        let denom = std::str::from_utf8(&denom[..denom_len]).unwrap();
+        let asset_id = token.asset_id().unwrap().to_bytes();
+
+        let (owned, _) = fvk.payment_address(AddressIndex::new(7)).unwrap();
+        let owned = owned.to_bytes().unwrap();
+        let foreign = hex::decode("7616f6c402371db1fa79eca16f1892132bbc1ea65e133fa67388049719f62f45c36fe666cc95ecc4444f6561a36d30fa6aad47a89032c8966f05a7cb098f9fd9ee392d0d337f3c35a33284ed4317f392").unwrap();
+        let other_asset_id =
+            hex::decode("29ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10")
+                .unwrap();
+        let rseed = hex::decode("7c14e7434fde0abeccbc2579e58eeb65045e538b14cad708c988075b9fc0df66")
+            .unwrap();
+
+        let plan = |vote: u8, asset_id: &[u8], address: &[u8], unbonded: u64| {
+            delegator_vote::DelegatorVotePlanC {
+                proposal: 267193148,
+                start_position: 20,
+                has_vote: true,
+                vote,
+                has_staked_note: true,
+                staked_note: NoteC {
+                    has_value: true,
+                    value: ValueC {
+                        has_amount: true,
+                        amount: AmountC { lo: 1000, hi: 0 },
+                        has_asset_id: true,
+                        asset_id: IdC {
+                            inner: BytesC::from_slice(asset_id),
+                        },
+                    },
+                    rseed: BytesC::from_slice(&rseed),
+                    has_address: true,
+                    address: AddressC {
+                        inner: BytesC::from_slice(address),
+                        alt_bech32m: BytesC::default(),
+                    },
+                },
+                staked_note_position: 30,
+                has_unbonded_amount: true,
+                unbonded_amount: AmountC {
+                    lo: unbonded,
+                    hi: 0,
+                },
+                randomizer: BytesC::default(),
+            }
+        };
+
+        let valid = plan(2, &asset_id, &owned, 1200);
+        assert!(valid.validate(&fvk, Some(denom)).is_ok());
+        // Without the staked denom only the delegation token check is skipped
+        assert!(valid.validate(&fvk, None).is_ok());
+
+        for (plan, staked_denom, expected) in [
+            (
+                plan(0, &asset_id, &owned, 1200),
+                Some(denom),
+                ParserError::UnspecifiedVote,
+            ),
+            (
+                plan(4, &asset_id, &owned, 1200),
+                Some(denom),
+                ParserError::UnspecifiedVote,
+            ),
+            (
+                plan(1, &asset_id, &owned, 1200),
+                Some("upenumbra"),
+                ParserError::StakedNoteNotDelegationToken,
+            ),
+            (
+                plan(1, &other_asset_id, &owned, 1200),
+                Some(denom),
+                ParserError::StakedNoteNotDelegationToken,
+            ),
+            (
+                plan(3, &asset_id, &foreign, 1200),
+                Some(denom),
+                ParserError::StakedNoteNotOwned,
+            ),
+            (
+                plan(3, &asset_id, &owned, 0),
+                Some(denom),
+                ParserError::ZeroUnbondedAmount,
+            ),
+        ] {
+            assert_eq!(plan.validate(&fvk, staked_denom).err(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_position_open_action_hash() {
+        let pair_1_bytes =
+            hex::decode("29ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10")
+                .unwrap();
+        let pair_2_bytes =
+            hex::decode("9f03c3910ab73af2e70701930fe9e6bf521f6f61849850a0347ad4fbef41b111")
+                .unwrap();
+        let nonce_bytes =
+            hex::decode("8347406e56080da0e7277d2e4b28d703d6e0b68fd7eb9bac5274d4a236670c57")
+                .unwrap();
+
+        let dummy_phi = TradingFunctionC {
+            has_component: true,
+            component: BareTradingFunctionC {
+                fee: 0,
+                has_p: true,
+                p: AmountC {
+                    lo: 769797955300442547,
+                    hi: 0,
+                },
+                has_q: true,
+                q: AmountC {
+                    lo: 887118975343387770,
+                    hi: 0,
+                },
+            },
+            has_pair: true,
+            pair: TradingPairC {
+                has_asset_1: true,
+                asset_1: BytesC::from_slice(&pair_1_bytes),
+                has_asset_2: true,
+                asset_2: BytesC::from_slice(&pair_2_bytes),
+            },
+        };
+
+        let dummy_reserves = ReservesC {
+            has_r1: true,
+            r1: AmountC {
+                lo: 394240010563350915,
+                hi: 0,
+            },
+            has_r2: true,
+            r2: AmountC {
+                lo: 15180663512249071,
+                hi: 0,
+            },
+        };
+
+        let dummy_action = position_open::PositionOpenPlanC {
+            has_position: true,
+            position: PositionC {
+                has_phi: true,
+                phi: dummy_phi,
+                nonce: BytesC::from_slice(&nonce_bytes),
+                has_state: true,
+                state: PositionStateC {
+                    state: 1,
+                    sequence: 0,
+                },
+                has_reserves: true,
+                reserves: dummy_reserves,
+                close_on_fill: true,
+            },
+            encrypted_metadata: BytesC::default(),
+        };
+
+        let position_open_hash = dummy_action.effect_hash();
+        let expected_hash = "5be6834941c3ded5e9b738d2fa129f2766ebfc0f4be2f43c942dbcbbade9bab628df7e2f1d5d87eff51e62d5c5089efd0b165a7472791fd1405e02b5ea022a88";
+        if let Ok(position_open_hash_bytes) = position_open_hash {
+            let computed_hash = hex::encode(position_open_hash_bytes.as_array());
+            assert_eq!(computed_hash, expected_hash);
+        } else {
+            panic!("position_open_hash is not Ok");
+        }
+
+        let mut price = [0u8; 48];
+        let err =
+            unsafe { rs_position_open_implied_price(&dummy_action, false, price.as_mut_ptr(), 48) };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert!(price.starts_with(b"0.867750\0"));
+
+        let err =
+            unsafe { rs_position_open_implied_price(&dummy_action, true, price.as_mut_ptr(), 48) };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert!(price.starts_with(b"1.152404\0"));
+
+        let mut position_id = [0u8; 65];
+        let err =
+            unsafe { rs_position_open_position_id(&dummy_action, position_id.as_mut_ptr(), 65) };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert_eq!(
+            &position_id[..],
+            b"plpid1skk7nlkqfwkpxzz2lun6rhmeeqyshu5xydu4kmq9zmtvfsjswa0qn7h6k5\0"
+        );
+
+        // the same position without close_on_fill gets another ID
+        let mut plain = dummy_action.clone();
+        plain.position.close_on_fill = false;
+        let err = unsafe { rs_position_open_position_id(&plain, position_id.as_mut_ptr(), 65) };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert_eq!(
+            &position_id[..],
+            b"plpid1v8lpj39kms76w7807kgzfkq6srgywyvfq3jhkregh3v3zdgx93cq0flarm\0"
+        );
+
+        // only positions in the opened state can be opened
+        let mut closed = dummy_action.clone();
+        closed.position.state.state = 2;
+        assert_eq!(
+            closed.effect_hash().err(),
+            Some(ParserError::PositionOpenPlanError)
+        );
+
+        // metadata would change the effect hash, so it is refused outright
+        let metadata = [0u8; 50];
+        let mut with_metadata = dummy_action.clone();
+        with_metadata.encrypted_metadata = BytesC::from_slice(&metadata);
+        assert_eq!(
+            with_metadata.effect_hash().err(),
+            Some(ParserError::PositionMetadataUnsupported)
+        );
+    }
+
+    #[test]
+    fn test_position_close_action_hash() {
+        let position_id_bytes =
+            hex::decode("4bbe086b0f46948f7fd7a5b873f7fb086fc399ede0ed812cfb46800769c701b5")
+                .unwrap();
+
+        let dummy_action = position_close::PositionClosePlanC {
+            has_position_id: true,
+            position_id: IdC {
+                inner: BytesC::from_slice(&position_id_bytes),
+            },
+        };
+
+        let position_close_hash = dummy_action.effect_hash();
+        let expected_hash = "ddbba7981049d703ad1760fa16c3bc56ab26170fac5ce6d0b2a351db1c094ce405a332cfc34c67652b56b55006a336045dc754a77a7429e7abcc7f134f8a0e53";
+        if let Ok(position_close_hash_bytes) = position_close_hash {
+            let computed_hash = hex::encode(position_close_hash_bytes.as_array());
+            assert_eq!(computed_hash, expected_hash);
+        } else {
+            panic!("position_close_hash is not Ok");
+        }
+
+        let mut encoded = [0u8; PositionId::BECH32_LEN];
+        let position_id = dummy_action.position_close().unwrap().position_id;
+        let len = position_id.to_bech32m(&mut encoded).unwrap();
+        assert_eq!(
+            &encoded[..len],
+            b"plpid1fwlqs6c0g62g7l7h5ku88almpphu8x0durkczt8mg6qqw6w8qx6s2gnap4"
+        );
+    }
+
     #[test]
     fn test_position_withdraw_action_hash() {
         // Create dummy ActionC
@@ -1039,6 +2006,128 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dutch_auction_schedule_action_hash() {
+        let asset_id_bytes =
+            hex::decode("29ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10")
+                .unwrap();
+        let output_id_bytes =
+            hex::decode("76b3e4b10681358c123b381f90638476b7789040e47802de879f0fb3eedc8d0b")
+                .unwrap();
+        let nonce_bytes =
+            hex::decode("d9d31f398ab3199ae95fd1da6270b30829b1c07cf1c88a78ee8a6cef3d8ecd38")
+                .unwrap();
+
+        let dummy_action = action_dutch_auction_schedule::ActionDutchAuctionSchedulePlanC {
+            has_description: true,
+            description: DutchAuctionDescriptionC {
+                has_input: true,
+                input: ValueC {
+                    has_amount: true,
+                    amount: AmountC {
+                        lo: 745828833716472921,
+                        hi: 0,
+                    },
+                    has_asset_id: true,
+                    asset_id: IdC {
+                        inner: BytesC::from_slice(&asset_id_bytes),
+                    },
+                },
+                has_output_id: true,
+                output_id: IdC {
+                    inner: BytesC::from_slice(&output_id_bytes),
+                },
+                has_max_output: true,
+                max_output: AmountC {
+                    lo: 867664568342528772,
+                    hi: 0,
+                },
+                has_min_output: true,
+                min_output: AmountC {
+                    lo: 681437623424907622,
+                    hi: 0,
+                },
+                start_height: 720997797,
+                end_height: 720998797,
+                step_count: 100,
+                nonce: BytesC::from_slice(&nonce_bytes),
+            },
+        };
+
+        let dutch_auction_schedule_hash = dummy_action.effect_hash();
+        let expected_hash = "caacb42377bd0ce5bdfb9acf4502feba42bdb7996c973d4dbbf43f31b6f03102faf5e80a6f9288a9c8380b46346b8c5bfca310bef20fc350e5aeab4fc77e169e";
+        if let Ok(dutch_auction_schedule_hash_bytes) = dutch_auction_schedule_hash {
+            let computed_hash = hex::encode(dutch_auction_schedule_hash_bytes.as_array());
+            assert_eq!(computed_hash, expected_hash);
+        } else {
+            panic!("dutch_auction_schedule_hash is not Ok");
+        }
+
+        let mut auction_id = [0u8; AuctionId::BECH32_LEN + 1];
+        let err = unsafe {
+            rs_action_dutch_auction_schedule_auction_id(
+                &dummy_action,
+                auction_id.as_mut_ptr(),
+                auction_id.len() as u16,
+            )
+        };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert_eq!(
+            &auction_id[..],
+            b"pauctid1xdz2gsqcdcu4sc87vulf03y9m7jrm2zwwtetqw3maf2g0apvtd4sce3cz0\0"
+        );
+
+        // the price moves linearly from max_output / input to min_output / input
+        let mut price = [0u8; 48];
+        for (step_index, expected) in [(0, &b"1.163356\0"[..]), (99, &b"0.913664\0"[..])] {
+            let err = unsafe {
+                rs_action_dutch_auction_schedule_price(
+                    &dummy_action,
+                    step_index,
+                    price.as_mut_ptr(),
+                    48,
+                )
+            };
+            assert_eq!(err, ParserError::Ok as u32);
+            assert!(price.starts_with(expected));
+        }
+
+        // there is no step past the end of the auction
+        let err = unsafe {
+            rs_action_dutch_auction_schedule_price(&dummy_action, 100, price.as_mut_ptr(), 48)
+        };
+        assert_eq!(err, ParserError::ValueOutOfRange as u32);
+
+        let description = dummy_action.to_action().unwrap().description;
+        assert_eq!(description.step_height(0).unwrap(), 720997797);
+        assert_eq!(description.step_height(99).unwrap(), 720998787);
+
+        let mut zero_input = dummy_action.clone();
+        zero_input.description.input.amount.lo = 0;
+        let mut same_asset = dummy_action.clone();
+        same_asset.description.output_id = IdC {
+            inner: BytesC::from_slice(&asset_id_bytes),
+        };
+        let mut rising_price = dummy_action.clone();
+        rising_price.description.min_output.lo = 867664568342528772;
+        let mut single_step = dummy_action.clone();
+        single_step.description.step_count = 1;
+        let mut empty_range = dummy_action.clone();
+        empty_range.description.end_height = 720997797;
+        for invalid in [
+            zero_input,
+            same_asset,
+            rising_price,
+            single_step,
+            empty_range,
+        ] {
+            assert!(matches!(
+                invalid.effect_hash(),
+                Err(ParserError::InvalidDutchAuction)
+            ));
+        }
+    }
+
     #[test]
     fn test_dutch_auction_withdraw_action_hash() {
         let auction_id_bytes =
@@ -1108,5 +2197,373 @@ mod tests {
         } else {
             panic!("dutch_auction_withdraw_hash is not Ok");
         }
+
+        // The reserves come back while the auction NFT advances one sequence.
+        let balance = dummy_action.balance().unwrap();
+        let mut nfts = balance
+            .imbalances()
+            .filter(|imbalance| imbalance.value.amount.inner == 1);
+        let previous = nfts.next().unwrap();
+        assert_eq!(
+            hex::encode(previous.value.asset_id.to_bytes()),
+            "a9799b5250bc26d752a39cca536c7fcae47c5e3238a534f9d402c1f4d62d9711"
+        );
+        assert_eq!(previous.sign, Sign::Required);
+        let next = nfts.next().unwrap();
+        assert_eq!(
+            hex::encode(next.value.asset_id.to_bytes()),
+            "b9a24b689241acabdfcd7377d50760497708d95748c73d1aaa28d1b755fccd0e"
+        );
+        assert_eq!(next.sign, Sign::Provided);
+        assert!(nfts.next().is_none());
+
+        // neither an opened nor a merely closed auction's NFT is a withdrawal
+        for seq in [0, 1] {
+            let mut unwithdrawn = dummy_action.clone();
+            unwithdrawn.seq = seq;
+            assert!(matches!(
+                unwithdrawn.effect_hash(),
+                Err(ParserError::DutchAuctionWithdrawPlanError)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_dutch_auction_end_action_hash() {
+        let auction_id_bytes =
+            hex::decode("c2ccae788b3e9972476a483dbff593a0739f64e2f45ee623fe72d0999224ce43")
+                .unwrap();
+
+        let dummy_action = action_dutch_auction_end::ActionDutchAuctionEndPlanC {
+            has_auction_id: true,
+            auction_id: IdC {
+                inner: BytesC::from_slice(&auction_id_bytes),
+            },
+        };
+
+        let dutch_auction_end_hash = dummy_action.effect_hash().unwrap();
+        let expected_hash = "e48fffcda3e31e482e32b26c1e5d2e8697f16a3171ad77199c592d1901a710303e2907679b2f57c9d3d678a34a203467e346e7b59593eee6f514280d6ba6413b";
+        assert_eq!(
+            hex::encode(dutch_auction_end_hash.as_array()),
+            expected_hash
+        );
+
+        let nft = AuctionNft::new(dummy_action.to_action().unwrap().auction_id, 1);
+        let mut denom = [0u8; AuctionNft::MAX_DENOM_LEN];
+        let len = nft.denom(&mut denom).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&denom[..len]).unwrap(),
+            "auctionnft_1_pauctid1ctx2u7yt86vhy3m2fq7mlavn5pee7e8z730wvgl7wtgfny3yeepsaph7xe"
+        );
+
+        // Ending burns the opened NFT and mints the closed one.
+        let balance = dummy_action.balance().unwrap();
+        let mut imbalances = balance.imbalances();
+        let opened = imbalances.next().unwrap();
+        assert_eq!(
+            hex::encode(opened.value.asset_id.to_bytes()),
+            "a5c2248045df996bfb3d19138a3501d6e2e1108ce6879175dc12ad2ce5fe8011"
+        );
+        assert_eq!(opened.sign, Sign::Required);
+        let closed = imbalances.next().unwrap();
+        assert_eq!(
+            hex::encode(closed.value.asset_id.to_bytes()),
+            "b312bc0f4174c971225d98d24013c1d7434f121969cf4778c18b2cc877031708"
+        );
+        assert_eq!(closed.sign, Sign::Provided);
+        assert!(imbalances.next().is_none());
+    }
+    #[test]
+    fn test_delegate_action_hash() {
+        let ik = hex::decode("e0a0b0f56054835b6f9ffa0cbb598771b0bfac41a1f4cb1e269afe34367c0cf4")
+            .unwrap();
+
+        let mut dummy_action = delegate::DelegatePlanC {
+            has_validator_identity: true,
+            validator_identity: IdentityKeyC {
+                ik: BytesC::from_slice(&ik),
+            },
+            epoch_index: 1343,
+            has_unbonded_amount: true,
+            unbonded_amount: AmountC {
+                lo: 72318322594591731,
+                hi: 0,
+            },
+            has_delegation_amount: true,
+            delegation_amount: AmountC {
+                lo: 563698478587466379,
+                hi: 0,
+            },
+        };
+
+        let delegate_hash = dummy_action.effect_hash().unwrap();
+        let expected_hash = "1145a175b8a19fa7f7be78a94071b3ed7ca433d508807b9a182f81ddc5a42d19d706723b4c38b0f0de8ea5a6312691169780f80daa4d42ff6a2798a90183eb81";
+        assert_eq!(hex::encode(delegate_hash.as_array()), expected_hash);
+
+        let delegate = dummy_action.to_action().unwrap();
+        let mut denom = [0u8; DelegationToken::MAX_DENOM_LEN];
+        let len = delegate.delegation_token().denom(&mut denom).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&denom[..len]).unwrap(),
+            "udelegation_penumbravalid1uzstpatq2jp4kmullgxtkkv8wxctltzp586vk83xntlrgdnupn6qfhccwl"
+        );
+
+        let mut rate = [0u8; 16];
+        let len = delegate
+            .exchange_rate()
+            .unwrap()
+            .to_decimal_str(PRICE_DISPLAY_PRECISION, &mut rate)
+            .unwrap();
+        assert_eq!(&rate[..len], b"0.128292");
+
+        // Delegating burns staking tokens and mints delegation tokens.
+        let balance = delegate.balance().unwrap();
+        let mut imbalances = balance.imbalances();
+        let staked = imbalances.next().unwrap();
+        assert_eq!(staked.sign, Sign::Required);
+        assert_eq!(
+            staked.value.asset_id.to_bytes(),
+            crate::parser::fee::STAKING_TOKEN_ASSET_ID_BYTES
+        );
+        let minted = imbalances.next().unwrap();
+        assert_eq!(minted.sign, Sign::Provided);
+        assert_eq!(
+            hex::encode(minted.value.asset_id.to_bytes()),
+            "9bb02b18602a0db9e3cbba7043c5a2cb4650b0f1467530e2ea4d0c5f2be56508"
+        );
+
+        // Minting delegation tokens for nothing is never consistent with a rate.
+        dummy_action.unbonded_amount = AmountC { lo: 0, hi: 0 };
+        assert!(matches!(
+            dummy_action.effect_hash(),
+            Err(ParserError::InvalidExchangeRate)
+        ));
+    }
+
+    #[test]
+    fn test_undelegate_action_hash() {
+        let ik = hex::decode("ba12985dc6a9341c672b41aa482405abe9acd5581afc050dd2aa95baafb4111f")
+            .unwrap();
+
+        let mut dummy_action = undelegate::UndelegatePlanC {
+            has_validator_identity: true,
+            validator_identity: IdentityKeyC {
+                ik: BytesC::from_slice(&ik),
+            },
+            has_unbonded_amount: true,
+            unbonded_amount: AmountC {
+                lo: 181076885887298711,
+                hi: 0,
+            },
+            has_delegation_amount: true,
+            delegation_amount: AmountC {
+                lo: 507250884536792780,
+                hi: 0,
+            },
+            has_from_epoch: true,
+            from_epoch: EpochC {
+                index: 5437,
+                start_height: 5437,
+            },
+        };
+
+        let undelegate_hash = dummy_action.effect_hash().unwrap();
+        let expected_hash = "91aca38f77392921cb2b59df482b2e05ff0b6098163e32996d76a77f6ee3b1d873216933acea436296c3fe342910d31dce2d5f331ac79a38074128edf62574c1";
+        assert_eq!(hex::encode(undelegate_hash.as_array()), expected_hash);
+
+        let undelegate = dummy_action.to_action().unwrap();
+        let mut rate = [0u8; 16];
+        let len = undelegate
+            .exchange_rate()
+            .unwrap()
+            .to_decimal_str(PRICE_DISPLAY_PRECISION, &mut rate)
+            .unwrap();
+        assert_eq!(&rate[..len], b"0.356976");
+
+        // Undelegating burns delegation tokens and mints unbonding tokens.
+        let balance = undelegate.balance().unwrap();
+        let mut imbalances = balance.imbalances();
+        let burned = imbalances.next().unwrap();
+        assert_eq!(burned.sign, Sign::Required);
+        assert_eq!(
+            hex::encode(burned.value.asset_id.to_bytes()),
+            "e02f246e36fc58160ef139de7e06487e2b972175654247e895665ed4a45cb30d"
+        );
+        let minted = imbalances.next().unwrap();
+        assert_eq!(minted.sign, Sign::Provided);
+        assert_eq!(
+            hex::encode(minted.value.asset_id.to_bytes()),
+            "39a9e911463e7dd29ff54b85158d56809f1825dce0d06f0b4fdfba92750cee06"
+        );
+
+        dummy_action.delegation_amount = AmountC { lo: 0, hi: 0 };
+        assert!(matches!(
+            dummy_action.effect_hash(),
+            Err(ParserError::InvalidExchangeRate)
+        ));
+    }
+
+    #[test]
+    fn test_check_rate() {
+        let ik = hex::decode("e0a0b0f56054835b6f9ffa0cbb598771b0bfac41a1f4cb1e269afe34367c0cf4")
+            .unwrap();
+        let amount = |lo: u64| AmountC { lo, hi: 0 };
+        let mut rate = RateDataC {
+            validator_identity: ik.clone().try_into().unwrap(),
+            exchange_rate: amount(1_5000_0000),
+        };
+
+        let mut delegate = delegate::DelegatePlanC {
+            has_validator_identity: true,
+            validator_identity: IdentityKeyC {
+                ik: BytesC::from_slice(&ik),
+            },
+            epoch_index: 1343,
+            has_unbonded_amount: true,
+            unbonded_amount: amount(1_000_000),
+            has_delegation_amount: true,
+            delegation_amount: amount(666_666),
+        };
+        let mut undelegate = undelegate::UndelegatePlanC {
+            has_validator_identity: true,
+            validator_identity: IdentityKeyC {
+                ik: BytesC::from_slice(&ik),
+            },
+            has_unbonded_amount: true,
+            unbonded_amount: amount(1_000_000),
+            has_delegation_amount: true,
+            delegation_amount: amount(666_667),
+            has_from_epoch: true,
+            from_epoch: EpochC {
+                index: 5437,
+                start_height: 5437,
+            },
+        };
+        let delegate_check = |plan: &delegate::DelegatePlanC, rate: &RateDataC| unsafe {
+            rs_delegate_check_rate(plan, rate)
+        };
+        let undelegate_check = |plan: &undelegate::UndelegatePlanC, rate: &RateDataC| unsafe {
+            rs_undelegate_check_rate(plan, rate)
+        };
+
+        assert_eq!(delegate_check(&delegate, &rate), ParserError::Ok as u32);
+        assert_eq!(undelegate_check(&undelegate, &rate), ParserError::Ok as u32);
+
+        // A rate that is off by more than rounding is refused.
+        delegate.delegation_amount = amount(700_000);
+        undelegate.unbonded_amount = amount(1_100_000);
+        assert_eq!(
+            delegate_check(&delegate, &rate),
+            ParserError::InvalidExchangeRate as u32
+        );
+        assert_eq!(
+            undelegate_check(&undelegate, &rate),
+            ParserError::InvalidExchangeRate as u32
+        );
+
+        // So is the rate of another validator, or a zero rate.
+        delegate.delegation_amount = amount(666_666);
+        rate.validator_identity[0] ^= 1;
+        assert_eq!(
+            delegate_check(&delegate, &rate),
+            ParserError::InvalidExchangeRate as u32
+        );
+        rate.validator_identity[0] ^= 1;
+        rate.exchange_rate = amount(0);
+        assert_eq!(
+            delegate_check(&delegate, &rate),
+            ParserError::InvalidExchangeRate as u32
+        );
+    }
+
+    #[test]
+    fn test_plan_balance() {
+        let upenumbra =
+            hex::decode("29ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10")
+                .unwrap();
+        let value = |lo: u64| ValueC {
+            has_amount: true,
+            amount: AmountC { lo, hi: 0 },
+            has_asset_id: true,
+            asset_id: IdC {
+                inner: BytesC::from_slice(&upenumbra),
+            },
+        };
+        let address = hex::decode("f72c37238af64e9c8517e4cac09a43a99cee8aa4cb7e2c20419f55dd06f0884bfbfa5202b88852edda3d54273de22c4ef40edb4bc54c0c14fd0b5475d33433d0bd9793c8670795eb822b94c3cbb1a412").unwrap();
+        let output = output::OutputPlanC {
+            value: value(1000),
+            dest_address: AddressC {
+                inner: BytesC::from_slice(&address),
+                alt_bech32m: BytesC::default(),
+            },
+            rseed: BytesC::default(),
+            value_blinding: BytesC::default(),
+        };
+
+        // Stand in for a spend of 1500 that funds the output and the fee.
+        let mut spent = Balance::new();
+        spent
+            .add(&Value::try_from(value(1500)).unwrap(), Sign::Provided)
+            .unwrap();
+        let mut plan_balance = BalanceC::from(&spent);
+
+        let err = unsafe {
+            rs_plan_balance_add(
+                ActionPlan::Output as u8,
+                &output as *const _ as *const c_void,
+                &mut plan_balance,
+            )
+        };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert_eq!(plan_balance.qty, 1);
+
+        let check = |fee: u64| unsafe { rs_plan_balance_check(&plan_balance, &FeeC(value(fee))) };
+        assert_eq!(check(500), ParserError::Ok as u32);
+        assert_eq!(check(400), ParserError::UnbalancedPlan as u32);
+        assert_eq!(check(600), ParserError::UnbalancedPlan as u32);
+
+        let err = unsafe {
+            rs_plan_balance_add(
+                ActionPlan::DelegatorVote as u8,
+                &output as *const _ as *const c_void,
+                &mut plan_balance,
+            )
+        };
+        assert_eq!(err, ParserError::Ok as u32);
+        assert_eq!(
+            unsafe { rs_plan_balance_check(&plan_balance, &FeeC(value(500))) },
+            ParserError::Ok as u32
+        );
+
+        // Closing a position swaps its opened LP NFT for the closed one.
+        let position_close = position_close::PositionClosePlanC {
+            has_position_id: true,
+            position_id: IdC {
+                inner: BytesC::from_slice(&[7u8; 32]),
+            },
+        };
+        let balance = position_close.balance().unwrap();
+        let mut imbalances = balance.imbalances();
+        let opened = imbalances.next().unwrap();
+        let closed = imbalances.next().unwrap();
+        assert_eq!(opened.sign, Sign::Required);
+        assert_eq!(closed.sign, Sign::Provided);
+        assert!(imbalances.next().is_none());
+
+        let mut denom = [0u8; LpNft::MAX_DENOM_LEN];
+        let position_id = PositionId([7u8; 32]);
+        let len = LpNft::new(position_id, PositionState::Withdrawn { sequence: 12 })
+            .denom(&mut denom)
+            .unwrap();
+        assert!(denom[..len].starts_with(b"lpnft_withdrawn_12_plpid1"));
+        assert_eq!(
+            Id::from_base_denom(std::str::from_utf8(&denom[..len]).unwrap())
+                .unwrap()
+                .to_bytes(),
+            LpNft::new(position_id, PositionState::Withdrawn { sequence: 12 })
+                .asset_id()
+                .unwrap()
+                .to_bytes()
+        );
     }
 }
@@ -0,0 +1,93 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::ACTION_DUTCH_AUCTION_END_PERSONALIZED;
+use crate::parser::{
+    auction::{AuctionId, AuctionNft},
+    balance::Balance,
+    effect_hash::{create_personalized_state, EffectHash},
+    id::IdC,
+    value::{Imbalance, Sign},
+};
+use crate::protobuf_h::auction_pb::{
+    penumbra_core_component_auction_v1_ActionDutchAuctionEnd_auction_id_tag, PB_LTYPE_UVARINT,
+};
+use crate::utils::protobuf::encode_and_update_proto_field;
+use crate::ParserError;
+
+pub struct ActionDutchAuctionEnd {
+    pub auction_id: AuctionId,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct ActionDutchAuctionEndPlanC {
+    pub has_auction_id: bool,
+    pub auction_id: IdC,
+}
+
+impl ActionDutchAuctionEndPlanC {
+    pub fn effect_hash(&self) -> Result<EffectHash, ParserError> {
+        let action_dutch_auction_end = self.to_action()?;
+
+        let mut state = create_personalized_state(
+            std::str::from_utf8(ACTION_DUTCH_AUCTION_END_PERSONALIZED)
+                .map_err(|_| ParserError::InvalidUtf8)?,
+        );
+
+        // auction_id
+        let auction_id = action_dutch_auction_end.auction_id.to_proto()?;
+        encode_and_update_proto_field(
+            &mut state,
+            penumbra_core_component_auction_v1_ActionDutchAuctionEnd_auction_id_tag as u64,
+            PB_LTYPE_UVARINT as u64,
+            &auction_id,
+            auction_id.len(),
+        )?;
+
+        Ok(EffectHash(*state.finalize().as_array()))
+    }
+
+    pub fn to_action(&self) -> Result<ActionDutchAuctionEnd, ParserError> {
+        if !self.has_auction_id {
+            return Err(ParserError::DutchAuctionEndPlanError);
+        }
+
+        Ok(ActionDutchAuctionEnd {
+            auction_id: AuctionId::try_from(self.auction_id.clone())?,
+        })
+    }
+
+    /// Ending an auction burns its opened NFT and mints the closed one.
+    pub fn balance(&self) -> Result<Balance, ParserError> {
+        let auction_id = self.to_action()?.auction_id;
+        let opened = AuctionNft::new(auction_id, 0).value()?;
+        let closed = AuctionNft::new(auction_id, 1).value()?;
+
+        let mut balance = Balance::new();
+        balance.insert(Imbalance {
+            value: opened,
+            sign: Sign::Required,
+        })?;
+        balance.insert(Imbalance {
+            value: closed,
+            sign: Sign::Provided,
+        })?;
+
+        Ok(balance)
+    }
+}
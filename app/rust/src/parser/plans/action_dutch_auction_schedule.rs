@@ -0,0 +1,92 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::ACTION_DUTCH_AUCTION_SCHEDULE_PERSONALIZED;
+use crate::parser::{
+    auction::{AuctionNft, DutchAuctionDescription, DutchAuctionDescriptionC},
+    balance::Balance,
+    effect_hash::{create_personalized_state, EffectHash},
+    value::{Imbalance, Sign},
+};
+use crate::protobuf_h::auction_pb::{
+    penumbra_core_component_auction_v1_ActionDutchAuctionSchedule_description_tag, PB_LTYPE_UVARINT,
+};
+use crate::utils::protobuf::encode_and_update_proto_field;
+use crate::ParserError;
+
+pub struct ActionDutchAuctionSchedule {
+    pub description: DutchAuctionDescription,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct ActionDutchAuctionSchedulePlanC {
+    pub has_description: bool,
+    pub description: DutchAuctionDescriptionC,
+}
+
+impl ActionDutchAuctionSchedulePlanC {
+    pub fn effect_hash(&self) -> Result<EffectHash, ParserError> {
+        let action_dutch_auction_schedule = self.to_action()?;
+
+        let mut state = create_personalized_state(
+            std::str::from_utf8(ACTION_DUTCH_AUCTION_SCHEDULE_PERSONALIZED)
+                .map_err(|_| ParserError::InvalidUtf8)?,
+        );
+
+        // description
+        let (description, description_len) =
+            action_dutch_auction_schedule.description.to_proto()?;
+        encode_and_update_proto_field(
+            &mut state,
+            penumbra_core_component_auction_v1_ActionDutchAuctionSchedule_description_tag as u64,
+            PB_LTYPE_UVARINT as u64,
+            &description,
+            description_len,
+        )?;
+
+        Ok(EffectHash(*state.finalize().as_array()))
+    }
+
+    pub fn to_action(&self) -> Result<ActionDutchAuctionSchedule, ParserError> {
+        if !self.has_description {
+            return Err(ParserError::DutchAuctionSchedulePlanError);
+        }
+
+        Ok(ActionDutchAuctionSchedule {
+            description: DutchAuctionDescription::try_from(self.description.clone())?,
+        })
+    }
+
+    /// Scheduling an auction escrows the input and mints the opened auction NFT.
+    pub fn balance(&self) -> Result<Balance, ParserError> {
+        let description = self.to_action()?.description;
+        let opened = AuctionNft::new(description.id()?, 0).value()?;
+
+        let mut balance = Balance::new();
+        balance.insert(Imbalance {
+            value: description.input,
+            sign: Sign::Required,
+        })?;
+        balance.insert(Imbalance {
+            value: opened,
+            sign: Sign::Provided,
+        })?;
+
+        Ok(balance)
+    }
+}
@@ -16,10 +16,11 @@
 
 use crate::constants::ACTION_DUTCH_AUCTION_WITHDRAWAL_PERSONALIZED;
 use crate::parser::{
+    auction::{AuctionId, AuctionNft},
     balance::Balance,
     commitment::Commitment,
     effect_hash::{create_personalized_state, EffectHash},
-    id::{IdC, IdRaw},
+    id::IdC,
     value::{Imbalance, Sign, Value, ValueC},
 };
 use crate::protobuf_h::auction_pb::{
@@ -32,7 +33,7 @@ use crate::ParserError;
 use decaf377::Fr;
 
 pub struct ActionDutchAuctionWithdraw {
-    pub auction_id: IdRaw,
+    pub auction_id: AuctionId,
     pub seq: u64,
     pub reserves_commitment: Commitment,
 }
@@ -93,17 +94,18 @@ impl ActionDutchAuctionWithdrawPlanC {
     }
 
     pub fn to_action(&self) -> Result<ActionDutchAuctionWithdraw, ParserError> {
-        let auction_id: [u8; 32] = self
-            .auction_id
-            .inner
-            .get_bytes()?
-            .try_into()
-            .map_err(|_| ParserError::InvalidLength)?;
-
+        // A withdrawal consumes the NFT of a closed (sequence 1) or already
+        // withdrawn auction, so it mints sequence 2 or later, and must leave
+        // room to mint the next one.
+        if !self.has_auction_id || self.seq < 2 || self.seq == u64::MAX {
+            return Err(ParserError::DutchAuctionWithdrawPlanError);
+        }
+
+        let auction_id = AuctionId::try_from(self.auction_id.clone())?;
         let reserves_commitment = self.reserves_commitment()?;
 
         let action_dutch_auction_withdraw = ActionDutchAuctionWithdraw {
-            auction_id: IdRaw(auction_id),
+            auction_id,
             seq: self.seq,
             reserves_commitment,
         };
@@ -121,7 +123,23 @@ impl ActionDutchAuctionWithdrawPlanC {
         })?;
 
         let reserves_output = Value::try_from(self.reserves_output.clone())?;
-        balance = balance.add(&reserves_output, Sign::Provided)?;
+        balance.add(&reserves_output, Sign::Provided)?;
+
+        Ok(balance)
+    }
+
+    /// The reserves paid out, plus the auction NFT advancing from `seq - 1` to `seq`.
+    pub fn balance(&self) -> Result<Balance, ParserError> {
+        let action = self.to_action()?;
+        let previous = AuctionNft::new(action.auction_id, action.seq - 1).value()?;
+        let next = AuctionNft::new(action.auction_id, action.seq).value()?;
+
+        let mut balance = self.reserves_balance()?;
+        balance.insert(Imbalance {
+            value: previous,
+            sign: Sign::Required,
+        })?;
+        balance.add(&next, Sign::Provided)?;
 
         Ok(balance)
     }
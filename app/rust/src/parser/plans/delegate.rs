@@ -0,0 +1,164 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::DELEGATE_PERSONALIZED;
+use crate::parser::{
+    amount::{Amount, AmountC},
+    balance::Balance,
+    delegation_token::{implied_exchange_rate, DelegationToken},
+    effect_hash::{create_personalized_state, EffectHash},
+    fee::STAKING_TOKEN_ASSET_ID_BYTES,
+    fixpoint::U128x128,
+    id::Id,
+    identity_key::IdentityKeyC,
+    rate_data::RateData,
+    validator_identity::ValidatorIdentity,
+    value::{Imbalance, Sign, Value},
+};
+use crate::protobuf_h::stake_pb::{
+    penumbra_core_component_stake_v1_Delegate_delegation_amount_tag,
+    penumbra_core_component_stake_v1_Delegate_epoch_index_tag,
+    penumbra_core_component_stake_v1_Delegate_unbonded_amount_tag,
+    penumbra_core_component_stake_v1_Delegate_validator_identity_tag, PB_LTYPE_UVARINT,
+};
+use crate::utils::protobuf::{encode_and_update_proto_field, encode_and_update_proto_number};
+use crate::ParserError;
+use decaf377::Fq;
+
+pub struct Delegate {
+    /// The identity key of the validator to delegate to.
+    pub validator_identity: ValidatorIdentity,
+    /// The index of the epoch in which this delegation was performed.
+    pub epoch_index: u64,
+    /// The amount of staking tokens being delegated.
+    pub unbonded_amount: Amount,
+    /// The amount of delegation tokens produced by this action.
+    pub delegation_amount: Amount,
+}
+
+impl Delegate {
+    /// Delegating burns staking tokens and mints the validator's delegation tokens.
+    pub fn balance(&self) -> Result<Balance, ParserError> {
+        let mut balance = Balance::new();
+        balance.insert(Imbalance {
+            value: Value {
+                amount: self.unbonded_amount,
+                asset_id: Id(Fq::from_le_bytes_mod_order(&STAKING_TOKEN_ASSET_ID_BYTES)),
+            },
+            sign: Sign::Required,
+        })?;
+        balance.insert(Imbalance {
+            value: Value {
+                amount: self.delegation_amount,
+                asset_id: self.delegation_token().asset_id()?,
+            },
+            sign: Sign::Provided,
+        })?;
+        Ok(balance)
+    }
+
+    pub fn delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.validator_identity.clone())
+    }
+
+    pub fn exchange_rate(&self) -> Result<U128x128, ParserError> {
+        implied_exchange_rate(self.unbonded_amount, self.delegation_amount)
+    }
+
+    /// Checks the delegation tokens minted against the validator's rate data.
+    pub fn check_rate(&self, rate: &RateData) -> Result<(), ParserError> {
+        rate.check(
+            &self.validator_identity,
+            rate.delegation_amount(self.unbonded_amount)?,
+            self.delegation_amount,
+        )
+    }
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct DelegatePlanC {
+    pub has_validator_identity: bool,
+    pub validator_identity: IdentityKeyC,
+    pub epoch_index: u64,
+    pub has_unbonded_amount: bool,
+    pub unbonded_amount: AmountC,
+    pub has_delegation_amount: bool,
+    pub delegation_amount: AmountC,
+}
+
+impl DelegatePlanC {
+    pub fn effect_hash(&self) -> Result<EffectHash, ParserError> {
+        let delegate = self.to_action()?;
+
+        let mut state = create_personalized_state(
+            std::str::from_utf8(DELEGATE_PERSONALIZED).map_err(|_| ParserError::InvalidUtf8)?,
+        );
+
+        // validator_identity
+        let validator_identity = delegate.validator_identity.to_proto()?;
+        encode_and_update_proto_field(
+            &mut state,
+            penumbra_core_component_stake_v1_Delegate_validator_identity_tag as u64,
+            PB_LTYPE_UVARINT as u64,
+            &validator_identity,
+            validator_identity.len(),
+        )?;
+
+        // epoch_index
+        if delegate.epoch_index != 0 {
+            encode_and_update_proto_number(
+                &mut state,
+                penumbra_core_component_stake_v1_Delegate_epoch_index_tag as u64,
+                delegate.epoch_index,
+            )?;
+        }
+
+        // unbonded_amount
+        state.update(&[
+            ((penumbra_core_component_stake_v1_Delegate_unbonded_amount_tag << 3) | 2) as u8,
+        ]);
+        let (unbonded_amount, unbonded_amount_len) = delegate.unbonded_amount.to_proto()?;
+        state.update(&unbonded_amount[..unbonded_amount_len]);
+
+        // delegation_amount
+        state.update(&[
+            ((penumbra_core_component_stake_v1_Delegate_delegation_amount_tag << 3) | 2) as u8,
+        ]);
+        let (delegation_amount, delegation_amount_len) = delegate.delegation_amount.to_proto()?;
+        state.update(&delegation_amount[..delegation_amount_len]);
+
+        Ok(EffectHash(*state.finalize().as_array()))
+    }
+
+    pub fn to_action(&self) -> Result<Delegate, ParserError> {
+        if !self.has_validator_identity || !self.has_unbonded_amount || !self.has_delegation_amount
+        {
+            return Err(ParserError::DelegatePlanError);
+        }
+
+        let delegate = Delegate {
+            validator_identity: ValidatorIdentity::try_from(self.validator_identity.clone())?,
+            epoch_index: self.epoch_index,
+            unbonded_amount: self.unbonded_amount.clone().try_into()?,
+            delegation_amount: self.delegation_amount.clone().try_into()?,
+        };
+        delegate.exchange_rate()?;
+
+        Ok(delegate)
+    }
+}
@@ -14,11 +14,13 @@
 *  limitations under the License.
 ********************************************************************************/
 
+use crate::address::Address;
 use crate::constants::DELEGATOR_VOTE_PERSONALIZED;
 use crate::keys::FullViewingKey;
 use crate::parser::{
     amount::{Amount, AmountC},
     bytes::BytesC,
+    delegation_token::DelegationToken,
     effect_hash::{create_personalized_state, EffectHash},
     note::{Note, NoteC},
     nullifier::Nullifier,
@@ -35,6 +37,7 @@ use crate::protobuf_h::governance_pb::{
     penumbra_core_component_governance_v1_DelegatorVoteBody_vote_tag,
     penumbra_core_component_governance_v1_Vote_vote_tag, PB_LTYPE_UVARINT,
 };
+
 use crate::utils::protobuf::{
     encode_and_update_proto_field, encode_and_update_proto_number, encode_proto_number,
     encode_varint,
@@ -43,6 +46,11 @@ use crate::ParserError;
 use decaf377::Fr;
 use decaf377_rdsa::{SpendAuth, VerificationKey};
 
+// Values of `penumbra.core.component.governance.v1.Vote.Vote`, zero is unspecified.
+const VOTE_ABSTAIN: u8 = 1;
+const VOTE_YES: u8 = 2;
+const VOTE_NO: u8 = 3;
+
 pub struct Body {
     /// The proposal ID the vote is for.
     pub proposal: u64,
@@ -156,6 +164,44 @@ impl DelegatorVotePlanC {
         Ok(EffectHash(*state.finalize().as_array()))
     }
 
+    /// Checks that the plan casts an actual vote, backed by a delegation token
+    /// note that the signer controls.
+    ///
+    /// `staked_denom` is the base denom of the staked note, as provided in the
+    /// transaction metadata. The note's asset can only be matched against a
+    /// delegation token when it is present.
+    pub fn validate(
+        &self,
+        fvk: &FullViewingKey,
+        staked_denom: Option<&str>,
+    ) -> Result<(), ParserError> {
+        if !self.has_vote || !matches!(self.vote, VOTE_ABSTAIN | VOTE_YES | VOTE_NO) {
+            return Err(ParserError::UnspecifiedVote);
+        }
+
+        let value = Value::try_from(self.staked_note.value.clone())?;
+        if let Some(staked_denom) = staked_denom {
+            let delegation_id = DelegationToken::from_denom(staked_denom)
+                .and_then(|token| token.asset_id())
+                .map_err(|_| ParserError::StakedNoteNotDelegationToken)?;
+            if delegation_id.to_bytes() != value.asset_id.to_bytes() {
+                return Err(ParserError::StakedNoteNotDelegationToken);
+            }
+        }
+
+        let address = Address::try_from(self.staked_note.address.inner.get_bytes()?)?;
+        if fvk.address_index(&address).is_none() {
+            return Err(ParserError::StakedNoteNotOwned);
+        }
+
+        let unbonded_amount = Amount::try_from(self.unbonded_amount.clone())?;
+        if !self.has_unbonded_amount || unbonded_amount.inner == 0 {
+            return Err(ParserError::ZeroUnbondedAmount);
+        }
+
+        Ok(())
+    }
+
     pub fn delegator_vote_body(&self, fvk: &FullViewingKey) -> Result<Body, ParserError> {
         let value = Value::try_from(self.staked_note.value.clone())?;
         let unbonded_amount = Amount::try_from(self.unbonded_amount.clone())?;
@@ -0,0 +1,57 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::parser::{
+    amount::{Amount, AmountC},
+    balance::Balance,
+    bytes::BytesC,
+    id::Id,
+    value::{Sign, Value},
+};
+use crate::ParserError;
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct Ics20WithdrawalPlanC {
+    pub has_amount: bool,
+    pub amount: AmountC,
+    pub has_denom: bool,
+    /// The C side wraps this in a `denom_t`, which holds nothing else.
+    pub denom: BytesC,
+    pub destination_chain_address: BytesC,
+    pub source_channel: BytesC,
+}
+
+impl Ics20WithdrawalPlanC {
+    /// A withdrawal sends its amount out of the chain.
+    pub fn balance(&self) -> Result<Balance, ParserError> {
+        if !self.has_amount || !self.has_denom {
+            return Err(ParserError::Ics20WithdrawalPlanError);
+        }
+
+        let denom =
+            std::str::from_utf8(self.denom.get_bytes()?).map_err(|_| ParserError::InvalidUtf8)?;
+        let value = Value {
+            amount: Amount::try_from(self.amount.clone())?,
+            asset_id: Id::from_base_denom(denom)?,
+        };
+
+        let mut balance = Balance::new();
+        balance.add(&value, Sign::Required)?;
+        Ok(balance)
+    }
+}
@@ -0,0 +1,88 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::POSITION_CLOSE_PERSONALIZED;
+use crate::parser::{
+    balance::Balance,
+    effect_hash::{create_personalized_state, EffectHash},
+    id::IdC,
+    position::{LpNft, PositionId, PositionState},
+    value::Sign,
+};
+use crate::protobuf_h::dex_pb::{
+    penumbra_core_component_dex_v1_PositionClose_position_id_tag, PB_LTYPE_UVARINT,
+};
+use crate::utils::protobuf::encode_and_update_proto_field;
+use crate::ParserError;
+
+pub struct PositionClose {
+    /// The position to close.
+    pub position_id: PositionId,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct PositionClosePlanC {
+    pub has_position_id: bool,
+    pub position_id: IdC,
+}
+
+impl PositionClosePlanC {
+    pub fn effect_hash(&self) -> Result<EffectHash, ParserError> {
+        let position_close = self.position_close()?;
+
+        let mut state = create_personalized_state(
+            std::str::from_utf8(POSITION_CLOSE_PERSONALIZED)
+                .map_err(|_| ParserError::InvalidUtf8)?,
+        );
+
+        // position_id
+        let position_id = position_close.position_id.to_proto()?;
+        encode_and_update_proto_field(
+            &mut state,
+            penumbra_core_component_dex_v1_PositionClose_position_id_tag as u64,
+            PB_LTYPE_UVARINT as u64,
+            &position_id,
+            position_id.len(),
+        )?;
+
+        Ok(EffectHash(*state.finalize().as_array()))
+    }
+
+    pub fn position_close(&self) -> Result<PositionClose, ParserError> {
+        if !self.has_position_id {
+            return Err(ParserError::PositionClosePlanError);
+        }
+
+        Ok(PositionClose {
+            position_id: PositionId::try_from(self.position_id.clone())?,
+        })
+    }
+
+    /// Closing a position burns its opened LP NFT and mints the closed one.
+    pub fn balance(&self) -> Result<Balance, ParserError> {
+        let position_id = self.position_close()?.position_id;
+        let opened = LpNft::new(position_id, PositionState::Opened).value()?;
+        let closed = LpNft::new(position_id, PositionState::Closed).value()?;
+
+        let mut balance = Balance::new();
+        balance.add(&opened, Sign::Required)?;
+        balance.add(&closed, Sign::Provided)?;
+
+        Ok(balance)
+    }
+}
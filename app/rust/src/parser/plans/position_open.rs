@@ -0,0 +1,103 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::POSITION_OPEN_PERSONALIZED;
+use crate::parser::{
+    balance::Balance,
+    bytes::BytesC,
+    effect_hash::{create_personalized_state, EffectHash},
+    position::{LpNft, Position, PositionC, PositionState},
+    value::Sign,
+};
+use crate::protobuf_h::dex_pb::{
+    penumbra_core_component_dex_v1_PositionOpen_position_tag, PB_LTYPE_UVARINT,
+};
+use crate::utils::protobuf::encode_and_update_proto_field;
+use crate::ParserError;
+
+pub struct PositionOpen {
+    /// Contains the data defining the position, sufficient to compute its `PositionId`.
+    pub position: Position,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct PositionOpenPlanC {
+    pub has_position: bool,
+    pub position: PositionC,
+    pub encrypted_metadata: BytesC,
+}
+
+impl PositionOpenPlanC {
+    /// Computes the effect hash of the `PositionOpen` action described by this plan.
+    ///
+    /// Position metadata is not supported: plans carrying `encrypted_metadata`
+    /// are refused in [`Self::position_open`], so the action never hashes it.
+    pub fn effect_hash(&self) -> Result<EffectHash, ParserError> {
+        let position_open = self.position_open()?;
+
+        let mut state = create_personalized_state(
+            std::str::from_utf8(POSITION_OPEN_PERSONALIZED)
+                .map_err(|_| ParserError::InvalidUtf8)?,
+        );
+
+        // position
+        let (position, position_len) = position_open.position.to_proto()?;
+        encode_and_update_proto_field(
+            &mut state,
+            penumbra_core_component_dex_v1_PositionOpen_position_tag as u64,
+            PB_LTYPE_UVARINT as u64,
+            &position,
+            position_len,
+        )?;
+
+        Ok(EffectHash(*state.finalize().as_array()))
+    }
+
+    pub fn position_open(&self) -> Result<PositionOpen, ParserError> {
+        if !self.has_position {
+            return Err(ParserError::PositionOpenPlanError);
+        }
+
+        if self.encrypted_metadata.len != 0 {
+            return Err(ParserError::PositionMetadataUnsupported);
+        }
+
+        // upstream only accepts positions being opened in the opened state
+        let position = Position::try_from(self.position.clone())?;
+        if position.state != PositionState::Opened {
+            return Err(ParserError::PositionOpenPlanError);
+        }
+
+        Ok(PositionOpen { position })
+    }
+
+    /// Opening a position escrows its reserves and mints the opened LP NFT.
+    pub fn balance(&self) -> Result<Balance, ParserError> {
+        let position = self.position_open()?.position;
+        let reserves = position.reserves.balance(&position.phi.pair)?;
+        let opened = LpNft::new(position.id()?, PositionState::Opened).value()?;
+
+        let mut balance = Balance::new();
+        for imbalance in reserves.imbalances() {
+            balance.add(&imbalance.value, Sign::Required)?;
+        }
+        balance.add(&opened, Sign::Provided)?;
+
+        Ok(balance)
+    }
+}
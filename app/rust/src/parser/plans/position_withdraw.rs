@@ -16,9 +16,11 @@
 
 use crate::constants::{MAX_REWARDS, POSITION_WITHDRAWAL_PERSONALIZED};
 use crate::parser::{
+    balance::Balance,
     commitment::Commitment,
     effect_hash::{create_personalized_state, EffectHash},
-    id::{IdC, IdRaw},
+    id::IdC,
+    position::{LpNft, PositionId, PositionState},
     reserves::{Reserves, ReservesC},
     trading_pair::{TradingPair, TradingPairC},
     value::{Sign, Value, ValueC},
@@ -33,8 +35,8 @@ use crate::ParserError;
 use decaf377::Fr;
 
 pub struct PositionWithdraw {
-    /// The identity key of the validator to undelegate from.
-    pub position_id: IdRaw,
+    /// The position to withdraw from.
+    pub position_id: PositionId,
     /// A transparent (zero blinding factor) commitment to the position's final reserves and fees.
     ///
     /// The chain will check this commitment by recomputing it with the on-chain state.
@@ -98,17 +100,12 @@ impl PositionWithdrawPlanC {
     }
 
     pub fn position_withdraw(&self) -> Result<PositionWithdraw, ParserError> {
-        let position_id: [u8; 32] = self
-            .position_id
-            .inner
-            .get_bytes()?
-            .try_into()
-            .map_err(|_| ParserError::InvalidLength)?;
+        let position_id = PositionId::try_from(self.position_id.clone())?;
 
         let reserves_commitment = self.reserves_commitment()?;
 
         let position_withdraw = PositionWithdraw {
-            position_id: IdRaw(position_id),
+            position_id,
             reserves_commitment,
             sequence: self.sequence,
         };
@@ -116,7 +113,8 @@ impl PositionWithdrawPlanC {
         Ok(position_withdraw)
     }
 
-    pub fn reserves_commitment(&self) -> Result<Commitment, ParserError> {
+    /// The final reserves of the position along with its accumulated rewards.
+    pub fn reserves_balance(&self) -> Result<Balance, ParserError> {
         let reserves = Reserves::try_from(self.reserves.clone())?;
         let trading_pair = TradingPair::try_from(self.pair.clone())?;
 
@@ -124,9 +122,33 @@ impl PositionWithdrawPlanC {
 
         for i in 0..self.rewards_qty as usize {
             let value = Value::try_from(self.rewards[i].clone())?;
-            reserves_balance = reserves_balance.add(&value, Sign::Provided)?;
+            reserves_balance.add(&value, Sign::Provided)?;
         }
 
-        reserves_balance.commit(Fr::ZERO)
+        Ok(reserves_balance)
+    }
+
+    /// The reserves paid out, plus the LP NFT advancing to the next withdrawn state.
+    pub fn balance(&self) -> Result<Balance, ParserError> {
+        let position_id = PositionId::try_from(self.position_id.clone())?;
+        let previous = match self.sequence {
+            0 => PositionState::Closed,
+            sequence => PositionState::Withdrawn {
+                sequence: sequence - 1,
+            },
+        };
+        let next = PositionState::Withdrawn {
+            sequence: self.sequence,
+        };
+
+        let mut balance = self.reserves_balance()?;
+        balance.add(&LpNft::new(position_id, previous).value()?, Sign::Required)?;
+        balance.add(&LpNft::new(position_id, next).value()?, Sign::Provided)?;
+
+        Ok(balance)
+    }
+
+    pub fn reserves_commitment(&self) -> Result<Commitment, ParserError> {
+        self.reserves_balance()?.commit(Fr::ZERO)
     }
 }
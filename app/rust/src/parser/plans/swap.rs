@@ -18,6 +18,7 @@ use crate::constants::SWAP_PERSONALIZED;
 use crate::keys::FullViewingKey;
 use crate::parser::{
     amount::Amount,
+    balance::Balance,
     bytes::BytesC,
     commitment::Commitment,
     effect_hash::{create_personalized_state, EffectHash},
@@ -25,7 +26,8 @@ use crate::parser::{
     swap_payload::SwapPayload,
     swap_plaintext::SwapPlaintext,
     swap_plaintext::SwapPlaintextC,
-    trading_pair::TradingPair,
+    trading_pair::{DirectedTradingPair, TradingPair},
+    value::{Imbalance, Sign, Value},
 };
 use crate::protobuf_h::dex_pb::{
     penumbra_core_component_dex_v1_SwapBody_delta_1_i_tag,
@@ -75,9 +77,7 @@ impl SwapPlanC {
         )?;
 
         // encode delta_1_i
-        state.update(&[
-            ((penumbra_core_component_dex_v1_SwapBody_delta_1_i_tag << 3) | 2) as u8,
-        ]);
+        state.update(&[((penumbra_core_component_dex_v1_SwapBody_delta_1_i_tag << 3) | 2) as u8]);
         let (asset_1, len_1) = body.delta_1_i.to_proto()?;
         state.update(&asset_1[..len_1]);
 
@@ -125,6 +125,48 @@ impl SwapPlanC {
         Ok(body)
     }
 
+    /// Orients the trading pair as "sell `start` for `end`", following the
+    /// single nonzero input delta.
+    ///
+    /// A two-sided swap sells both assets of the pair into the same batch and
+    /// has no single direction, so it yields `None`.
+    pub fn direction(&self) -> Result<Option<DirectedTradingPair>, ParserError> {
+        let trading_pair = TradingPair::try_from(self.swap_plaintext.trading_pair.clone())?;
+        let delta_1_i = Amount::try_from(self.swap_plaintext.delta_1_i.clone())?;
+        let delta_2_i = Amount::try_from(self.swap_plaintext.delta_2_i.clone())?;
+
+        match (delta_1_i.inner, delta_2_i.inner) {
+            (0, 0) => Err(ParserError::SwapPlanError),
+            (_, 0) => trading_pair.orient(trading_pair.asset_1()).map(Some),
+            (0, _) => trading_pair.orient(trading_pair.asset_2()).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// The swap escrows both input deltas together with the prepaid claim fee.
+    pub fn balance(&self) -> Result<Balance, ParserError> {
+        let trading_pair = TradingPair::try_from(self.swap_plaintext.trading_pair.clone())?;
+        let delta_1 = Value {
+            amount: Amount::try_from(self.swap_plaintext.delta_1_i.clone())?,
+            asset_id: trading_pair.asset_1().clone(),
+        };
+        let delta_2 = Value {
+            amount: Amount::try_from(self.swap_plaintext.delta_2_i.clone())?,
+            asset_id: trading_pair.asset_2().clone(),
+        };
+        let claim_fee = Fee::try_from(self.swap_plaintext.claim_fee.clone())?;
+
+        let mut balance = Balance::new();
+        balance.insert(Imbalance {
+            value: delta_1,
+            sign: Sign::Required,
+        })?;
+        balance.add(&delta_2, Sign::Required)?;
+        balance.add(&claim_fee.0, Sign::Required)?;
+
+        Ok(balance)
+    }
+
     pub fn fee_commitment(&self) -> Result<Commitment, ParserError> {
         let fee_blinding_fr = self.get_fee_blinding_fr()?;
         let fee = Fee::try_from(self.swap_plaintext.claim_fee.clone())?;
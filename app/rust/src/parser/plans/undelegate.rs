@@ -0,0 +1,173 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::UNDELEGATE_PERSONALIZED;
+use crate::parser::{
+    amount::{Amount, AmountC},
+    balance::Balance,
+    delegation_token::{implied_exchange_rate, DelegationToken},
+    effect_hash::{create_personalized_state, EffectHash},
+    epoch::{Epoch, EpochC},
+    fixpoint::U128x128,
+    identity_key::IdentityKeyC,
+    rate_data::RateData,
+    unbonding_token::UnbondingToken,
+    validator_identity::ValidatorIdentity,
+    value::{Imbalance, Sign, Value},
+};
+use crate::protobuf_h::stake_pb::{
+    penumbra_core_component_stake_v1_Undelegate_delegation_amount_tag,
+    penumbra_core_component_stake_v1_Undelegate_from_epoch_tag,
+    penumbra_core_component_stake_v1_Undelegate_unbonded_amount_tag,
+    penumbra_core_component_stake_v1_Undelegate_validator_identity_tag, PB_LTYPE_UVARINT,
+};
+use crate::utils::protobuf::encode_and_update_proto_field;
+use crate::ParserError;
+
+pub struct Undelegate {
+    /// The identity key of the validator to undelegate from.
+    pub validator_identity: ValidatorIdentity,
+    /// The epoch in which unbonding starts.
+    pub from_epoch: Epoch,
+    /// The amount of unbonding tokens produced by this action.
+    pub unbonded_amount: Amount,
+    /// The amount of delegation tokens consumed by this action.
+    pub delegation_amount: Amount,
+}
+
+impl Undelegate {
+    /// Undelegating burns delegation tokens and mints unbonding tokens.
+    pub fn balance(&self) -> Result<Balance, ParserError> {
+        let mut balance = Balance::new();
+        balance.insert(Imbalance {
+            value: Value {
+                amount: self.delegation_amount,
+                asset_id: self.delegation_token().asset_id()?,
+            },
+            sign: Sign::Required,
+        })?;
+        balance.insert(Imbalance {
+            value: Value {
+                amount: self.unbonded_amount,
+                asset_id: self.unbonding_token().asset_id()?,
+            },
+            sign: Sign::Provided,
+        })?;
+        Ok(balance)
+    }
+
+    pub fn delegation_token(&self) -> DelegationToken {
+        DelegationToken::new(self.validator_identity.clone())
+    }
+
+    pub fn unbonding_token(&self) -> UnbondingToken {
+        UnbondingToken::new(
+            self.validator_identity.clone(),
+            self.from_epoch.start_height,
+        )
+    }
+
+    pub fn exchange_rate(&self) -> Result<U128x128, ParserError> {
+        implied_exchange_rate(self.unbonded_amount, self.delegation_amount)
+    }
+
+    /// Checks the staking tokens released against the validator's rate data.
+    pub fn check_rate(&self, rate: &RateData) -> Result<(), ParserError> {
+        rate.check(
+            &self.validator_identity,
+            rate.unbonded_amount(self.delegation_amount)?,
+            self.unbonded_amount,
+        )
+    }
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct UndelegatePlanC {
+    pub has_validator_identity: bool,
+    pub validator_identity: IdentityKeyC,
+    pub has_unbonded_amount: bool,
+    pub unbonded_amount: AmountC,
+    pub has_delegation_amount: bool,
+    pub delegation_amount: AmountC,
+    pub has_from_epoch: bool,
+    pub from_epoch: EpochC,
+}
+
+impl UndelegatePlanC {
+    pub fn effect_hash(&self) -> Result<EffectHash, ParserError> {
+        let undelegate = self.to_action()?;
+
+        let mut state = create_personalized_state(
+            std::str::from_utf8(UNDELEGATE_PERSONALIZED).map_err(|_| ParserError::InvalidUtf8)?,
+        );
+
+        // validator_identity
+        let validator_identity = undelegate.validator_identity.to_proto()?;
+        encode_and_update_proto_field(
+            &mut state,
+            penumbra_core_component_stake_v1_Undelegate_validator_identity_tag as u64,
+            PB_LTYPE_UVARINT as u64,
+            &validator_identity,
+            validator_identity.len(),
+        )?;
+
+        // unbonded_amount
+        state.update(&[
+            ((penumbra_core_component_stake_v1_Undelegate_unbonded_amount_tag << 3) | 2) as u8,
+        ]);
+        let (unbonded_amount, unbonded_amount_len) = undelegate.unbonded_amount.to_proto()?;
+        state.update(&unbonded_amount[..unbonded_amount_len]);
+
+        // delegation_amount
+        state.update(&[
+            ((penumbra_core_component_stake_v1_Undelegate_delegation_amount_tag << 3) | 2) as u8,
+        ]);
+        let (delegation_amount, delegation_amount_len) = undelegate.delegation_amount.to_proto()?;
+        state.update(&delegation_amount[..delegation_amount_len]);
+
+        // from_epoch
+        let (from_epoch, from_epoch_len) = undelegate.from_epoch.to_proto()?;
+        state.update(&[
+            ((penumbra_core_component_stake_v1_Undelegate_from_epoch_tag << 3) | 2) as u8,
+        ]);
+        state.update(&[from_epoch_len as u8]);
+        state.update(&from_epoch[..from_epoch_len]);
+
+        Ok(EffectHash(*state.finalize().as_array()))
+    }
+
+    pub fn to_action(&self) -> Result<Undelegate, ParserError> {
+        if !self.has_validator_identity
+            || !self.has_unbonded_amount
+            || !self.has_delegation_amount
+            || !self.has_from_epoch
+        {
+            return Err(ParserError::UndelegatePlanError);
+        }
+
+        let undelegate = Undelegate {
+            validator_identity: ValidatorIdentity::try_from(self.validator_identity.clone())?,
+            from_epoch: self.from_epoch.clone().into(),
+            unbonded_amount: self.unbonded_amount.clone().try_into()?,
+            delegation_amount: self.delegation_amount.clone().try_into()?,
+        };
+        undelegate.exchange_rate()?;
+
+        Ok(undelegate)
+    }
+}
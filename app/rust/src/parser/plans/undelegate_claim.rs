@@ -15,17 +15,16 @@
 ********************************************************************************/
 
 use crate::constants::UNDELEGATE_CLAIM_PERSONALIZED;
-use crate::ffi::bech32::bech32_encode;
 use crate::parser::{
-    amount::AmountC,
+    amount::{Amount, AmountC},
     balance::Balance,
     bytes::BytesC,
     commitment::Commitment,
     effect_hash::{create_personalized_state, EffectHash},
-    id::AssetId,
     id::Id,
     identity_key::IdentityKeyC,
     penalty::{Penalty, PenaltyC},
+    unbonding_token::UnbondingToken,
     validator_identity::ValidatorIdentity,
 };
 use crate::protobuf_h::stake_pb::{
@@ -36,8 +35,7 @@ use crate::protobuf_h::stake_pb::{
 };
 use crate::utils::protobuf::{encode_and_update_proto_field, encode_and_update_proto_number};
 use crate::ParserError;
-use decaf377::{Fq, Fr};
-use itoa::Buffer;
+use decaf377::Fr;
 
 pub struct Body {
     /// The identity key of the validator to undelegate from.
@@ -153,38 +151,20 @@ impl UndelegateClaimPlanC {
         penalty.balance_for_claim(self.unbonding_id()?, unbonding_amount)
     }
 
+    /// The amount of staking tokens this claim yields, after the penalty.
+    pub fn claimed_amount(&self) -> Result<Amount, ParserError> {
+        let penalty = Penalty::try_from(self.penalty.clone())?;
+        penalty.apply_to_amount(self.unbonding_amount.clone().try_into()?)
+    }
+
+    /// The amount of staking tokens lost to slashing.
+    pub fn slashed_amount(&self) -> Result<Amount, ParserError> {
+        let penalty = Penalty::try_from(self.penalty.clone())?;
+        penalty.slashed_amount(self.unbonding_amount.clone().try_into()?)
+    }
+
     pub fn unbonding_id(&self) -> Result<Id, ParserError> {
-        let hrp = "penumbravalid";
-        let mut validator_identity_bytes = [0u8; 72];
-        bech32_encode(
-            hrp,
-            self.validator_identity.ik.get_bytes()?,
-            &mut validator_identity_bytes,
-        )
-        .unwrap();
-
-        let mut result = [0u8; 150];
-        let prefix = b"uunbonding_start_at_";
-
-        let mut buffer = Buffer::new();
-        let unbonding_start_height_str = buffer.format(self.unbonding_start_height).as_bytes();
-        let id_len =
-            prefix.len() + unbonding_start_height_str.len() + 1 + validator_identity_bytes.len();
-
-        result[..prefix.len()].copy_from_slice(prefix);
-        result[prefix.len()..prefix.len() + unbonding_start_height_str.len()]
-            .copy_from_slice(unbonding_start_height_str);
-        result[prefix.len() + unbonding_start_height_str.len()] = b'_';
-        result[prefix.len() + unbonding_start_height_str.len() + 1..id_len]
-            .copy_from_slice(&validator_identity_bytes);
-
-        unsafe {
-            let data_slice = std::slice::from_raw_parts(result.as_ptr(), id_len);
-            if let Ok(asset) = AssetId::new(std::str::from_utf8(data_slice).unwrap()) {
-                Ok(Id(Fq::from_le_bytes_mod_order(&asset.to_bytes())))
-            } else {
-                Err(ParserError::InvalidAssetId)
-            }
-        }
+        let validator_identity = ValidatorIdentity::try_from(self.validator_identity.clone())?;
+        UnbondingToken::new(validator_identity, self.unbonding_start_height).asset_id()
     }
 }
@@ -0,0 +1,340 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::{ID_LEN_BYTES, POSITION_NONCE_LEN_BYTES};
+use crate::ffi::bech32::bech32_encode;
+use crate::parser::{
+    amount::Amount,
+    bytes::BytesC,
+    id::{Id, IdC},
+    reserves::{Reserves, ReservesC},
+    trading_function::{TradingFunction, TradingFunctionC},
+    value::Value,
+};
+use crate::protobuf_h::dex_pb::{
+    penumbra_core_component_dex_v1_PositionId_inner_tag,
+    penumbra_core_component_dex_v1_PositionState_sequence_tag,
+    penumbra_core_component_dex_v1_PositionState_state_tag,
+    penumbra_core_component_dex_v1_Position_close_on_fill_tag,
+    penumbra_core_component_dex_v1_Position_nonce_tag,
+    penumbra_core_component_dex_v1_Position_phi_tag,
+    penumbra_core_component_dex_v1_Position_reserves_tag,
+    penumbra_core_component_dex_v1_Position_state_tag,
+};
+use crate::utils::protobuf::{encode_proto_bytes_field, encode_proto_number};
+use crate::ParserError;
+use itoa::Buffer;
+
+/// A hash of a position's trading function, nonce and close-on-fill flag, identifying it over
+/// its whole lifetime.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct PositionId(pub [u8; ID_LEN_BYTES]);
+
+impl PositionId {
+    pub const LEN: usize = ID_LEN_BYTES;
+    pub const PROTO_LEN: usize = Self::LEN + 2;
+    pub const BECH32_PREFIX: &'static str = "plpid";
+    // prefix, separator, 52 data characters and 6 checksum characters
+    pub const BECH32_LEN: usize = 5 + 1 + 52 + 6;
+
+    pub fn to_proto(&self) -> Result<[u8; Self::PROTO_LEN], ParserError> {
+        let mut proto = [0u8; Self::PROTO_LEN];
+
+        let len = encode_proto_bytes_field(
+            penumbra_core_component_dex_v1_PositionId_inner_tag as u64,
+            &self.0,
+            &mut proto,
+        )?;
+
+        if len != Self::PROTO_LEN {
+            return Err(ParserError::InvalidLength);
+        }
+
+        Ok(proto)
+    }
+
+    /// Writes the `plpid1…` bech32m encoding of this ID, returning the number of bytes written.
+    pub fn to_bech32m(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        bech32_encode(Self::BECH32_PREFIX, &self.0, output)
+            .map_err(|_| ParserError::UnexpectedBufferEnd)
+    }
+}
+
+impl TryFrom<IdC> for PositionId {
+    type Error = ParserError;
+
+    fn try_from(value: IdC) -> Result<Self, Self::Error> {
+        let bytes = value
+            .inner
+            .get_bytes()?
+            .try_into()
+            .map_err(|_| ParserError::InvalidLength)?;
+
+        Ok(PositionId(bytes))
+    }
+}
+
+/// The state of a position.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub enum PositionState {
+    Opened,
+    Closed,
+    Withdrawn { sequence: u64 },
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct PositionStateC {
+    pub state: u32,
+    pub sequence: u64,
+}
+
+impl PositionState {
+    // state and sequence
+    pub const PROTO_LEN: usize = 2 + 11;
+
+    pub fn to_proto(&self) -> Result<([u8; Self::PROTO_LEN], usize), ParserError> {
+        let mut proto = [0u8; Self::PROTO_LEN];
+
+        let (state, sequence) = match self {
+            PositionState::Opened => (1, 0),
+            PositionState::Closed => (2, 0),
+            PositionState::Withdrawn { sequence } => (3, *sequence),
+        };
+
+        let mut offset = encode_proto_number(
+            penumbra_core_component_dex_v1_PositionState_state_tag as u64,
+            state,
+            &mut proto,
+        )?;
+        if sequence != 0 {
+            offset += encode_proto_number(
+                penumbra_core_component_dex_v1_PositionState_sequence_tag as u64,
+                sequence,
+                &mut proto[offset..],
+            )?;
+        }
+
+        Ok((proto, offset))
+    }
+}
+
+impl TryFrom<PositionStateC> for PositionState {
+    type Error = ParserError;
+
+    fn try_from(value: PositionStateC) -> Result<Self, Self::Error> {
+        match value.state {
+            1 => Ok(PositionState::Opened),
+            2 => Ok(PositionState::Closed),
+            3 => Ok(PositionState::Withdrawn {
+                sequence: value.sequence,
+            }),
+            _ => Err(ParserError::UnexpectedValue),
+        }
+    }
+}
+
+/// The NFT controlling a position in a given state, as in upstream's `LpNft`.
+#[derive(Clone, Copy)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct LpNft {
+    pub id: PositionId,
+    pub state: PositionState,
+}
+
+impl LpNft {
+    const DENOM_PREFIX: &'static [u8] = b"lpnft_";
+    // prefix, "withdrawn_" with up to 20 sequence digits, separator and the position ID
+    pub const MAX_DENOM_LEN: usize =
+        Self::DENOM_PREFIX.len() + 10 + 20 + 1 + PositionId::BECH32_LEN;
+
+    pub fn new(id: PositionId, state: PositionState) -> Self {
+        LpNft { id, state }
+    }
+
+    /// Writes the denom of this NFT, returning the number of bytes written.
+    pub fn denom(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        let mut buffer = Buffer::new();
+        let (state, sequence): (&[u8], &[u8]) = match self.state {
+            PositionState::Opened => (b"opened", b""),
+            PositionState::Closed => (b"closed", b""),
+            PositionState::Withdrawn { sequence } => {
+                (b"withdrawn_", buffer.format(sequence).as_bytes())
+            }
+        };
+
+        let mut offset = 0;
+        for part in [Self::DENOM_PREFIX, state, sequence, b"_"] {
+            output
+                .get_mut(offset..offset + part.len())
+                .ok_or(ParserError::UnexpectedBufferEnd)?
+                .copy_from_slice(part);
+            offset += part.len();
+        }
+
+        let id_len = self.id.to_bech32m(&mut output[offset..])?;
+        Ok(offset + id_len)
+    }
+
+    pub fn asset_id(&self) -> Result<Id, ParserError> {
+        let mut denom = [0u8; Self::MAX_DENOM_LEN];
+        let len = self.denom(&mut denom)?;
+        let denom = std::str::from_utf8(&denom[..len]).map_err(|_| ParserError::InvalidUtf8)?;
+
+        Id::from_base_denom(denom)
+    }
+
+    /// The single unit of this NFT that a position action mints or burns.
+    pub fn value(&self) -> Result<Value, ParserError> {
+        Ok(Value {
+            amount: Amount { inner: 1 },
+            asset_id: self.asset_id()?,
+        })
+    }
+}
+
+/// A liquidity position: the immutable trading function and nonce, along with
+/// its current state and reserves.
+pub struct Position {
+    pub phi: TradingFunction,
+    /// A random value used to disambiguate positions with the same trading function.
+    pub nonce: [u8; POSITION_NONCE_LEN_BYTES],
+    pub state: PositionState,
+    pub reserves: Reserves,
+    /// Set if the position is a limit order, closed after being filled against.
+    pub close_on_fill: bool,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct PositionC {
+    pub has_phi: bool,
+    pub phi: TradingFunctionC,
+    pub nonce: BytesC,
+    pub has_state: bool,
+    pub state: PositionStateC,
+    pub has_reserves: bool,
+    pub reserves: ReservesC,
+    pub close_on_fill: bool,
+}
+
+impl Position {
+    pub const PROTO_LEN: usize = 3
+        + TradingFunction::PROTO_LEN
+        + 2
+        + POSITION_NONCE_LEN_BYTES
+        + 2
+        + PositionState::PROTO_LEN
+        + 2
+        + Reserves::PROTO_LEN
+        + 2;
+
+    /// Computes the ID of this position, as in upstream's `Position::id()`.
+    ///
+    /// The nonce, the trading function and the close-on-fill flag are hashed,
+    /// but not the state or the reserves, so the ID stays the same through
+    /// every state of the position.
+    pub fn id(&self) -> Result<PositionId, ParserError> {
+        let mut state = blake2b_simd::Params::new()
+            .personal(b"penumbra_lp_id")
+            .to_state();
+
+        state.update(&self.nonce);
+        state.update(&self.phi.pair.asset_1().to_bytes());
+        state.update(&self.phi.pair.asset_2().to_bytes());
+        state.update(&self.phi.component.fee.to_le_bytes());
+        state.update(&self.phi.component.p.to_le_bytes());
+        state.update(&self.phi.component.q.to_le_bytes());
+        state.update(&[self.close_on_fill as u8]);
+
+        let hash = state.finalize();
+        let id = hash.as_bytes()[..PositionId::LEN]
+            .try_into()
+            .map_err(|_| ParserError::InvalidLength)?;
+
+        Ok(PositionId(id))
+    }
+
+    pub fn to_proto(&self) -> Result<([u8; Self::PROTO_LEN], usize), ParserError> {
+        let mut proto = [0u8; Self::PROTO_LEN];
+        let mut offset = 0;
+
+        let (phi, phi_len) = self.phi.to_proto()?;
+        offset += encode_proto_bytes_field(
+            penumbra_core_component_dex_v1_Position_phi_tag as u64,
+            &phi[..phi_len],
+            &mut proto[offset..],
+        )?;
+
+        offset += encode_proto_bytes_field(
+            penumbra_core_component_dex_v1_Position_nonce_tag as u64,
+            &self.nonce,
+            &mut proto[offset..],
+        )?;
+
+        let (state, state_len) = self.state.to_proto()?;
+        offset += encode_proto_bytes_field(
+            penumbra_core_component_dex_v1_Position_state_tag as u64,
+            &state[..state_len],
+            &mut proto[offset..],
+        )?;
+
+        let (reserves, reserves_len) = self.reserves.to_proto()?;
+        offset += encode_proto_bytes_field(
+            penumbra_core_component_dex_v1_Position_reserves_tag as u64,
+            &reserves[..reserves_len],
+            &mut proto[offset..],
+        )?;
+
+        if self.close_on_fill {
+            offset += encode_proto_number(
+                penumbra_core_component_dex_v1_Position_close_on_fill_tag as u64,
+                1,
+                &mut proto[offset..],
+            )?;
+        }
+
+        Ok((proto, offset))
+    }
+}
+
+impl TryFrom<PositionC> for Position {
+    type Error = ParserError;
+
+    fn try_from(value: PositionC) -> Result<Self, Self::Error> {
+        if !value.has_phi || !value.has_state || !value.has_reserves {
+            return Err(ParserError::MissingField);
+        }
+
+        let nonce = value
+            .nonce
+            .get_bytes()?
+            .try_into()
+            .map_err(|_| ParserError::InvalidLength)?;
+
+        Ok(Position {
+            phi: TradingFunction::try_from(value.phi)?,
+            nonce,
+            state: PositionState::try_from(value.state)?,
+            reserves: Reserves::try_from(value.reserves)?,
+            close_on_fill: value.close_on_fill,
+        })
+    }
+}
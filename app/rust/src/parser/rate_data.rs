@@ -0,0 +1,281 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::constants::VALIDATOR_IDENTITY_BYTES;
+use crate::parser::{
+    amount::{Amount, AmountC},
+    fixpoint::U128x128,
+    validator_identity::ValidatorIdentity,
+};
+use crate::protobuf_h::stake_pb::{
+    penumbra_core_keys_v1_IdentityKey_ik_tag, penumbra_core_num_v1_Amount_hi_tag,
+    penumbra_core_num_v1_Amount_lo_tag,
+};
+use crate::utils::protobuf::{fields, set_once, FieldValue};
+use crate::ParserError;
+
+// Field tags as consts so they can be matched on. `RateData` itself is not
+// part of the generated bindings.
+const IDENTITY_KEY_TAG: u32 = 1;
+const EXCHANGE_RATE_TAG: u32 = 5;
+const IK_TAG: u32 = penumbra_core_keys_v1_IdentityKey_ik_tag;
+const AMOUNT_LO_TAG: u32 = penumbra_core_num_v1_Amount_lo_tag;
+const AMOUNT_HI_TAG: u32 = penumbra_core_num_v1_Amount_hi_tag;
+
+/// The parts of a `penumbra.core.component.stake.v1.RateData` the device
+/// relies on: the validator and its exchange rate between delegation tokens
+/// and staking tokens, in fixed point with a scaling factor of 10^8.
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct RateData {
+    pub validator_identity: ValidatorIdentity,
+    pub exchange_rate: Amount,
+}
+
+impl RateData {
+    pub const EXCHANGE_RATE_SCALE: u128 = 1_0000_0000;
+
+    pub fn parse(input: &[u8]) -> Result<Self, ParserError> {
+        let mut identity = None;
+        let mut exchange_rate = None;
+
+        for field in fields(input) {
+            match field? {
+                (IDENTITY_KEY_TAG, FieldValue::Bytes(bytes)) => {
+                    set_once(&mut identity, Self::parse_identity(bytes)?)?
+                }
+                (EXCHANGE_RATE_TAG, FieldValue::Bytes(bytes)) => {
+                    set_once(&mut exchange_rate, Self::parse_amount(bytes)?)?
+                }
+                (IDENTITY_KEY_TAG | EXCHANGE_RATE_TAG, _) => {
+                    return Err(ParserError::InvalidExchangeRate)
+                }
+                _ => {}
+            }
+        }
+
+        let rate = Self {
+            validator_identity: identity.ok_or(ParserError::MissingField)?,
+            exchange_rate: exchange_rate.ok_or(ParserError::MissingField)?,
+        };
+        if rate.exchange_rate.inner == 0 {
+            return Err(ParserError::InvalidExchangeRate);
+        }
+
+        Ok(rate)
+    }
+
+    fn parse_identity(input: &[u8]) -> Result<ValidatorIdentity, ParserError> {
+        let mut ik = None;
+        for field in fields(input) {
+            if let (IK_TAG, value) = field? {
+                let FieldValue::Bytes(bytes) = value else {
+                    return Err(ParserError::InvalidLength);
+                };
+                let bytes = <[u8; VALIDATOR_IDENTITY_BYTES]>::try_from(bytes)
+                    .map_err(|_| ParserError::InvalidLength)?;
+                set_once(&mut ik, bytes)?;
+            }
+        }
+        Ok(ValidatorIdentity(ik.ok_or(ParserError::MissingField)?))
+    }
+
+    fn parse_amount(input: &[u8]) -> Result<Amount, ParserError> {
+        let mut lo = None;
+        let mut hi = None;
+        for field in fields(input) {
+            match field? {
+                (AMOUNT_LO_TAG, FieldValue::Varint(value)) => set_once(&mut lo, value)?,
+                (AMOUNT_HI_TAG, FieldValue::Varint(value)) => set_once(&mut hi, value)?,
+                (AMOUNT_LO_TAG | AMOUNT_HI_TAG, _) => return Err(ParserError::InvalidExchangeRate),
+                _ => {}
+            }
+        }
+        Amount::try_from(AmountC {
+            lo: lo.unwrap_or_default(),
+            hi: hi.unwrap_or_default(),
+        })
+    }
+
+    fn exchange_rate(&self) -> Result<U128x128, ParserError> {
+        U128x128::from(self.exchange_rate) / U128x128::from(Self::EXCHANGE_RATE_SCALE)
+    }
+
+    /// The delegation tokens minted for `unbonded_amount` staking tokens,
+    /// rounded down as the chain does.
+    pub fn delegation_amount(&self, unbonded_amount: Amount) -> Result<Amount, ParserError> {
+        (U128x128::from(unbonded_amount) / self.exchange_rate()?)?
+            .round_down()
+            .try_into()
+    }
+
+    /// The staking tokens released for `delegation_amount` delegation tokens,
+    /// rounded down as the chain does.
+    pub fn unbonded_amount(&self, delegation_amount: Amount) -> Result<Amount, ParserError> {
+        (U128x128::from(delegation_amount) * self.exchange_rate()?)?
+            .round_down()
+            .try_into()
+    }
+
+    /// Checks that a conversion to or from `validator_identity`'s delegation
+    /// tokens pays out `actual`, where the rate data gives `expected`. The
+    /// planner may round the other way, so an error of one unit is accepted.
+    pub fn check(
+        &self,
+        validator_identity: &ValidatorIdentity,
+        expected: Amount,
+        actual: Amount,
+    ) -> Result<(), ParserError> {
+        if &self.validator_identity != validator_identity
+            || expected.inner.abs_diff(actual.inner) > 1
+        {
+            return Err(ParserError::InvalidExchangeRate);
+        }
+        Ok(())
+    }
+}
+
+/// The parsed form of one rate data entry, mirroring `rate_data_t`.
+#[repr(C)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct RateDataC {
+    pub validator_identity: [u8; VALIDATOR_IDENTITY_BYTES],
+    pub exchange_rate: AmountC,
+}
+
+impl RateDataC {
+    pub fn fill(&mut self, rate: &RateData) {
+        self.validator_identity = rate.validator_identity.0;
+        self.exchange_rate = rate.exchange_rate.into();
+    }
+}
+
+impl TryFrom<&RateDataC> for RateData {
+    type Error = ParserError;
+
+    fn try_from(value: &RateDataC) -> Result<Self, Self::Error> {
+        let rate = Self {
+            validator_identity: ValidatorIdentity(value.validator_identity),
+            exchange_rate: value.exchange_rate.clone().try_into()?,
+        };
+        if rate.exchange_rate.inner == 0 {
+            return Err(ParserError::InvalidExchangeRate);
+        }
+        Ok(rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::encode_varint;
+    use crate::utils::protobuf::WIRE_LEN;
+    use std::vec::Vec;
+
+    const IK: [u8; VALIDATOR_IDENTITY_BYTES] = [7; VALIDATOR_IDENTITY_BYTES];
+
+    fn field(tag: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut out = encode_varint(((tag as u64) << 3) | WIRE_LEN);
+        out.extend(encode_varint(bytes.len() as u64));
+        out.extend_from_slice(bytes);
+        out
+    }
+
+    fn rate_data(ik: &[u8], exchange_rate: u128) -> Vec<u8> {
+        let mut out = field(IDENTITY_KEY_TAG, &field(IK_TAG, ik));
+        out.extend(encode_varint(2 << 3));
+        out.extend(encode_varint(1343));
+        let (amount, len) = Amount {
+            inner: exchange_rate,
+        }
+        .to_proto()
+        .unwrap();
+        out.extend(encode_varint(((EXCHANGE_RATE_TAG as u64) << 3) | WIRE_LEN));
+        out.extend_from_slice(&amount[..len]);
+        out
+    }
+
+    fn amount(inner: u128) -> Amount {
+        Amount { inner }
+    }
+
+    #[test]
+    fn test_parse_rate_data() {
+        let rate = RateData::parse(&rate_data(&IK, 1_5000_0000)).unwrap();
+        assert_eq!(rate.validator_identity, ValidatorIdentity(IK));
+        assert_eq!(rate.exchange_rate.inner, 1_5000_0000);
+
+        let rate = RateData::parse(&rate_data(&IK, 1 << 64)).unwrap();
+        assert_eq!(rate.exchange_rate.inner, 1 << 64);
+
+        for (encoded, expected) in [
+            (rate_data(&IK, 0), ParserError::InvalidExchangeRate),
+            (rate_data(&IK[1..], 1_0000_0000), ParserError::InvalidLength),
+            (
+                field(IDENTITY_KEY_TAG, &field(IK_TAG, &IK)),
+                ParserError::MissingField,
+            ),
+        ] {
+            assert_eq!(RateData::parse(&encoded).err(), Some(expected));
+        }
+
+        let mut duplicated = rate_data(&IK, 1_0000_0000);
+        duplicated.extend(field(IDENTITY_KEY_TAG, &field(IK_TAG, &[8; 32])));
+        assert_eq!(
+            RateData::parse(&duplicated).err(),
+            Some(ParserError::DuplicatedField)
+        );
+    }
+
+    #[test]
+    fn test_rate_data_conversions() {
+        let rate = RateData::parse(&rate_data(&IK, 1_5000_0000)).unwrap();
+        assert_eq!(
+            rate.delegation_amount(amount(3_000_000)).unwrap().inner,
+            2_000_000
+        );
+        assert_eq!(
+            rate.delegation_amount(amount(1_000_000)).unwrap().inner,
+            666_666
+        );
+        assert_eq!(
+            rate.unbonded_amount(amount(2_000_000)).unwrap().inner,
+            3_000_000
+        );
+        assert_eq!(
+            rate.unbonded_amount(amount(666_667)).unwrap().inner,
+            1_000_000
+        );
+
+        let identity = ValidatorIdentity(IK);
+        let expected = rate.delegation_amount(amount(1_000_000)).unwrap();
+        for actual in [666_665, 666_666, 666_667] {
+            assert!(rate.check(&identity, expected, amount(actual)).is_ok());
+        }
+        for actual in [0, 666_664, 666_668, 1_000_000] {
+            assert_eq!(
+                rate.check(&identity, expected, amount(actual)).err(),
+                Some(ParserError::InvalidExchangeRate)
+            );
+        }
+
+        // Another validator's rate never vouches for this one.
+        assert_eq!(
+            rate.check(&ValidatorIdentity([8; 32]), expected, expected)
+                .err(),
+            Some(ParserError::InvalidExchangeRate)
+        );
+    }
+}
@@ -21,6 +21,11 @@ use crate::parser::{
     value::{Imbalance, Sign},
     ParserError,
 };
+use crate::protobuf_h::dex_pb::{
+    penumbra_core_component_dex_v1_Reserves_r1_tag, penumbra_core_component_dex_v1_Reserves_r2_tag,
+    PB_LTYPE_UVARINT,
+};
+use crate::utils::protobuf::encode_varint;
 
 pub struct Reserves {
     pub r1: Amount,
@@ -38,6 +43,29 @@ pub struct ReservesC {
 }
 
 impl Reserves {
+    pub const PROTO_LEN: usize = 2 * (1 + Amount::PROTO_LEN);
+
+    pub fn to_proto(&self) -> Result<([u8; Self::PROTO_LEN], usize), ParserError> {
+        let mut proto = [0u8; Self::PROTO_LEN];
+        let mut offset = 0;
+
+        for (tag, amount) in [
+            (penumbra_core_component_dex_v1_Reserves_r1_tag, &self.r1),
+            (penumbra_core_component_dex_v1_Reserves_r2_tag, &self.r2),
+        ] {
+            // `Amount::to_proto` is already length-prefixed, so only the key is needed
+            offset += encode_varint(((tag << 3) | PB_LTYPE_UVARINT) as u64, &mut proto[offset..])?;
+            let (amount_proto, amount_len) = amount.to_proto()?;
+            if offset + amount_len > proto.len() {
+                return Err(ParserError::InvalidLength);
+            }
+            proto[offset..offset + amount_len].copy_from_slice(&amount_proto[..amount_len]);
+            offset += amount_len;
+        }
+
+        Ok((proto, offset))
+    }
+
     /// Augment `self` with type information to get a typed `Balance`.
     pub fn balance(&self, pair: &TradingPair) -> Result<Balance, ParserError> {
         let mut balance = Balance::new();
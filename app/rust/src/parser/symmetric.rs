@@ -14,7 +14,10 @@
 *  limitations under the License.
 ********************************************************************************/
 
-use crate::constants::{NONCE_LEN, NONCE_MEMO, NONCE_MEMO_KEYS, NONCE_NOTE, NONCE_SWAP};
+use crate::constants::{
+    FVK_LEN, FVK_RECIPIENT_FINGERPRINT_LEN, NONCE_LEN, NONCE_MEMO, NONCE_MEMO_KEYS, NONCE_NOTE,
+    NONCE_SWAP,
+};
 use crate::keys::ka;
 use crate::keys::ovk::Ovk;
 use crate::parser::commitment::{Commitment, StateCommitment};
@@ -247,3 +250,64 @@ impl BackreferenceKey {
         Self(*Key::from_slice(key.as_bytes()))
     }
 }
+
+/// Represents a symmetric `ChaCha20Poly1305` key.
+///
+/// Used for encrypting the full viewing key to a public key supplied by the host,
+/// so that only the holder of the matching secret can read it.
+pub struct FvkExportKey(pub Key);
+
+impl FvkExportKey {
+    /// Use Blake2b-256 to derive an export key from the shared secret and both public keys.
+    pub fn derive(
+        shared_secret: &ka::SharedSecret,
+        epk: &ka::Public,
+        host_pk: &ka::Public,
+    ) -> Self {
+        let mut kdf_params = blake2b_simd::Params::new();
+        kdf_params.personal(b"Penumbra_ExptFVK");
+        kdf_params.hash_length(32);
+        let mut kdf = kdf_params.to_state();
+        kdf.update(&shared_secret.0);
+        kdf.update(&epk.0);
+        kdf.update(&host_pk.0);
+
+        let key = kdf.finalize();
+        Self(*Key::from_slice(key.as_bytes()))
+    }
+
+    /// The fingerprint the device shows for `host_pk` before exporting to it, so
+    /// the user can match it against the one their wallet displays.
+    pub fn recipient_fingerprint(host_pk: &ka::Public) -> [u8; FVK_RECIPIENT_FINGERPRINT_LEN] {
+        let hash = blake2b_simd::Params::new()
+            .personal(b"Penumbra_FvkRcpt")
+            .hash_length(FVK_RECIPIENT_FINGERPRINT_LEN)
+            .hash(&host_pk.0);
+
+        let mut fingerprint = [0u8; FVK_RECIPIENT_FINGERPRINT_LEN];
+        fingerprint.copy_from_slice(hash.as_bytes());
+        fingerprint
+    }
+
+    /// Encrypt the full viewing key, the authentication tag is written after it.
+    /// The nonce is sent along with the ciphertext.
+    pub fn encrypt(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        plaintext: &mut [u8],
+    ) -> Result<(), ParserError> {
+        if plaintext.len() < FVK_LEN + 16 {
+            return Err(ParserError::InvalidLength);
+        }
+
+        let cipher = ChaCha20Poly1305::new(&self.0);
+        let nonce = Nonce::<ChaCha20Poly1305>::from_slice(nonce);
+
+        let tag = cipher
+            .encrypt_in_place_detached(nonce, &[], &mut plaintext[..FVK_LEN])
+            .map_err(|_| ParserError::EncryptionError)?;
+        plaintext[FVK_LEN..FVK_LEN + tag.len()].copy_from_slice(&tag);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::parser::{
+    amount::{Amount, AmountC},
+    fixpoint::U128x128,
+    trading_pair::{TradingPair, TradingPairC},
+};
+use crate::protobuf_h::dex_pb::{
+    penumbra_core_component_dex_v1_BareTradingFunction_fee_tag,
+    penumbra_core_component_dex_v1_BareTradingFunction_p_tag,
+    penumbra_core_component_dex_v1_BareTradingFunction_q_tag,
+    penumbra_core_component_dex_v1_TradingFunction_component_tag,
+    penumbra_core_component_dex_v1_TradingFunction_pair_tag, PB_LTYPE_UVARINT,
+};
+use crate::utils::protobuf::{encode_proto_bytes_field, encode_proto_number, encode_varint};
+use crate::ParserError;
+
+/// A trading function's fee (spread) must be at most 50% (5000 bps).
+pub const MAX_FEE_BPS: u32 = 5000;
+
+/// The data describing a trading function `phi(R) = p*R_1 + q*R_2`, with a
+/// fee expressed in basis points.
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct BareTradingFunction {
+    pub fee: u32,
+    /// The valuation for the first asset of the pair, according to canonical ordering.
+    pub p: Amount,
+    /// The valuation for the second asset of the pair, according to canonical ordering.
+    pub q: Amount,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct BareTradingFunctionC {
+    pub fee: u32,
+    pub has_p: bool,
+    pub p: AmountC,
+    pub has_q: bool,
+    pub q: AmountC,
+}
+
+impl BareTradingFunction {
+    // fee, plus p and q as length-prefixed amounts
+    pub const PROTO_LEN: usize = 6 + 2 * (1 + Amount::PROTO_LEN);
+
+    /// Units of asset 2 that one unit of asset 1 is valued at, `p / q`,
+    /// not including the fee.
+    pub fn implied_price_1_to_2(&self) -> Result<U128x128, ParserError> {
+        U128x128::from(self.p) / U128x128::from(self.q)
+    }
+
+    /// Units of asset 1 that one unit of asset 2 is valued at, `q / p`,
+    /// not including the fee.
+    pub fn implied_price_2_to_1(&self) -> Result<U128x128, ParserError> {
+        U128x128::from(self.q) / U128x128::from(self.p)
+    }
+
+    pub fn to_proto(&self) -> Result<([u8; Self::PROTO_LEN], usize), ParserError> {
+        let mut proto = [0u8; Self::PROTO_LEN];
+        let mut offset = 0;
+
+        if self.fee != 0 {
+            offset += encode_proto_number(
+                penumbra_core_component_dex_v1_BareTradingFunction_fee_tag as u64,
+                self.fee as u64,
+                &mut proto[offset..],
+            )?;
+        }
+
+        for (tag, amount) in [
+            (
+                penumbra_core_component_dex_v1_BareTradingFunction_p_tag,
+                &self.p,
+            ),
+            (
+                penumbra_core_component_dex_v1_BareTradingFunction_q_tag,
+                &self.q,
+            ),
+        ] {
+            // `Amount::to_proto` is already length-prefixed, so only the key is needed
+            offset += encode_varint(((tag << 3) | PB_LTYPE_UVARINT) as u64, &mut proto[offset..])?;
+            let (amount_proto, amount_len) = amount.to_proto()?;
+            if offset + amount_len > proto.len() {
+                return Err(ParserError::InvalidLength);
+            }
+            proto[offset..offset + amount_len].copy_from_slice(&amount_proto[..amount_len]);
+            offset += amount_len;
+        }
+
+        Ok((proto, offset))
+    }
+}
+
+impl TryFrom<BareTradingFunctionC> for BareTradingFunction {
+    type Error = ParserError;
+
+    fn try_from(value: BareTradingFunctionC) -> Result<Self, Self::Error> {
+        let p = Amount::try_from(value.p)?;
+        let q = Amount::try_from(value.q)?;
+
+        if value.fee > MAX_FEE_BPS || p.inner == 0 || q.inner == 0 {
+            return Err(ParserError::ValueOutOfRange);
+        }
+
+        Ok(BareTradingFunction {
+            fee: value.fee,
+            p,
+            q,
+        })
+    }
+}
+
+/// A trading function bound to a specific trading pair.
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct TradingFunction {
+    pub component: BareTradingFunction,
+    pub pair: TradingPair,
+}
+
+#[repr(C)]
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct TradingFunctionC {
+    pub has_component: bool,
+    pub component: BareTradingFunctionC,
+    pub has_pair: bool,
+    pub pair: TradingPairC,
+}
+
+impl TradingFunction {
+    pub const PROTO_LEN: usize = 2 + BareTradingFunction::PROTO_LEN + 2 + TradingPair::PROTO_LEN;
+
+    pub fn to_proto(&self) -> Result<([u8; Self::PROTO_LEN], usize), ParserError> {
+        let mut proto = [0u8; Self::PROTO_LEN];
+        let mut offset = 0;
+
+        let (component, component_len) = self.component.to_proto()?;
+        offset += encode_proto_bytes_field(
+            penumbra_core_component_dex_v1_TradingFunction_component_tag as u64,
+            &component[..component_len],
+            &mut proto[offset..],
+        )?;
+
+        let pair = self.pair.to_proto()?;
+        offset += encode_proto_bytes_field(
+            penumbra_core_component_dex_v1_TradingFunction_pair_tag as u64,
+            &pair,
+            &mut proto[offset..],
+        )?;
+
+        Ok((proto, offset))
+    }
+}
+
+impl TryFrom<TradingFunctionC> for TradingFunction {
+    type Error = ParserError;
+
+    fn try_from(value: TradingFunctionC) -> Result<Self, Self::Error> {
+        if !value.has_component || !value.has_pair {
+            return Err(ParserError::MissingField);
+        }
+
+        Ok(TradingFunction {
+            component: BareTradingFunction::try_from(value.component)?,
+            pair: TradingPair::try_from(value.pair)?,
+        })
+    }
+}
@@ -51,16 +51,43 @@ impl TryFrom<TradingPairC> for TradingPair {
             inner: value.asset_2,
         };
 
-        Ok(Self {
-            asset_1: Id::try_from(id_1)?,
-            asset_2: Id::try_from(id_2)?,
-        })
+        Self::new(Id::try_from(id_1)?, Id::try_from(id_2)?)
     }
 }
 
 impl TradingPair {
     pub const PROTO_LEN: usize = 2 * Id::LEN + 8;
 
+    /// Builds a trading pair from assets already in canonical order.
+    ///
+    /// Unlike upstream, which silently reorders the assets, a pair that is
+    /// not strictly ordered (`asset_1 < asset_2`) is rejected: the plan would
+    /// otherwise describe a different pair than the one being confirmed.
+    pub fn new(asset_1: Id, asset_2: Id) -> Result<Self, ParserError> {
+        if asset_1.0 >= asset_2.0 {
+            return Err(ParserError::InvalidTradingPair);
+        }
+
+        Ok(Self { asset_1, asset_2 })
+    }
+
+    /// Orients the pair so that `start` is the asset being sold.
+    pub fn orient(&self, start: &Id) -> Result<DirectedTradingPair, ParserError> {
+        if *start == self.asset_1 {
+            Ok(DirectedTradingPair {
+                start: self.asset_1.clone(),
+                end: self.asset_2.clone(),
+            })
+        } else if *start == self.asset_2 {
+            Ok(DirectedTradingPair {
+                start: self.asset_2.clone(),
+                end: self.asset_1.clone(),
+            })
+        } else {
+            Err(ParserError::InvalidTradingPair)
+        }
+    }
+
     pub fn asset_1(&self) -> &Id {
         &self.asset_1
     }
@@ -111,3 +138,28 @@ impl TradingPair {
         Ok(proto)
     }
 }
+
+/// A trading pair oriented for display: sell `start` for `end`.
+#[derive(Clone)]
+#[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
+pub struct DirectedTradingPair {
+    pub start: Id,
+    pub end: Id,
+}
+
+impl DirectedTradingPair {
+    pub fn flip(&self) -> Self {
+        Self {
+            start: self.end.clone(),
+            end: self.start.clone(),
+        }
+    }
+
+    pub fn to_canonical(&self) -> Result<TradingPair, ParserError> {
+        if self.start.0 < self.end.0 {
+            TradingPair::new(self.start.clone(), self.end.clone())
+        } else {
+            TradingPair::new(self.end.clone(), self.start.clone())
+        }
+    }
+}
@@ -0,0 +1,117 @@
+/*******************************************************************************
+*   (c) 2024 Zondax GmbH
+*
+*  Licensed under the Apache License, Version 2.0 (the "License");
+*  you may not use this file except in compliance with the License.
+*  You may obtain a copy of the License at
+*
+*      http://www.apache.org/licenses/LICENSE-2.0
+*
+*  Unless required by applicable law or agreed to in writing, software
+*  distributed under the License is distributed on an "AS IS" BASIS,
+*  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+*  See the License for the specific language governing permissions and
+*  limitations under the License.
+********************************************************************************/
+
+use crate::parser::{id::Id, validator_identity::ValidatorIdentity};
+use crate::ParserError;
+use itoa::Buffer;
+
+/// Stake that is unbonding from a validator, with denom
+/// `uunbonding_start_at_<height>_<penumbravalid1…>`.
+pub struct UnbondingToken {
+    pub validator_identity: ValidatorIdentity,
+    pub unbonding_start_height: u64,
+}
+
+impl UnbondingToken {
+    pub const DENOM_PREFIX: &'static str = "uunbonding_start_at_";
+    // prefix, up to 20 height digits, separator and the validator identity
+    pub const MAX_DENOM_LEN: usize =
+        Self::DENOM_PREFIX.len() + 20 + 1 + ValidatorIdentity::BECH32_LEN;
+
+    pub fn new(validator_identity: ValidatorIdentity, unbonding_start_height: u64) -> Self {
+        UnbondingToken {
+            validator_identity,
+            unbonding_start_height,
+        }
+    }
+
+    /// Writes the base denom of this token, returning the number of bytes written.
+    pub fn denom(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        let mut buffer = Buffer::new();
+        let height = buffer.format(self.unbonding_start_height).as_bytes();
+
+        let identity_start = Self::DENOM_PREFIX.len() + height.len() + 1;
+        if output.len() < identity_start {
+            return Err(ParserError::UnexpectedBufferEnd);
+        }
+
+        output[..Self::DENOM_PREFIX.len()].copy_from_slice(Self::DENOM_PREFIX.as_bytes());
+        output[Self::DENOM_PREFIX.len()..identity_start - 1].copy_from_slice(height);
+        output[identity_start - 1] = b'_';
+
+        let identity_len = self
+            .validator_identity
+            .to_bech32m(&mut output[identity_start..])?;
+        Ok(identity_start + identity_len)
+    }
+
+    /// Parses a `uunbonding_start_at_<height>_<penumbravalid1…>` base denom.
+    pub fn from_denom(denom: &str) -> Result<Self, ParserError> {
+        let rest = denom
+            .strip_prefix(Self::DENOM_PREFIX)
+            .ok_or(ParserError::InvalidMetadata)?;
+        let (height, identity) = rest.split_once('_').ok_or(ParserError::InvalidMetadata)?;
+
+        // reject signs and leading zeros, so each token has a single denom
+        let canonical = !height.is_empty()
+            && height.bytes().all(|b| b.is_ascii_digit())
+            && (height == "0" || !height.starts_with('0'));
+        if !canonical {
+            return Err(ParserError::InvalidMetadata);
+        }
+        let height = height
+            .parse::<u64>()
+            .map_err(|_| ParserError::InvalidMetadata)?;
+
+        Ok(Self::new(
+            ValidatorIdentity::from_bech32m(identity)?,
+            height,
+        ))
+    }
+
+    /// Writes "unbonding from <validator> since height N", returning the number
+    /// of bytes written.
+    pub fn render(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        const LABEL: &[u8] = b"unbonding from ";
+        const SINCE: &[u8] = b" since height ";
+        if output.len() < LABEL.len() {
+            return Err(ParserError::UnexpectedBufferEnd);
+        }
+
+        output[..LABEL.len()].copy_from_slice(LABEL);
+        let mut written = LABEL.len();
+        written += self.validator_identity.to_bech32m(&mut output[written..])?;
+
+        let mut buffer = Buffer::new();
+        let height = buffer.format(self.unbonding_start_height).as_bytes();
+        let end = written + SINCE.len() + height.len();
+        if output.len() < end {
+            return Err(ParserError::UnexpectedBufferEnd);
+        }
+        output[written..written + SINCE.len()].copy_from_slice(SINCE);
+        output[written + SINCE.len()..end].copy_from_slice(height);
+
+        Ok(end)
+    }
+
+    pub fn asset_id(&self) -> Result<Id, ParserError> {
+        let mut denom = [0u8; Self::MAX_DENOM_LEN];
+        let len = self.denom(&mut denom)?;
+        let denom = std::str::from_utf8(&denom[..len]).map_err(|_| ParserError::InvalidUtf8)?;
+
+        Id::from_base_denom(denom)
+    }
+}
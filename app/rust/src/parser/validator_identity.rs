@@ -15,15 +15,22 @@
 ********************************************************************************/
 
 use crate::constants::VALIDATOR_IDENTITY_BYTES;
+use crate::ffi::bech32::bech32_encode;
+use crate::parser::identity_key::IdentityKeyC;
 use crate::protobuf_h::keys_pb::{penumbra_core_keys_v1_IdentityKey_ik_tag, PB_LTYPE_UVARINT};
 use crate::utils::protobuf::encode_proto_field;
 use crate::ParserError;
+use bech32::{primitives::decode::CheckedHrpstring, Bech32m, Hrp};
+
 #[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(any(feature = "derive-debug", test), derive(Debug))]
 pub struct ValidatorIdentity(pub [u8; VALIDATOR_IDENTITY_BYTES]);
 
 impl ValidatorIdentity {
     pub const PROTO_LEN: usize = VALIDATOR_IDENTITY_BYTES + 2;
+    pub const BECH32_PREFIX: &'static str = "penumbravalid";
+    // prefix, separator, 52 data characters and 6 checksum characters
+    pub const BECH32_LEN: usize = 13 + 1 + 52 + 6;
 
     pub fn to_proto(&self) -> Result<[u8; Self::PROTO_LEN], ParserError> {
         let mut proto = [0u8; Self::PROTO_LEN];
@@ -43,4 +50,58 @@ impl ValidatorIdentity {
         proto[len..].copy_from_slice(&bytes);
         Ok(proto)
     }
+
+    /// Writes the `penumbravalid1…` bech32m encoding of this identity, returning the number of bytes written.
+    pub fn to_bech32m(&self, output: &mut [u8]) -> Result<usize, ParserError> {
+        bech32_encode(Self::BECH32_PREFIX, &self.0, output)
+            .map_err(|_| ParserError::UnexpectedBufferEnd)
+    }
+
+    /// Parses a `penumbravalid1…` bech32m string, which must be in its canonical lowercase form.
+    pub fn from_bech32m(s: &str) -> Result<Self, ParserError> {
+        let checked =
+            CheckedHrpstring::new::<Bech32m>(s).map_err(|_| ParserError::InvalidAddress)?;
+        let prefix = Hrp::parse_unchecked(Self::BECH32_PREFIX);
+        if checked.hrp() != prefix {
+            return Err(ParserError::InvalidAddress);
+        }
+
+        let mut bytes = [0u8; VALIDATOR_IDENTITY_BYTES];
+        let mut len = 0;
+        for byte in checked.byte_iter() {
+            if len == bytes.len() {
+                return Err(ParserError::InvalidLength);
+            }
+            bytes[len] = byte;
+            len += 1;
+        }
+        if len != bytes.len() {
+            return Err(ParserError::InvalidLength);
+        }
+
+        // bech32m also decodes the all-uppercase form, but only the canonical
+        // lowercase string names the validator.
+        let identity = ValidatorIdentity(bytes);
+        let mut canonical = [0u8; Self::BECH32_LEN];
+        let canonical_len = identity.to_bech32m(&mut canonical)?;
+        if &canonical[..canonical_len] != s.as_bytes() {
+            return Err(ParserError::InvalidAddress);
+        }
+
+        Ok(identity)
+    }
+}
+
+impl TryFrom<IdentityKeyC> for ValidatorIdentity {
+    type Error = ParserError;
+
+    fn try_from(value: IdentityKeyC) -> Result<Self, Self::Error> {
+        let bytes = value
+            .ik
+            .get_bytes()?
+            .try_into()
+            .map_err(|_| ParserError::InvalidLength)?;
+
+        Ok(ValidatorIdentity(bytes))
+    }
 }
@@ -14,6 +14,8 @@
 *  limitations under the License.
 ********************************************************************************/
 
+use crate::protobuf_h::num_pb::PB_LTYPE_UVARINT;
+use crate::utils::varint;
 use crate::ParserError;
 
 pub fn encode_varint(mut value: u64, buf: &mut [u8]) -> Result<usize, ParserError> {
@@ -113,3 +115,89 @@ pub fn encode_and_update_proto_number(
     state.update(&proto_buf[..len]);
     Ok(())
 }
+
+pub fn encode_proto_bytes_field(
+    tag: u64,
+    input: &[u8],
+    output: &mut [u8],
+) -> Result<usize, ParserError> {
+    let len = encode_proto_field(tag, PB_LTYPE_UVARINT as u64, input.len(), output)?;
+    if len + input.len() > output.len() {
+        return Err(ParserError::InvalidLength);
+    }
+
+    output[len..len + input.len()].copy_from_slice(input);
+    Ok(len + input.len())
+}
+
+pub(crate) const WIRE_VARINT: u64 = 0;
+pub(crate) const WIRE_FIXED64: u64 = 1;
+pub(crate) const WIRE_LEN: u64 = 2;
+pub(crate) const WIRE_FIXED32: u64 = 5;
+
+pub(crate) enum FieldValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed,
+}
+
+/// Iterates over the top level fields of an encoded protobuf message.
+pub(crate) struct Fields<'a> {
+    input: &'a [u8],
+}
+
+impl<'a> Fields<'a> {
+    fn read(&mut self) -> Result<(u32, FieldValue<'a>), ParserError> {
+        let (rest, key) = varint(self.input)?;
+        let tag = u32::try_from(key >> 3).map_err(|_| ParserError::ValueOutOfRange)?;
+
+        let (rest, value) = match key & 0x07 {
+            WIRE_VARINT => {
+                let (rest, value) = varint(rest)?;
+                (rest, FieldValue::Varint(value))
+            }
+            WIRE_LEN => {
+                let (rest, len) = varint(rest)?;
+                let len = usize::try_from(len).map_err(|_| ParserError::ValueOutOfRange)?;
+                if rest.len() < len {
+                    return Err(ParserError::UnexpectedBufferEnd);
+                }
+                let (bytes, rest) = rest.split_at(len);
+                (rest, FieldValue::Bytes(bytes))
+            }
+            WIRE_FIXED64 if rest.len() >= 8 => (&rest[8..], FieldValue::Fixed),
+            WIRE_FIXED32 if rest.len() >= 4 => (&rest[4..], FieldValue::Fixed),
+            _ => return Err(ParserError::UnexpectedType),
+        };
+
+        self.input = rest;
+        Ok((tag, value))
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = Result<(u32, FieldValue<'a>), ParserError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.input.is_empty() {
+            return None;
+        }
+        let field = self.read();
+        if field.is_err() {
+            self.input = &[];
+        }
+        Some(field)
+    }
+}
+
+pub(crate) fn fields(input: &[u8]) -> Fields<'_> {
+    Fields { input }
+}
+
+/// Sets a singular field, rejecting a second occurrence.
+pub(crate) fn set_once<T>(slot: &mut Option<T>, value: T) -> Result<(), ParserError> {
+    match slot.replace(value) {
+        Some(_) => Err(ParserError::DuplicatedField),
+        None => Ok(()),
+    }
+}
@@ -24,8 +24,13 @@
 #include "addr.h"
 #include "app_main.h"
 #include "coin.h"
+#include "confirm_address.h"
 #include "crypto.h"
+#include "frost_sign.h"
+#include "fvk.h"
+#include "ownership_proof.h"
 #include "parser_common.h"
+#include "sign_message.h"
 #include "tx.h"
 #include "view.h"
 #include "zxformat.h"
@@ -115,7 +120,18 @@ __Z_INLINE void handleGetAddr(volatile uint32_t *flags, volatile uint32_t *tx, u
     address_index_t address_index = {0};
     extractAddressIndex(rx, OFFSET_DATA + sizeof(uint32_t) * HDPATH_LEN_DEFAULT, &address_index);
 
-    zxerr_t zxerr = app_fill_address(address_index);
+    zxerr_t zxerr = zxerr_unknown;
+    switch (G_io_apdu_buffer[OFFSET_P2]) {
+        case P2_ADDR_HOST_RANDOMIZER:
+            zxerr = app_fill_address(address_index);
+            break;
+        case P2_ADDR_DEVICE_RANDOMIZER:
+            // whatever randomizer the host sent is ignored
+            zxerr = app_fill_ephemeral_address(address_index.account);
+            break;
+        default:
+            THROW(APDU_CODE_INVALIDP1P2);
+    }
 
     if (zxerr != zxerr_ok) {
         *tx = 0;
@@ -133,14 +149,96 @@ __Z_INLINE void handleGetAddr(volatile uint32_t *flags, volatile uint32_t *tx, u
     THROW(APDU_CODE_OK);
 }
 
-__Z_INLINE void handleGetFVK(volatile uint32_t *tx, uint32_t rx) {
+__Z_INLINE void handleConfirmAddress(volatile uint32_t *flags, volatile uint32_t *tx, uint32_t rx) {
+    zemu_log("handleConfirmAddress\n");
+
+    extractHDPath(rx, OFFSET_DATA);
+
+    // the ConfirmAddressRequest follows the HD path
+    const uint32_t offset = OFFSET_DATA + sizeof(uint32_t) * HDPATH_LEN_DEFAULT;
+    if (rx <= offset || (rx - offset) > CONFIRM_ADDRESS_REQUEST_MAX_LEN) {
+        THROW(APDU_CODE_WRONG_LENGTH);
+    }
+
+    // the response is written over the apdu buffer
+    uint8_t request[CONFIRM_ADDRESS_REQUEST_MAX_LEN] = {0};
+    const uint16_t request_len = (uint16_t)(rx - offset);
+    MEMCPY(request, G_io_apdu_buffer + offset, request_len);
+
+    app_fill_confirm_address(request, request_len);
+
+    // the address is only handed back once the user has seen it
+    view_review_init(confirm_address_getItem, confirm_address_getNumItems, app_reply_address);
+    view_review_show(REVIEW_ADDRESS);
+    *flags |= IO_ASYNCH_REPLY;
+}
+
+__Z_INLINE void handleProveAddressOwnership(volatile uint32_t *flags, volatile uint32_t *tx, uint32_t rx) {
+    zemu_log("handleProveAddressOwnership\n");
+
+    extractHDPath(rx, OFFSET_DATA);
+
+    // the account + randomizer data and then the challenge follow the HD path
+    address_index_t address_index = {0};
+    uint32_t offset = OFFSET_DATA + sizeof(uint32_t) * HDPATH_LEN_DEFAULT;
+    extractAddressIndex(rx, offset, &address_index);
+
+    offset += sizeof(address_index_t);
+    if (rx <= offset || (rx - offset) > OWNERSHIP_CHALLENGE_MAX_LEN) {
+        THROW(APDU_CODE_WRONG_LENGTH);
+    }
+
+    // the proof is written over the apdu buffer
+    uint8_t challenge[OWNERSHIP_CHALLENGE_MAX_LEN] = {0};
+    const uint16_t challenge_len = (uint16_t)(rx - offset);
+    MEMCPY(challenge, G_io_apdu_buffer + offset, challenge_len);
+
+    app_fill_ownership_proof(address_index, challenge, challenge_len);
+
+    // the proof is only handed back once the user has approved it
+    view_review_init(ownership_proof_getItem, ownership_proof_getNumItems, app_reply_address);
+    view_review_show(REVIEW_ADDRESS);
+    *flags |= IO_ASYNCH_REPLY;
+}
+
+__Z_INLINE void handleGetFVK(volatile uint32_t *flags, volatile uint32_t *tx, uint32_t rx) {
     zemu_log("handleGetFVK\n");
 
     extractHDPath(rx, OFFSET_DATA);
 
-    zxerr_t zxerr = app_fill_keys();
-    *tx = cmdResponseLen;
+    zxerr_t zxerr = zxerr_unknown;
+    switch (G_io_apdu_buffer[OFFSET_P2]) {
+        case P2_FVK_PLAIN:
+            zxerr = app_fill_keys();
+            break;
+        case P2_FVK_PROTO:
+            zxerr = app_fill_proto_keys();
+            break;
+        case P2_FVK_ENCRYPTED: {
+            // the host public key follows the HD path and the account index
+            const uint32_t offset = OFFSET_DATA + sizeof(uint32_t) * HDPATH_LEN_DEFAULT + sizeof(address_index_t);
+            if (rx < offset || (rx - offset) < KEY_LEN) {
+                THROW(APDU_CODE_WRONG_LENGTH);
+            }
+            uint8_t host_pk[KEY_LEN] = {0};
+            MEMCPY(host_pk, G_io_apdu_buffer + offset, KEY_LEN);
+
+            if (app_fill_encrypted_keys(host_pk) != zxerr_ok || fvk_review_init(host_pk) != zxerr_ok) {
+                *tx = 0;
+                THROW(APDU_CODE_DATA_INVALID);
+            }
 
+            // the key only leaves the device once the user approves who receives it
+            view_review_init(fvk_getItem, fvk_getNumItems, app_reply_fvk);
+            view_review_show(REVIEW_GENERIC);
+            *flags |= IO_ASYNCH_REPLY;
+            return;
+        }
+        default:
+            THROW(APDU_CODE_INVALIDP1P2);
+    }
+
+    *tx = cmdResponseLen;
     if (zxerr != zxerr_ok) {
         *tx = 0;
         THROW(APDU_CODE_DATA_INVALID);
@@ -156,7 +254,12 @@ __Z_INLINE void handleTxMetadata(volatile uint32_t *tx, uint32_t rx) {
         THROW(APDU_CODE_OK);
     }
 
-    __Z_UNUSED const char *error_msg = tx_parse_metadata();
+    const uint8_t format = G_io_apdu_buffer[OFFSET_P2];
+    if (format != P2_METADATA_DENOMS && format != P2_METADATA_PROTO && format != P2_METADATA_RATE_DATA) {
+        THROW(APDU_CODE_INVALIDP1P2);
+    }
+
+    __Z_UNUSED const char *error_msg = tx_parse_metadata(format);
     CHECK_APP_CANARY()
     if (error_msg != NULL) {
         const int error_msg_length = strnlen(error_msg, sizeof(G_io_apdu_buffer));
@@ -176,7 +279,18 @@ __Z_INLINE void handleSign(volatile uint32_t *flags, volatile uint32_t *tx, uint
     }
 
     view_spinner_show("Processing...");
-    __Z_UNUSED const char *error_msg = tx_parse();
+    const char *error_msg = NULL;
+    sign_binding_signature = (G_io_apdu_buffer[OFFSET_P2] & P2_SIGN_BINDING_SIGNATURE) != 0;
+    switch (G_io_apdu_buffer[OFFSET_P2] & P2_SIGN_PAYLOAD_MASK) {
+        case P2_SIGN_PLAN:
+            error_msg = tx_parse();
+            break;
+        case P2_SIGN_AUTHORIZE_REQUEST:
+            error_msg = tx_parse_authorize_request();
+            break;
+        default:
+            THROW(APDU_CODE_INVALIDP1P2);
+    }
     CHECK_APP_CANARY()
     if (error_msg != NULL) {
         const int error_msg_length = strnlen(error_msg, sizeof(G_io_apdu_buffer));
@@ -190,6 +304,64 @@ __Z_INLINE void handleSign(volatile uint32_t *flags, volatile uint32_t *tx, uint
     *flags |= IO_ASYNCH_REPLY;
 }
 
+__Z_INLINE void handleSignMessage(volatile uint32_t *flags, volatile uint32_t *tx, uint32_t rx) {
+    zemu_log("handleSignMessage\n");
+
+    extractHDPath(rx, OFFSET_DATA);
+
+    // the randomizer and then the message follow the HD path
+    const uint32_t offset = OFFSET_DATA + sizeof(uint32_t) * HDPATH_LEN_DEFAULT;
+    if (rx <= offset + KEY_LEN || (rx - offset - KEY_LEN) > SIGN_MESSAGE_MAX_LEN) {
+        THROW(APDU_CODE_WRONG_LENGTH);
+    }
+
+    const uint8_t *randomizer = G_io_apdu_buffer + offset;
+    if (sign_message_init(randomizer, randomizer + KEY_LEN, (uint16_t)(rx - offset - KEY_LEN)) != zxerr_ok) {
+        THROW(APDU_CODE_DATA_INVALID);
+    }
+
+    // nothing gets signed before the user has read the whole message
+    view_review_init(sign_message_getItem, sign_message_getNumItems, app_sign_message);
+    view_review_show(REVIEW_TXN);
+    *flags |= IO_ASYNCH_REPLY;
+}
+
+__Z_INLINE void handleFrostCommit(volatile uint32_t *tx, uint32_t rx) {
+    zemu_log("handleFrostCommit\n");
+
+    if (!process_chunk(tx, rx)) {
+        THROW(APDU_CODE_OK);
+    }
+
+    // the commitments are written over the apdu buffer
+    uint16_t response_len = 0;
+    if (crypto_frostCommit(tx_get_buffer(), (uint16_t)tx_get_buffer_length(), G_io_apdu_buffer,
+                           IO_APDU_BUFFER_SIZE - 2, &response_len) != zxerr_ok) {
+        *tx = 0;
+        THROW(APDU_CODE_DATA_INVALID);
+    }
+
+    *tx = response_len;
+    THROW(APDU_CODE_OK);
+}
+
+__Z_INLINE void handleFrostSign(volatile uint32_t *flags, volatile uint32_t *tx, uint32_t rx) {
+    zemu_log("handleFrostSign\n");
+
+    if (!process_chunk(tx, rx)) {
+        THROW(APDU_CODE_OK);
+    }
+
+    if (frost_sign_init(tx_get_buffer(), (uint16_t)tx_get_buffer_length()) != zxerr_ok) {
+        THROW(APDU_CODE_DATA_INVALID);
+    }
+
+    // no share gets signed before the user has read the whole message
+    view_review_init(frost_sign_getItem, frost_sign_getNumItems, app_frost_sign);
+    view_review_show(REVIEW_TXN);
+    *flags |= IO_ASYNCH_REPLY;
+}
+
 __Z_INLINE void handle_getversion(__Z_UNUSED volatile uint32_t *flags, volatile uint32_t *tx) {
     G_io_apdu_buffer[0] = 0;
 
@@ -235,6 +407,19 @@ __Z_INLINE void handleGetSpendAuthSignatures(volatile uint32_t *tx, uint32_t rx)
     THROW(APDU_CODE_OK);
 }
 
+__Z_INLINE void handleGetAuthorizationData(volatile uint32_t *tx, uint32_t rx) {
+    zemu_log("handleGetAuthorizationData\n");
+    if (rx < OFFSET_DATA) {
+        THROW(APDU_CODE_WRONG_LENGTH);
+    }
+
+    const uint8_t page = G_io_apdu_buffer[OFFSET_P1];
+    app_fill_authorization_data(page);
+    *tx = cmdResponseLen;
+
+    THROW(APDU_CODE_OK);
+}
+
 #if defined(ENABLE_DELEGATOR_VOTE_SIGNATURES)
 __Z_INLINE void handleGetDelegatorVoteSignatures(volatile uint32_t *tx, uint32_t rx) {
     zemu_log("handleGetDelegatorVoteSignatures\n");
@@ -288,7 +473,19 @@ void handleApdu(volatile uint32_t *flags, volatile uint32_t *tx, uint32_t rx) {
 
                 case INS_GET_FVK: {
                     CHECK_PIN_VALIDATED()
-                    handleGetFVK(tx, rx);
+                    handleGetFVK(flags, tx, rx);
+                    break;
+                }
+
+                case INS_CONFIRM_ADDRESS: {
+                    CHECK_PIN_VALIDATED()
+                    handleConfirmAddress(flags, tx, rx);
+                    break;
+                }
+
+                case INS_PROVE_ADDRESS_OWNERSHIP: {
+                    CHECK_PIN_VALIDATED()
+                    handleProveAddressOwnership(flags, tx, rx);
                     break;
                 }
 
@@ -298,6 +495,24 @@ void handleApdu(volatile uint32_t *flags, volatile uint32_t *tx, uint32_t rx) {
                     break;
                 }
 
+                case INS_SIGN_MESSAGE: {
+                    CHECK_PIN_VALIDATED()
+                    handleSignMessage(flags, tx, rx);
+                    break;
+                }
+
+                case INS_FROST_COMMIT: {
+                    CHECK_PIN_VALIDATED()
+                    handleFrostCommit(tx, rx);
+                    break;
+                }
+
+                case INS_FROST_SIGN: {
+                    CHECK_PIN_VALIDATED()
+                    handleFrostSign(flags, tx, rx);
+                    break;
+                }
+
                 case INS_TX_METADATA: {
                     handleTxMetadata(tx, rx);
                     break;
@@ -308,6 +523,11 @@ void handleApdu(volatile uint32_t *flags, volatile uint32_t *tx, uint32_t rx) {
                     break;
                 }
 
+                case INS_GET_AUTHORIZATION_DATA: {
+                    handleGetAuthorizationData(tx, rx);
+                    break;
+                }
+
 #if defined(ENABLE_DELEGATOR_VOTE_SIGNATURES)
                 case INS_GET_DELEGATOR_VOTE_SIGNATURES: {
                     handleGetDelegatorVoteSignatures(tx, rx);
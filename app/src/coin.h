@@ -48,6 +48,55 @@ extern "C" {
 #define INS_TX_METADATA 0x04
 #define INS_GET_SPEND_AUTH_SIGNATURES 0x05
 #define INS_GET_DELEGATOR_VOTE_SIGNATURES 0x06
+#define INS_GET_AUTHORIZATION_DATA 0x07
+#define INS_CONFIRM_ADDRESS 0x08
+
+// INS_CONFIRM_ADDRESS accepts ConfirmAddressRequest messages up to this length
+#define CONFIRM_ADDRESS_REQUEST_MAX_LEN 64
+
+#define INS_SIGN_MESSAGE 0x09
+
+// INS_SIGN_MESSAGE signs printable ASCII messages up to this length
+#define SIGN_MESSAGE_MAX_LEN 200
+
+#define INS_PROVE_ADDRESS_OWNERSHIP 0x0A
+
+// INS_PROVE_ADDRESS_OWNERSHIP answers printable ASCII challenges up to this length
+#define OWNERSHIP_CHALLENGE_MAX_LEN 128
+// address, address index, ak, SpendAuth signature, then the Schnorr commitment and response
+#define OWNERSHIP_PROOF_LEN (ADDRESS_LEN_BYTES + ADDR_INDEX_LEN + KEY_LEN + SIGNATURE_LEN + 2 * KEY_LEN)
+
+#define INS_FROST_COMMIT 0x0B
+#define INS_FROST_SIGN 0x0C
+
+// INS_FROST_COMMIT returns the identifier followed by the hiding and binding commitments
+#define FROST_COMMITMENTS_LEN (2 + 2 * KEY_LEN)
+// INS_FROST_SIGN signs messages up to this length, shown in hex unless printable ASCII
+#define FROST_MESSAGE_MAX_LEN 128
+
+// INS_GET_AUTHORIZATION_DATA returns this many 68-byte fields per page
+#define AUTHORIZATION_DATA_FIELDS_PER_PAGE 3
+
+// INS_SIGN payloads, selected by the low bits of P2
+#define P2_SIGN_PLAN 0x00
+#define P2_SIGN_AUTHORIZE_REQUEST 0x01
+#define P2_SIGN_PAYLOAD_MASK 0x7F
+// INS_SIGN flag appending the binding signature to the response
+#define P2_SIGN_BINDING_SIGNATURE 0x80
+
+// INS_GET_ADDR randomizer source, selected by P2
+#define P2_ADDR_HOST_RANDOMIZER 0x00
+#define P2_ADDR_DEVICE_RANDOMIZER 0x01
+
+// INS_TX_METADATA entry encodings, selected by P2
+#define P2_METADATA_DENOMS 0x00
+#define P2_METADATA_PROTO 0x01
+#define P2_METADATA_RATE_DATA 0x02
+
+// INS_GET_FVK export modes, selected by P2
+#define P2_FVK_PLAIN 0x00
+#define P2_FVK_ENCRYPTED 0x01
+#define P2_FVK_PROTO 0x02
 
 typedef enum {
     Address = 0,
@@ -17,3 +17,4 @@
 #include "actions.h"
 
 uint16_t cmdResponseLen;
+bool sign_binding_signature = false;
@@ -21,9 +21,13 @@
 #include "addr.h"
 #include "apdu_codes.h"
 #include "coin.h"
+#include "confirm_address.h"
 #include "crypto.h"
+#include "frost_sign.h"
 #include "nv_signature.h"
+#include "ownership_proof.h"
 #include "parser_interface.h"
+#include "sign_message.h"
 #include "tx.h"
 #include "zxerror.h"
 #include "zxformat.h"
@@ -31,6 +35,7 @@
 extern uint16_t cmdResponseLen;
 extern uint32_t address_idx_account;
 extern bool is_randomized;
+extern bool sign_binding_signature;
 
 __Z_INLINE zxerr_t app_fill_address(address_index_t address_index) {
     check_app_canary();
@@ -54,6 +59,61 @@ __Z_INLINE zxerr_t app_fill_address(address_index_t address_index) {
     return error;
 }
 
+__Z_INLINE zxerr_t app_fill_ephemeral_address(uint32_t account) {
+    check_app_canary();
+    // Put data directly in the apdu buffer
+    MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
+
+    cmdResponseLen = 0;
+
+    zxerr_t error = crypto_fillEphemeralAddress(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE - 2, &cmdResponseLen, account);
+
+    if (error != zxerr_ok || cmdResponseLen == 0) {
+        THROW(APDU_CODE_EXECUTION_ERROR);
+    }
+
+    // the randomizer is always set, the device picked it
+    address_idx_account = account;
+    is_randomized = true;
+
+    return error;
+}
+
+__Z_INLINE zxerr_t app_fill_confirm_address(const uint8_t *request, uint16_t requestLen) {
+    check_app_canary();
+    // Put data directly in the apdu buffer
+    MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
+
+    cmdResponseLen = 0;
+
+    zxerr_t error =
+        confirm_address_init(request, requestLen, G_io_apdu_buffer, IO_APDU_BUFFER_SIZE - 2, &cmdResponseLen);
+
+    if (error != zxerr_ok || cmdResponseLen == 0) {
+        THROW(APDU_CODE_DATA_INVALID);
+    }
+
+    return error;
+}
+
+__Z_INLINE zxerr_t app_fill_ownership_proof(address_index_t address_index, const uint8_t *challenge,
+                                            uint16_t challengeLen) {
+    check_app_canary();
+    // Put data directly in the apdu buffer
+    MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
+
+    cmdResponseLen = 0;
+
+    zxerr_t error = ownership_proof_init(address_index, challenge, challengeLen, G_io_apdu_buffer,
+                                         IO_APDU_BUFFER_SIZE - 2, &cmdResponseLen);
+
+    if (error != zxerr_ok || cmdResponseLen == 0) {
+        THROW(APDU_CODE_DATA_INVALID);
+    }
+
+    return error;
+}
+
 __Z_INLINE zxerr_t app_fill_keys() {
     // Put data directly in the apdu buffer
     MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
@@ -69,23 +129,93 @@ __Z_INLINE zxerr_t app_fill_keys() {
     return zxerr_ok;
 }
 
+__Z_INLINE zxerr_t app_fill_encrypted_keys(const uint8_t *host_pk) {
+    // Put data directly in the apdu buffer
+    MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
+
+    cmdResponseLen = 0;
+
+    zxerr_t err = crypto_fillEncryptedKeys(host_pk, G_io_apdu_buffer, IO_APDU_BUFFER_SIZE - 2, &cmdResponseLen);
+
+    if (err != zxerr_ok || cmdResponseLen == 0) {
+        THROW(APDU_CODE_EXECUTION_ERROR);
+    }
+
+    return zxerr_ok;
+}
+
+__Z_INLINE zxerr_t app_fill_proto_keys() {
+    // Put data directly in the apdu buffer
+    MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
+
+    cmdResponseLen = 0;
+
+    zxerr_t err = crypto_fillProtoKeys(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE - 2, &cmdResponseLen);
+
+    if (err != zxerr_ok || cmdResponseLen == 0) {
+        THROW(APDU_CODE_EXECUTION_ERROR);
+    }
+
+    return zxerr_ok;
+}
+
 __Z_INLINE void app_sign() {
     MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
 
     parser_tx_t *tx = tx_get_txObject();
 
-    zxerr_t err = crypto_sign(tx, G_io_apdu_buffer, IO_APDU_BUFFER_SIZE - 3);
+    zxerr_t err = crypto_sign(tx, sign_binding_signature, G_io_apdu_buffer, IO_APDU_BUFFER_SIZE - 3);
 
     check_app_canary();
 
-    // |   64 bytes  |         2 bytes          |        2 bytes        |
-    // | effect hash | spend auth signature qty | binding signature qty |
+    // |   64 bytes  |         2 bytes          |          2 bytes           |          64 bytes          |
+    // | effect hash | spend auth signature qty | delegator vote signature qty | binding signature (opt.) |
     if (err != zxerr_ok) {
         set_code(G_io_apdu_buffer, 0, APDU_CODE_SIGN_VERIFY_ERROR);
         io_exchange(CHANNEL_APDU | IO_RETURN_AFTER_TX, 2);
     } else {
-        set_code(G_io_apdu_buffer, EFFECT_HASH_LEN + 2 * sizeof(uint16_t), APDU_CODE_OK);
-        io_exchange(CHANNEL_APDU | IO_RETURN_AFTER_TX, EFFECT_HASH_LEN + 2 * sizeof(uint16_t) + 2);
+        const uint16_t response_len =
+            EFFECT_HASH_LEN + 2 * sizeof(uint16_t) + (sign_binding_signature ? SIGNATURE_LEN : 0);
+        set_code(G_io_apdu_buffer, response_len, APDU_CODE_OK);
+        io_exchange(CHANNEL_APDU | IO_RETURN_AFTER_TX, response_len + 2);
+    }
+}
+
+__Z_INLINE void app_sign_message() {
+    MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
+
+    uint16_t response_len = 0;
+    zxerr_t err = sign_message_sign(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE - 2, &response_len);
+
+    check_app_canary();
+
+    // |  64 bytes  | 32 bytes |
+    // | signature  |    rk    |
+    if (err != zxerr_ok || response_len == 0) {
+        set_code(G_io_apdu_buffer, 0, APDU_CODE_SIGN_VERIFY_ERROR);
+        io_exchange(CHANNEL_APDU | IO_RETURN_AFTER_TX, 2);
+    } else {
+        set_code(G_io_apdu_buffer, response_len, APDU_CODE_OK);
+        io_exchange(CHANNEL_APDU | IO_RETURN_AFTER_TX, response_len + 2);
+    }
+}
+
+__Z_INLINE void app_frost_sign() {
+    MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
+
+    uint16_t response_len = 0;
+    zxerr_t err = frost_sign_sign(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE - 2, &response_len);
+
+    check_app_canary();
+
+    // |  32 bytes  |
+    // |   share    |
+    if (err != zxerr_ok || response_len == 0) {
+        set_code(G_io_apdu_buffer, 0, APDU_CODE_SIGN_VERIFY_ERROR);
+        io_exchange(CHANNEL_APDU | IO_RETURN_AFTER_TX, 2);
+    } else {
+        set_code(G_io_apdu_buffer, response_len, APDU_CODE_OK);
+        io_exchange(CHANNEL_APDU | IO_RETURN_AFTER_TX, response_len + 2);
     }
 }
 
@@ -101,6 +231,22 @@ __Z_INLINE zxerr_t app_fill_signatures(uint16_t index, signature_type_t signatur
     return zxerr_ok;
 }
 
+__Z_INLINE zxerr_t app_fill_authorization_data(uint8_t page) {
+    // Put data directly in the apdu buffer
+    MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
+
+    cmdResponseLen = 0;
+
+    zxerr_t err = crypto_fillAuthorizationData(tx_get_txObject(), page, G_io_apdu_buffer, IO_APDU_BUFFER_SIZE - 2,
+                                               &cmdResponseLen);
+
+    if (err != zxerr_ok || cmdResponseLen == 0) {
+        THROW(APDU_CODE_DATA_INVALID);
+    }
+
+    return zxerr_ok;
+}
+
 __Z_INLINE void app_reject() {
     MEMZERO(G_io_apdu_buffer, IO_APDU_BUFFER_SIZE);
     set_code(G_io_apdu_buffer, 0, APDU_CODE_COMMAND_NOT_ALLOWED);
@@ -112,6 +258,11 @@ __Z_INLINE void app_reply_address() {
     io_exchange(CHANNEL_APDU | IO_RETURN_AFTER_TX, cmdResponseLen + 2);
 }
 
+__Z_INLINE void app_reply_fvk() {
+    set_code(G_io_apdu_buffer, cmdResponseLen, APDU_CODE_OK);
+    io_exchange(CHANNEL_APDU | IO_RETURN_AFTER_TX, cmdResponseLen + 2);
+}
+
 __Z_INLINE void app_reply_error() {
     set_code(G_io_apdu_buffer, 0, APDU_CODE_DATA_INVALID);
     io_exchange(CHANNEL_APDU | IO_RETURN_AFTER_TX, 2);
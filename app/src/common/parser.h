@@ -28,9 +28,16 @@ const char *parser_getMsgPackTypeDescription(uint8_t type);
 //// parses a tx buffer
 parser_error_t parser_parse(parser_context_t *ctx, const uint8_t *data, size_t dataLen, parser_tx_t *tx_obj);
 
+//// parses a custody AuthorizeRequest, verifying its pre-authorizations before parsing the plan
+parser_error_t parser_parseAuthorizeRequest(parser_context_t *ctx, const uint8_t *data, size_t dataLen,
+                                           parser_tx_t *tx_obj);
+
 //// computes the effect hash
 parser_error_t parser_computeEffectHash(parser_context_t *ctx);
 
+//// refuses plans whose actions, minus the fee, do not balance to zero
+parser_error_t parser_checkBalance(parser_context_t *ctx);
+
 //// verifies tx fields
 parser_error_t parser_validate(parser_context_t *ctx);
 
@@ -41,7 +48,7 @@ parser_error_t parser_getNumItems(const parser_context_t *ctx, uint8_t *num_item
 parser_error_t parser_getItem(const parser_context_t *ctx, uint8_t displayIdx, char *outKey, uint16_t outKeyLen,
                               char *outVal, uint16_t outValLen, uint8_t pageIdx, uint8_t *pageCount);
 
-parser_error_t parser_parseTxMetadata(parser_context_t *ctx, const uint8_t *data, size_t dataLen);
+parser_error_t parser_parseTxMetadata(parser_context_t *ctx, const uint8_t *data, size_t dataLen, uint8_t format);
 
 #ifdef __cplusplus
 }
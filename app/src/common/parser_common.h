@@ -133,6 +133,17 @@ typedef enum {
     parser_encryption_error,
     parser_action_decode_error,
     parser_clue_plan_decode_error,
+    parser_division_by_zero,
+    parser_invalid_trading_pair,
+    parser_invalid_exchange_rate,
+    parser_unspecified_vote,
+    parser_staked_note_not_delegation_token,
+    parser_staked_note_not_owned,
+    parser_zero_unbonded_amount,
+    parser_unbalanced_plan,
+    parser_binding_signature_mismatch,
+    parser_position_metadata_unsupported,
+    parser_invalid_dutch_auction,
 } parser_error_t;
 
 typedef struct {
@@ -143,6 +154,8 @@ typedef struct {
     address_index_t address_index;
     tx_metadata_t tx_metadata[MAX_TX_METADATA_LEN];
     uint8_t tx_metadata_len;
+    rate_data_t rate_data[MAX_RATE_DATA_LEN];
+    uint8_t rate_data_len;
 } parser_context_t;
 
 #ifdef __cplusplus
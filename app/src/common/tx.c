@@ -52,10 +52,8 @@ uint32_t tx_get_buffer_length() { return buffering_get_buffer()->pos; }
 
 uint8_t *tx_get_buffer() { return buffering_get_buffer()->data; }
 
-const char *tx_parse_metadata() {
-    MEMZERO(ctx_parsed_tx.tx_metadata, sizeof(ctx_parsed_tx.tx_metadata));
-
-    uint8_t err = parser_parseTxMetadata(&ctx_parsed_tx, tx_get_buffer(), tx_get_buffer_length());
+const char *tx_parse_metadata(uint8_t format) {
+    uint8_t err = parser_parseTxMetadata(&ctx_parsed_tx, tx_get_buffer(), tx_get_buffer_length(), format);
 
     CHECK_APP_CANARY()
 
@@ -66,18 +64,16 @@ const char *tx_parse_metadata() {
     return NULL;
 }
 
-const char *tx_parse() {
-    MEMZERO(&tx_obj, sizeof(tx_obj));
-
-    uint8_t err = parser_parse(&ctx_parsed_tx, tx_get_buffer(), tx_get_buffer_length(), &tx_obj);
-
+static const char *tx_process() {
+    uint8_t err = parser_computeEffectHash(&ctx_parsed_tx);
     CHECK_APP_CANARY()
 
     if (err != parser_ok) {
         return parser_getErrorDescription(err);
     }
 
-    err = parser_computeEffectHash(&ctx_parsed_tx);
+    // Unbalanced plans are refused before they are shown for review
+    err = parser_checkBalance(&ctx_parsed_tx);
     CHECK_APP_CANARY()
 
     if (err != parser_ok) {
@@ -94,6 +90,34 @@ const char *tx_parse() {
     return NULL;
 }
 
+const char *tx_parse() {
+    MEMZERO(&tx_obj, sizeof(tx_obj));
+
+    uint8_t err = parser_parse(&ctx_parsed_tx, tx_get_buffer(), tx_get_buffer_length(), &tx_obj);
+
+    CHECK_APP_CANARY()
+
+    if (err != parser_ok) {
+        return parser_getErrorDescription(err);
+    }
+
+    return tx_process();
+}
+
+const char *tx_parse_authorize_request() {
+    MEMZERO(&tx_obj, sizeof(tx_obj));
+
+    uint8_t err = parser_parseAuthorizeRequest(&ctx_parsed_tx, tx_get_buffer(), tx_get_buffer_length(), &tx_obj);
+
+    CHECK_APP_CANARY()
+
+    if (err != parser_ok) {
+        return parser_getErrorDescription(err);
+    }
+
+    return tx_process();
+}
+
 void tx_parse_reset() { MEMZERO(&tx_obj, sizeof(tx_obj)); }
 
 parser_tx_t *tx_get_txObject() { return &tx_obj; }
@@ -48,11 +48,16 @@ uint8_t *tx_get_buffer();
 /// \return It returns NULL if data is valid or error message otherwise.
 const char *tx_parse();
 
+/// Parse a custody AuthorizeRequest stored in transaction buffer
+/// Its pre-authorizations are verified before the plan it carries is parsed.
+/// \return It returns NULL if data is valid or error message otherwise.
+const char *tx_parse_authorize_request();
+
 /// Parse transaction metadata
 /// storing result in parser context
 /// this metadata is used during transaction
 /// processing
-const char *tx_parse_metadata();
+const char *tx_parse_metadata(uint8_t format);
 
 /// Return the number of items in the transaction
 zxerr_t tx_getNumItems(uint8_t *num_items);
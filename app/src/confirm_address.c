@@ -0,0 +1,66 @@
+/*******************************************************************************
+ *   (c) 2018 - 2024 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+
+#include "confirm_address.h"
+
+#include <stdio.h>
+
+#include "constants.h"
+#include "parser_common.h"
+#include "rslib.h"
+#include "zxformat.h"
+#include "zxmacros.h"
+
+// The address exactly as the response encodes it, in the form shown on screen
+static char ui_address[SHORT_ADDRESS_LEN + 1] = {0};
+
+zxerr_t confirm_address_init(const uint8_t *request, uint16_t requestLen, uint8_t *response, uint16_t responseLen,
+                             uint16_t *written) {
+    MEMZERO(ui_address, sizeof(ui_address));
+    if (request == NULL || response == NULL || written == NULL) {
+        return zxerr_no_data;
+    }
+
+    const bytes_t request_bytes = {.ptr = request, .len = requestLen};
+    if (rs_confirm_address(&request_bytes, response, responseLen, written, (uint8_t *)ui_address,
+                           sizeof(ui_address)) != parser_ok) {
+        MEMZERO(ui_address, sizeof(ui_address));
+        *written = 0;
+        return zxerr_unknown;
+    }
+
+    return zxerr_ok;
+}
+
+zxerr_t confirm_address_getNumItems(uint8_t *num_items) {
+    zemu_log_stack("confirm_address_getNumItems");
+    *num_items = 1;
+    return zxerr_ok;
+}
+
+zxerr_t confirm_address_getItem(int8_t displayIdx, char *outKey, uint16_t outKeyLen, char *outVal, uint16_t outValLen,
+                                uint8_t pageIdx, uint8_t *pageCount) {
+    ZEMU_LOGF(50, "[confirm_address_getItem] %d/%d\n", displayIdx, pageIdx)
+
+    switch (displayIdx) {
+        case 0:
+            snprintf(outKey, outKeyLen, "Confirm Address");
+            pageString(outVal, outValLen, ui_address, pageIdx, pageCount);
+            return zxerr_ok;
+        default:
+            return zxerr_no_data;
+    }
+}
@@ -0,0 +1,39 @@
+/*******************************************************************************
+ *   (c) 2018 - 2024 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+#pragma once
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#include <stdbool.h>
+#include <stdint.h>
+
+#include "zxerror.h"
+
+// Answers a custody ConfirmAddressRequest, keeping the address to show for review
+zxerr_t confirm_address_init(const uint8_t *request, uint16_t requestLen, uint8_t *response, uint16_t responseLen,
+                             uint16_t *written);
+
+// Return the number of items in the address confirmation view
+zxerr_t confirm_address_getNumItems(uint8_t *num_items);
+
+// Gets an specific item from the address confirmation view (including paging)
+zxerr_t confirm_address_getItem(int8_t displayIdx, char *outKey, uint16_t outKeyLen, char *outValue,
+                                uint16_t outValueLen, uint8_t pageIdx, uint8_t *pageCount);
+
+#ifdef __cplusplus
+}
+#endif
@@ -14,6 +14,12 @@
  *  limitations under the License.
  ********************************************************************************/
 #define U128_STR_MAX_LEN 40
+// a u128 with a decimal point, or with "0." ahead of 38 fractional digits, and null terminator
+#define AMOUNT_STR_MAX_LEN (U128_STR_MAX_LEN + 1)
+// integral part, decimal point and 6 fractional digits
+#define PRICE_STR_MAX_LEN (U128_STR_MAX_LEN + 7)
+// slashing penalty in percent, up to 100, decimal point, 6 fractional digits and null terminator
+#define PENALTY_STR_MAX_LEN 11
 // plus null terminator
 #define MAX_DENOM_LEN 120 + 1
 
@@ -21,8 +27,8 @@
 // to hold the formatting of a value_t type, following
 // provided documentation, we choose the worst case, where
 // a value contains an unknown token and we use a custom denom
-// U128 + space + denom + null terminator
-#define VALUE_DISPLAY_MAX_LEN (U128_STR_MAX_LEN + 1 + MAX_DENOM_LEN)  // = 162
+// amount + space + denom + null terminator
+#define VALUE_DISPLAY_MAX_LEN (AMOUNT_STR_MAX_LEN + 1 + MAX_DENOM_LEN)  // = 163
 
 #define SIGNATURE_LEN_BYTES 64
 
@@ -37,9 +43,15 @@
 
 #define POSITION_ID_BECH32_PREFIX "plpid"
 #define POSITION_ID_LEN 32
+// HRP + separator + 52 data chars + 6 checksum chars + null terminator
+#define POSITION_ID_BECH32_LEN 65
+#define POSITION_NONCE_LEN 32
 
 #define AUCTION_ID_BECH32_PREFIX "pauctid"
 #define AUCTION_ID_LEN 32
+// HRP + separator + 52 data chars + 6 checksum chars + null terminator
+#define AUCTION_ID_BECH32_LEN 67
+#define DUTCH_AUCTION_NONCE_LEN 32
 
 // Common BECH32m constants
 #define CHECKSUM_LENGTH 8
@@ -77,6 +89,9 @@
 // The number of metadata we can handle in RAM during
 // transaction signing
 #define MAX_TX_METADATA_LEN 5
+// The number of validator rate data entries we can
+// handle in RAM during transaction signing
+#define MAX_RATE_DATA_LEN 4
 
 // The staking token asset ID (upenumbra)
 // Bech32m: passet1984fctenw8m2fpl8a9wzguzp7j34d7vravryuhft808nyt9fdggqxmanqm
@@ -106,13 +121,17 @@
     (VALUE_DISPLAY_MAX_LEN + 300 + 36)  // = 498 -> 300 bytes for the channel and destination address
 
 // Constant to use to allocate a buffer on the stack to hold the formatting of an delegate action
-#define DELEGATE_DISPLAY_MAX_LEN (VALUE_DISPLAY_MAX_LEN + 92)  // = 254
+#define DELEGATE_DISPLAY_MAX_LEN (2 * VALUE_DISPLAY_MAX_LEN + 100)  // = 424
 
 // Constant to use to allocate a buffer on the stack to hold the formatting of an undelegate action
 #define UNDELEGATE_DISPLAY_MAX_LEN (2 * VALUE_DISPLAY_MAX_LEN + 100)  // = 424
 
+// Constant to use to allocate a buffer on the stack to hold the formatting of an undelegate claim action
+#define UNDELEGATE_CLAIM_DISPLAY_MAX_LEN (3 * VALUE_DISPLAY_MAX_LEN + PENALTY_STR_MAX_LEN + 100)  // = 597
+
 // Constant to use to allocate a buffer on the stack to hold the formatting of an position_open action
-#define POSITION_OPEN_DISPLAY_MAX_LEN (2 * VALUE_DISPLAY_MAX_LEN + 110)  // = 434
+#define POSITION_OPEN_DISPLAY_MAX_LEN \
+    (2 * VALUE_DISPLAY_MAX_LEN + 2 * PRICE_STR_MAX_LEN + POSITION_ID_BECH32_LEN + 159)  // = 642
 
 // Constant to use to allocate a buffer on the stack to hold the formatting of an position_close action
 #define POSITION_CLOSE_DISPLAY_MAX_LEN 100
@@ -121,10 +140,11 @@
 #define POSITION_WITHDRAW_DISPLAY_MAX_LEN 140
 
 // Constant to use to allocate a buffer on the stack to hold the formatting of an dutch_auction_schedule action
-#define DUTCH_AUCTION_SCHEDULE_DISPLAY_MAX_LEN (4 * VALUE_DISPLAY_MAX_LEN + 154)  // = 802
+#define DUTCH_AUCTION_SCHEDULE_DISPLAY_MAX_LEN \
+    (4 * VALUE_DISPLAY_MAX_LEN + PRICE_STR_MAX_LEN + AUCTION_ID_BECH32_LEN + 186)  // = 948
 
 // Constant to use to allocate a buffer on the stack to hold the formatting of an dutch_auction_end action
-#define DUTCH_AUCTION_END_DISPLAY_MAX_LEN 100
+#define DUTCH_AUCTION_END_DISPLAY_MAX_LEN (AUCTION_ID_BECH32_LEN + 58)  // = 125
 
 // Constant to use to allocate a buffer on the stack to hold the formatting of an dutch_auction_withdraw action
 #define DUTCH_AUCTION_WITHDRAW_DISPLAY_MAX_LEN (2 * VALUE_DISPLAY_MAX_LEN + ASSET_ID_LEN + 88)  // 444
@@ -122,6 +122,68 @@ catch_zx_error:
     return error;
 }
 
+zxerr_t crypto_fillEncryptedKeys(const uint8_t *host_pk, uint8_t *output, uint16_t len, uint16_t *cmdResponseLen) {
+    zemu_log("crypto_fillEncryptedKeys\n");
+
+    keys_t keys = {0};
+    zxerr_t error = zxerr_invalid_crypto_settings;
+
+    if (host_pk == NULL || output == NULL || cmdResponseLen == NULL || len < ENCRYPTED_FVK_LEN) {
+        return error;
+    }
+    *cmdResponseLen = 0;
+
+    // Compute seed
+    CATCH_ZX_ERROR(computeSpendKey(&keys));
+
+    // use seed to compute viewing keys
+    CATCH_ZX_ERROR(compute_keys(&keys));
+    MEMZERO(keys.skb, sizeof(keys.skb));
+
+    if (rs_encrypt_fvk(&keys, host_pk, output, len) != parser_ok) {
+        goto catch_zx_error;
+    }
+
+    *cmdResponseLen = ENCRYPTED_FVK_LEN;
+    error = zxerr_ok;
+
+catch_zx_error:
+    MEMZERO(&keys, sizeof(keys));
+
+    return error;
+}
+
+zxerr_t crypto_fillProtoKeys(uint8_t *output, uint16_t len, uint16_t *cmdResponseLen) {
+    zemu_log("crypto_fillProtoKeys\n");
+
+    keys_t keys = {0};
+    zxerr_t error = zxerr_invalid_crypto_settings;
+
+    if (output == NULL || cmdResponseLen == NULL || len < ENCODED_FVK_RESPONSE_LEN) {
+        return error;
+    }
+    *cmdResponseLen = 0;
+
+    // Compute seed
+    CATCH_ZX_ERROR(computeSpendKey(&keys));
+
+    // use seed to compute viewing keys
+    CATCH_ZX_ERROR(compute_keys(&keys));
+    MEMZERO(keys.skb, sizeof(keys.skb));
+
+    if (rs_encode_fvk_response(&keys, output, len) != parser_ok) {
+        goto catch_zx_error;
+    }
+
+    *cmdResponseLen = ENCODED_FVK_RESPONSE_LEN;
+    error = zxerr_ok;
+
+catch_zx_error:
+    MEMZERO(&keys, sizeof(keys));
+
+    return error;
+}
+
 zxerr_t crypto_fillAddress(uint8_t *buffer, uint16_t bufferLen, uint16_t *cmdResponseLen, uint32_t account,
                            uint8_t *randomizer) {
     zemu_log("crypto_fillAddress\n");
@@ -150,8 +212,239 @@ catch_zx_error:
     return error;
 }
 
-zxerr_t crypto_sign(parser_tx_t *tx_obj, uint8_t *signature, uint16_t signatureMaxlen) {
-    if (signature == NULL || tx_obj == NULL || signatureMaxlen < EFFECT_HASH_LEN + 2 * sizeof(uint16_t)) {
+// Writes a one-time address under a device-chosen randomizer, followed by its address index.
+zxerr_t crypto_fillEphemeralAddress(uint8_t *buffer, uint16_t bufferLen, uint16_t *cmdResponseLen, uint32_t account) {
+    zemu_log("crypto_fillEphemeralAddress\n");
+    check_app_canary();
+
+    keys_t keys = {0};
+    zxerr_t error = zxerr_invalid_crypto_settings;
+
+    if (buffer == NULL || cmdResponseLen == NULL || bufferLen < ADDRESS_LEN_BYTES + ADDR_INDEX_LEN) {
+        return zxerr_invalid_crypto_settings;
+    }
+
+    MEMZERO(buffer, bufferLen);
+
+    CATCH_ZX_ERROR(computeSpendKey(&keys));
+
+    CATCH_ZX_ERROR(compute_ephemeral_address(&keys, account, buffer + ADDRESS_LEN_BYTES));
+    MEMZERO(keys.skb, sizeof(keys.skb));
+
+    CATCH_ZX_ERROR(copyKeys(&keys, Address, buffer, bufferLen, cmdResponseLen));
+    *cmdResponseLen += ADDR_INDEX_LEN;
+
+    error = zxerr_ok;
+
+catch_zx_error:
+    MEMZERO(&keys, sizeof(keys));
+    return error;
+}
+
+// Writes the address at address_index and its index, followed by proofs that the device
+// holds its spend key and incoming viewing key, answering the auditor's challenge.
+zxerr_t crypto_proveAddressOwnership(address_index_t address_index, const uint8_t *challenge, uint16_t challengeLen,
+                                     uint8_t *buffer, uint16_t bufferLen, uint16_t *cmdResponseLen) {
+    zemu_log("crypto_proveAddressOwnership\n");
+    check_app_canary();
+
+    if (challenge == NULL || buffer == NULL || cmdResponseLen == NULL ||
+        bufferLen < OWNERSHIP_PROOF_LEN) {
+        return zxerr_invalid_crypto_settings;
+    }
+
+    keys_t keys = {0};
+    zxerr_t error = zxerr_invalid_crypto_settings;
+    *cmdResponseLen = 0;
+    MEMZERO(buffer, bufferLen);
+
+    CATCH_ZX_ERROR(computeSpendKey(&keys));
+
+    const bytes_t challenge_bytes = {.ptr = challenge, .len = challengeLen};
+    if (rs_prove_address_ownership(&keys, address_index.account,
+                                   address_index.has_randomizer ? address_index.randomizer : NULL, &challenge_bytes,
+                                   buffer, bufferLen) != parser_ok) {
+        MEMZERO(buffer, bufferLen);
+        goto catch_zx_error;
+    }
+
+    *cmdResponseLen = OWNERSHIP_PROOF_LEN;
+    error = zxerr_ok;
+
+catch_zx_error:
+    MEMZERO(&keys, sizeof(keys));
+    return error;
+}
+
+// Signs an off-chain message with the spend key randomized by `randomizer`,
+// writing the signature followed by the randomized verification key.
+zxerr_t crypto_signMessage(const uint8_t *message, uint16_t messageLen, const uint8_t *randomizer, uint8_t *output,
+                           uint16_t outputLen, uint16_t *cmdResponseLen) {
+    zemu_log("crypto_signMessage\n");
+    check_app_canary();
+
+    if (message == NULL || randomizer == NULL || output == NULL || cmdResponseLen == NULL ||
+        outputLen < SIGNATURE_LEN + KEY_LEN) {
+        return zxerr_invalid_crypto_settings;
+    }
+
+    keys_t keys = {0};
+    zxerr_t error = zxerr_invalid_crypto_settings;
+    *cmdResponseLen = 0;
+    MEMZERO(output, outputLen);
+
+    CATCH_ZX_ERROR(computeSpendKey(&keys));
+
+    const bytes_t message_bytes = {.ptr = message, .len = messageLen};
+    const bytes_t randomizer_bytes = {.ptr = randomizer, .len = KEY_LEN};
+    if (rs_sign_message(&message_bytes, &randomizer_bytes, &keys.skb, output, outputLen) != parser_ok) {
+        MEMZERO(output, outputLen);
+        goto catch_zx_error;
+    }
+
+    *cmdResponseLen = SIGNATURE_LEN + KEY_LEN;
+    error = zxerr_ok;
+
+catch_zx_error:
+    MEMZERO(&keys, sizeof(keys));
+    return error;
+}
+
+// Runs FROST round one for the participant of the key generation transcript,
+// writing its identifier and nonce commitments. The nonces stay on the device.
+zxerr_t crypto_frostCommit(const uint8_t *transcript, uint16_t transcriptLen, uint8_t *output, uint16_t outputLen,
+                           uint16_t *cmdResponseLen) {
+    zemu_log("crypto_frostCommit\n");
+    check_app_canary();
+
+    if (transcript == NULL || output == NULL || cmdResponseLen == NULL || outputLen < FROST_COMMITMENTS_LEN) {
+        return zxerr_invalid_crypto_settings;
+    }
+
+    keys_t keys = {0};
+    zxerr_t error = zxerr_invalid_crypto_settings;
+    *cmdResponseLen = 0;
+    MEMZERO(output, outputLen);
+
+    CATCH_ZX_ERROR(computeSpendKey(&keys));
+
+    const bytes_t transcript_bytes = {.ptr = transcript, .len = transcriptLen};
+    if (rs_frost_commit(&keys.skb, &transcript_bytes, output, outputLen) != parser_ok) {
+        MEMZERO(output, outputLen);
+        goto catch_zx_error;
+    }
+
+    *cmdResponseLen = FROST_COMMITMENTS_LEN;
+    error = zxerr_ok;
+
+catch_zx_error:
+    MEMZERO(&keys, sizeof(keys));
+    return error;
+}
+
+// Runs FROST round two on an approved request with the nonces of the last
+// round one, writing the signature share.
+zxerr_t crypto_frostSignShare(const uint8_t *request, uint16_t requestLen, uint8_t *output, uint16_t outputLen,
+                              uint16_t *cmdResponseLen) {
+    zemu_log("crypto_frostSignShare\n");
+    check_app_canary();
+
+    if (request == NULL || output == NULL || cmdResponseLen == NULL || outputLen < KEY_LEN) {
+        return zxerr_invalid_crypto_settings;
+    }
+
+    keys_t keys = {0};
+    zxerr_t error = zxerr_invalid_crypto_settings;
+    *cmdResponseLen = 0;
+    MEMZERO(output, outputLen);
+
+    CATCH_ZX_ERROR(computeSpendKey(&keys));
+
+    const bytes_t request_bytes = {.ptr = request, .len = requestLen};
+    if (rs_frost_sign_share(&keys.skb, &request_bytes, output, outputLen) != parser_ok) {
+        MEMZERO(output, outputLen);
+        goto catch_zx_error;
+    }
+
+    *cmdResponseLen = KEY_LEN;
+    error = zxerr_ok;
+
+catch_zx_error:
+    MEMZERO(&keys, sizeof(keys));
+    return error;
+}
+
+// Field 0 of the AuthorizationData is the effect hash, the spend auths follow
+// in order. Delegator vote signatures are not kept, so there are none.
+zxerr_t crypto_fillAuthorizationData(const parser_tx_t *tx_obj, uint8_t page, uint8_t *buffer, uint16_t bufferLen,
+                                     uint16_t *cmdResponseLen) {
+    if (tx_obj == NULL || buffer == NULL || cmdResponseLen == NULL) {
+        return zxerr_no_data;
+    }
+    *cmdResponseLen = 0;
+
+    const uint32_t spend_qty = (uint32_t)nv_num_signatures(Spend);
+    const uint32_t fields = 1 + spend_qty;
+    const uint32_t first = (uint32_t)page * AUTHORIZATION_DATA_FIELDS_PER_PAGE;
+    if (first >= fields) {
+        return zxerr_no_data;
+    }
+    uint32_t last = first + AUTHORIZATION_DATA_FIELDS_PER_PAGE;
+    if (last > fields) {
+        last = fields;
+    }
+
+    bytes_t effect_hash = {0};
+    signature_t spend_auths[AUTHORIZATION_DATA_FIELDS_PER_PAGE] = {0};
+    uint16_t spend_auths_qty = 0;
+    for (uint32_t field = first; field < last; field++) {
+        if (field == 0) {
+            effect_hash.ptr = tx_obj->effect_hash;
+            effect_hash.len = EFFECT_HASH_LEN;
+            continue;
+        }
+        if (nv_get_signature((uint16_t)(field - 1), &spend_auths[spend_auths_qty], Spend) == 0) {
+            return zxerr_unknown;
+        }
+        spend_auths_qty++;
+    }
+
+    uint16_t written = 0;
+    if (rs_encode_authorization_data(&effect_hash, (const uint8_t *)spend_auths, spend_auths_qty, NULL, 0, NULL, 0,
+                                     buffer, bufferLen, &written) != parser_ok) {
+        return zxerr_buffer_too_small;
+    }
+
+    *cmdResponseLen = written;
+    return zxerr_ok;
+}
+
+// Called from Rust to check the Ed25519 pre-authorizations of a custody AuthorizeRequest.
+bool crypto_verify_ed25519(const uint8_t *vk, const uint8_t *msg, uint32_t msg_len, const uint8_t *sig) {
+    if (vk == NULL || msg == NULL || sig == NULL) {
+        return false;
+    }
+
+    // The SDK takes a compressed point as 0x02 followed by y in big-endian, which is the
+    // little-endian Ed25519 encoding reversed, sign bit included.
+    uint8_t point[1 + 2 * PK_LEN_25519] = {0};
+    point[0] = 0x02;
+    for (uint8_t i = 0; i < PK_LEN_25519; i++) {
+        point[1 + i] = vk[PK_LEN_25519 - 1 - i];
+    }
+
+    cx_ecfp_public_key_t public_key = {0};
+    if (cx_edwards_decompress_point_no_throw(CX_CURVE_Ed25519, point, sizeof(point)) != CX_OK ||
+        cx_ecfp_init_public_key_no_throw(CX_CURVE_Ed25519, point, sizeof(point), &public_key) != CX_OK) {
+        return false;
+    }
+
+    return cx_eddsa_verify_no_throw(&public_key, CX_SHA512, msg, msg_len, sig, SIGNATURE_LEN);
+}
+
+zxerr_t crypto_sign(parser_tx_t *tx_obj, bool with_binding, uint8_t *signature, uint16_t signatureMaxlen) {
+    if (signature == NULL || tx_obj == NULL ||
+        signatureMaxlen < EFFECT_HASH_LEN + 2 * sizeof(uint16_t) + (with_binding ? SIGNATURE_LEN : 0)) {
         return zxerr_invalid_crypto_settings;
     }
 
@@ -195,6 +488,14 @@ zxerr_t crypto_sign(parser_tx_t *tx_obj, uint8_t *signature, uint16_t signatureM
     current_ptr += sizeof(uint16_t);
 
     MEMCPY(current_ptr, &delegator_signatures, sizeof(uint16_t));
+    current_ptr += sizeof(uint16_t);
+
+    // On request, the binding signature goes right after the counters, which
+    // completes the authorization data for the plan.
+    if (with_binding && compute_binding_signature(tx_obj, current_ptr, SIGNATURE_LEN) != parser_ok) {
+        MEMZERO(signature, signatureMaxlen);
+        return zxerr_invalid_crypto_settings;
+    }
 
     return zxerr_ok;
 
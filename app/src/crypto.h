@@ -32,10 +32,33 @@ extern uint32_t hdPath[HDPATH_LEN_DEFAULT];
 
 zxerr_t crypto_fillKeys(uint8_t *output, uint16_t len, uint16_t *cmdResponseLen);
 
+zxerr_t crypto_fillEncryptedKeys(const uint8_t *host_pk, uint8_t *output, uint16_t len, uint16_t *cmdResponseLen);
+
+zxerr_t crypto_fillProtoKeys(uint8_t *output, uint16_t len, uint16_t *cmdResponseLen);
+
 zxerr_t crypto_fillAddress(uint8_t *buffer, uint16_t bufferLen, uint16_t *addrResponseLen, uint32_t account,
                            uint8_t *randomizer);
 
-zxerr_t crypto_sign(parser_tx_t *tx_obj, uint8_t *signature, uint16_t signatureMaxlen);
+zxerr_t crypto_fillEphemeralAddress(uint8_t *buffer, uint16_t bufferLen, uint16_t *addrResponseLen, uint32_t account);
+
+zxerr_t crypto_sign(parser_tx_t *tx_obj, bool with_binding, uint8_t *signature, uint16_t signatureMaxlen);
+
+zxerr_t crypto_proveAddressOwnership(address_index_t address_index, const uint8_t *challenge, uint16_t challengeLen,
+                                     uint8_t *buffer, uint16_t bufferLen, uint16_t *cmdResponseLen);
+
+zxerr_t crypto_signMessage(const uint8_t *message, uint16_t messageLen, const uint8_t *randomizer, uint8_t *output,
+                           uint16_t outputLen, uint16_t *cmdResponseLen);
+
+zxerr_t crypto_frostCommit(const uint8_t *transcript, uint16_t transcriptLen, uint8_t *output, uint16_t outputLen,
+                           uint16_t *cmdResponseLen);
+
+zxerr_t crypto_frostSignShare(const uint8_t *request, uint16_t requestLen, uint8_t *output, uint16_t outputLen,
+                              uint16_t *cmdResponseLen);
+
+zxerr_t crypto_fillAuthorizationData(const parser_tx_t *tx_obj, uint8_t page, uint8_t *buffer, uint16_t bufferLen,
+                                     uint16_t *cmdResponseLen);
+
+bool crypto_verify_ed25519(const uint8_t *vk, const uint8_t *msg, uint32_t msg_len, const uint8_t *sig);
 
 zxerr_t crypto_extractSpendingKeyBytes(uint8_t *key_bytes, uint32_t key_bytes_len);
 
@@ -31,6 +31,16 @@ zxerr_t compute_address(keys_t *keys, uint32_t account, uint8_t *randomizer) {
     return zxerr_ok;
 }
 
+zxerr_t compute_ephemeral_address(keys_t *keys, uint32_t account, uint8_t *address_index) {
+    if (keys == NULL || address_index == NULL) return zxerr_unknown;
+
+    if (rs_compute_ephemeral_address(keys, account, address_index) != parser_ok) {
+        return zxerr_unknown;
+    }
+
+    return zxerr_ok;
+}
+
 zxerr_t compute_keys(keys_t *keys) {
     if (keys == NULL) return zxerr_unknown;
 
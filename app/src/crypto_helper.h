@@ -46,6 +46,7 @@ extern "C" {
     } while (0)
 
 zxerr_t compute_address(keys_t *keys, uint32_t account, uint8_t *randomizer);
+zxerr_t compute_ephemeral_address(keys_t *keys, uint32_t account, uint8_t *address_index);
 zxerr_t compute_keys(keys_t *keys);
 
 #ifdef __cplusplus
@@ -0,0 +1,118 @@
+/*******************************************************************************
+ *  (c) 2018 - 2024 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+
+#include "flow_summary.h"
+
+#include "note.h"
+#include "parser_impl.h"
+#include "ui_utils.h"
+#include "zxformat.h"
+
+static bool is_zero(const amount_t *amount) { return amount->lo == 0 && amount->hi == 0; }
+
+typedef enum {
+    FLOW_SENT = 0,
+    FLOW_RECEIVED,
+    FLOW_FEE,
+    FLOW_KINDS,
+} flow_kind_e;
+
+static const char *const flow_keys[FLOW_KINDS] = {"Sent", "Received", "Fee"};
+
+static const amount_t *flow_amount(const asset_flow_t *flow, flow_kind_e kind) {
+    switch (kind) {
+        case FLOW_SENT:
+            return &flow->sent;
+        case FLOW_RECEIVED:
+            return &flow->received;
+        default:
+            return &flow->fee;
+    }
+}
+
+// Each asset shows up to three items, in order: what is sent, what is received and the fee
+static uint8_t flow_num_items(const asset_flow_t *flow) {
+    uint8_t num_items = 0;
+    for (uint8_t kind = 0; kind < FLOW_KINDS; kind++) {
+        num_items += is_zero(flow_amount(flow, kind)) ? 0 : 1;
+    }
+    return num_items;
+}
+
+parser_error_t flow_summary_getNumItems(const parser_context_t *ctx, uint8_t *num_items) {
+    if (ctx == NULL || num_items == NULL) {
+        return parser_no_data;
+    }
+
+    const flow_summary_t *summary = &ctx->tx_obj->flow_summary;
+    if (summary->incomplete) {
+        *num_items = 1;
+        return parser_ok;
+    }
+
+    *num_items = 0;
+    for (uint8_t i = 0; i < summary->qty && i < FLOW_ASSETS_QTY; i++) {
+        *num_items += flow_num_items(&summary->flows[i]);
+    }
+    return parser_ok;
+}
+
+parser_error_t flow_summary_getItem(const parser_context_t *ctx, uint8_t displayIdx, char *outKey, uint16_t outKeyLen,
+                                    char *outVal, uint16_t outValLen, uint8_t pageIdx, uint8_t *pageCount) {
+    if (ctx == NULL || outKey == NULL || outVal == NULL || outKeyLen == 0 || outValLen == 0) {
+        return parser_no_data;
+    }
+
+    const flow_summary_t *summary = &ctx->tx_obj->flow_summary;
+    if (summary->incomplete) {
+        if (displayIdx != 0) {
+            return parser_no_data;
+        }
+        snprintf(outKey, outKeyLen, "Summary");
+        pageString(outVal, outValLen, "Too many assets, review each action", pageIdx, pageCount);
+        return parser_ok;
+    }
+
+    for (uint8_t i = 0; i < summary->qty && i < FLOW_ASSETS_QTY; i++) {
+        const asset_flow_t *flow = &summary->flows[i];
+        for (uint8_t kind = 0; kind < FLOW_KINDS; kind++) {
+            const amount_t *amount = flow_amount(flow, kind);
+            if (is_zero(amount)) {
+                continue;
+            }
+            if (displayIdx > 0) {
+                displayIdx--;
+                continue;
+            }
+
+            value_t value = {0};
+            value.has_amount = true;
+            value.amount = *amount;
+            value.has_asset_id = true;
+            value.asset_id.inner.ptr = flow->asset_id;
+            value.asset_id.inner.len = ASSET_ID_LEN;
+
+            char bufferUI[VALUE_DISPLAY_MAX_LEN] = {0};
+            CHECK_ERROR(
+                printValue(ctx, &value, &ctx->tx_obj->parameters_plan.chain_id, true, bufferUI, sizeof(bufferUI)));
+            snprintf(outKey, outKeyLen, "%s", flow_keys[kind]);
+            pageString(outVal, outValLen, bufferUI, pageIdx, pageCount);
+            return parser_ok;
+        }
+    }
+
+    return parser_no_data;
+}
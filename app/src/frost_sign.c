@@ -0,0 +1,104 @@
+/*******************************************************************************
+ *   (c) 2018 - 2024 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+
+#include "frost_sign.h"
+
+#include <stdio.h>
+
+#include "coin.h"
+#include "crypto.h"
+#include "parser_common.h"
+#include "rslib.h"
+#include "zxformat.h"
+#include "zxmacros.h"
+
+// The request stays where it was received, only the message is copied out
+static const uint8_t *request = NULL;
+static uint16_t request_len = 0;
+
+// The message as shown, null terminated: as is when printable ASCII, in hex otherwise
+static char message[2 * FROST_MESSAGE_MAX_LEN + 1] = {0};
+
+static void frost_sign_clear() {
+    MEMZERO(message, sizeof(message));
+    request = NULL;
+    request_len = 0;
+}
+
+zxerr_t frost_sign_init(const uint8_t *req, uint16_t reqLen) {
+    frost_sign_clear();
+    if (req == NULL || reqLen == 0) {
+        return zxerr_no_data;
+    }
+
+    const bytes_t request_bytes = {.ptr = req, .len = reqLen};
+    bytes_t message_bytes = {0};
+    if (rs_frost_parse_sign_request(&request_bytes, &message_bytes) != parser_ok || message_bytes.len == 0 ||
+        message_bytes.len > FROST_MESSAGE_MAX_LEN) {
+        return zxerr_invalid_crypto_settings;
+    }
+
+    bool printable = true;
+    for (uint16_t i = 0; i < message_bytes.len; i++) {
+        if (message_bytes.ptr[i] < 0x20 || message_bytes.ptr[i] > 0x7E) {
+            printable = false;
+            break;
+        }
+    }
+
+    if (printable) {
+        MEMCPY(message, message_bytes.ptr, message_bytes.len);
+    } else if (array_to_hexstr(message, sizeof(message), message_bytes.ptr, message_bytes.len) == 0) {
+        frost_sign_clear();
+        return zxerr_buffer_too_small;
+    }
+
+    request = req;
+    request_len = reqLen;
+
+    return zxerr_ok;
+}
+
+zxerr_t frost_sign_sign(uint8_t *output, uint16_t outputLen, uint16_t *written) {
+    if (request == NULL) {
+        return zxerr_no_data;
+    }
+
+    zxerr_t err = crypto_frostSignShare(request, request_len, output, outputLen, written);
+    frost_sign_clear();
+
+    return err;
+}
+
+zxerr_t frost_sign_getNumItems(uint8_t *num_items) {
+    zemu_log_stack("frost_sign_getNumItems");
+    *num_items = 1;
+    return zxerr_ok;
+}
+
+zxerr_t frost_sign_getItem(int8_t displayIdx, char *outKey, uint16_t outKeyLen, char *outVal, uint16_t outValLen,
+                           uint8_t pageIdx, uint8_t *pageCount) {
+    ZEMU_LOGF(50, "[frost_sign_getItem] %d/%d\n", displayIdx, pageIdx)
+
+    switch (displayIdx) {
+        case 0:
+            snprintf(outKey, outKeyLen, "Threshold Sign");
+            pageString(outVal, outValLen, message, pageIdx, pageCount);
+            return zxerr_ok;
+        default:
+            return zxerr_no_data;
+    }
+}
@@ -0,0 +1,68 @@
+/*******************************************************************************
+ *   (c) 2018 - 2023 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+
+#include "fvk.h"
+
+#include <stdio.h>
+
+#include "keys_def.h"
+#include "parser_common.h"
+#include "rslib.h"
+#include "zxformat.h"
+#include "zxmacros.h"
+
+static uint8_t recipient_fingerprint[FVK_RECIPIENT_FINGERPRINT_LEN] = {0};
+
+zxerr_t fvk_review_init(const uint8_t *host_pk) {
+    MEMZERO(recipient_fingerprint, sizeof(recipient_fingerprint));
+
+    if (host_pk == NULL ||
+        rs_fvk_recipient_fingerprint(host_pk, recipient_fingerprint, sizeof(recipient_fingerprint)) != parser_ok) {
+        return zxerr_unknown;
+    }
+
+    return zxerr_ok;
+}
+
+zxerr_t fvk_getNumItems(uint8_t *num_items) {
+    zemu_log_stack("fvk_getNumItems");
+    // what is exported and who can read it
+    *num_items = 2;
+    return zxerr_ok;
+}
+
+zxerr_t fvk_getItem(int8_t displayIdx, char *outKey, uint16_t outKeyLen, char *outVal, uint16_t outValLen,
+                    uint8_t pageIdx, uint8_t *pageCount) {
+    ZEMU_LOGF(50, "[fvk_getItem] %d/%d\n", displayIdx, pageIdx)
+
+    switch (displayIdx) {
+        case 0:
+            snprintf(outKey, outKeyLen, "Export");
+            pageString(outVal, outValLen, "Full Viewing Key", pageIdx, pageCount);
+            return zxerr_ok;
+
+        case 1: {
+            snprintf(outKey, outKeyLen, "Recipient");
+            char fingerprint_hex[2 * FVK_RECIPIENT_FINGERPRINT_LEN + 1] = {0};
+            array_to_hexstr(fingerprint_hex, sizeof(fingerprint_hex), recipient_fingerprint,
+                            sizeof(recipient_fingerprint));
+            pageString(outVal, outValLen, fingerprint_hex, pageIdx, pageCount);
+            return zxerr_ok;
+        }
+        default:
+            return zxerr_no_data;
+    }
+}
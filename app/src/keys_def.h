@@ -26,6 +26,11 @@ extern "C" {
 
 #define KEY_LEN 32
 #define FVK_LEN 64
+#define FVK_EXPORT_NONCE_LEN 12
+// ephemeral public key | nonce | encrypted fvk | authentication tag
+#define ENCRYPTED_FVK_LEN (KEY_LEN + FVK_EXPORT_NONCE_LEN + FVK_LEN + 16)
+#define ENCODED_FVK_RESPONSE_LEN (2 + 2 + FVK_LEN)
+#define FVK_RECIPIENT_FINGERPRINT_LEN 20
 #define DIVERSIFIER_KEY_LEN 16
 #define OUTGOING_VIEWING_KEY_LEN KEY_LEN
 #define NULLIFIER_KEY_LEN KEY_LEN            // Assuming decaf377 curve parameters
@@ -42,6 +47,8 @@ extern "C" {
 #define DIVERSIFIER_KEY_LEN 16
 
 #define ADDR_RANDOMIZER_LEN 12
+// account (4 bytes, little-endian) followed by the randomizer
+#define ADDR_INDEX_LEN 16
 
 #define ADDRESS_NUM_CHARS_SHORT_FORM 24
 #define NUM_CHARS_TO_DISPLAY 33
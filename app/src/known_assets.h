@@ -13,6 +13,8 @@
  *  See the License for the specific language governing permissions and
  *  limitations under the License.
  ********************************************************************************/
+#include <stdbool.h>
+
 #include "constants.h"
 #include "parser_common.h"
 
@@ -21,7 +23,8 @@
 extern "C" {
 #endif
 
-const asset_info_t *asset_info_from_table(const uint8_t asset_id[ASSET_ID_LEN]);
+// Fills asset_info from the asset registry, returning false for unlisted assets
+bool asset_info_from_table(const uint8_t asset_id[ASSET_ID_LEN], asset_info_t *asset_info);
 
 #ifdef __cplusplus
 }
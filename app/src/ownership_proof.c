@@ -0,0 +1,104 @@
+/*******************************************************************************
+ *   (c) 2018 - 2024 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+
+#include "ownership_proof.h"
+
+#include <stdio.h>
+
+#include "coin.h"
+#include "constants.h"
+#include "crypto.h"
+#include "parser_common.h"
+#include "ui_utils.h"
+#include "zxformat.h"
+#include "zxmacros.h"
+
+// The proof starts with the address it is about, kept here for display
+static uint8_t proven_address[ADDRESS_LEN_BYTES] = {0};
+static uint32_t proven_account = 0;
+static char challenge_text[OWNERSHIP_CHALLENGE_MAX_LEN + 1] = {0};
+
+zxerr_t ownership_proof_init(address_index_t address_index, const uint8_t *challenge, uint16_t challengeLen,
+                             uint8_t *output, uint16_t outputLen, uint16_t *written) {
+    MEMZERO(proven_address, sizeof(proven_address));
+    MEMZERO(challenge_text, sizeof(challenge_text));
+    proven_account = 0;
+
+    if (challenge == NULL || challengeLen == 0 || challengeLen > OWNERSHIP_CHALLENGE_MAX_LEN || output == NULL ||
+        written == NULL || outputLen < ADDRESS_LEN_BYTES) {
+        return zxerr_no_data;
+    }
+
+    // the challenge is shown in full, so printable ASCII alone
+    for (uint16_t i = 0; i < challengeLen; i++) {
+        if (challenge[i] < 0x20 || challenge[i] > 0x7E) {
+            return zxerr_invalid_crypto_settings;
+        }
+    }
+
+    zxerr_t err = crypto_proveAddressOwnership(address_index, challenge, challengeLen, output, outputLen, written);
+    if (err != zxerr_ok) {
+        return err;
+    }
+
+    MEMCPY(proven_address, output, ADDRESS_LEN_BYTES);
+    MEMCPY(challenge_text, challenge, challengeLen);
+    proven_account = address_index.account;
+
+    return zxerr_ok;
+}
+
+zxerr_t ownership_proof_getNumItems(uint8_t *num_items) {
+    zemu_log_stack("ownership_proof_getNumItems");
+    // address, account and challenge
+    *num_items = 3;
+    return zxerr_ok;
+}
+
+zxerr_t ownership_proof_getItem(int8_t displayIdx, char *outKey, uint16_t outKeyLen, char *outVal, uint16_t outValLen,
+                                uint8_t pageIdx, uint8_t *pageCount) {
+    ZEMU_LOGF(50, "[ownership_proof_getItem] %d/%d\n", displayIdx, pageIdx)
+
+    switch (displayIdx) {
+        case 0: {
+            char encoded_addr[ENCODED_ADDR_BUFFER_SIZE + 1] = {'\0'};
+            snprintf(outKey, outKeyLen, "Prove Ownership");
+            if (printShortAddress(proven_address, ADDRESS_LEN_BYTES, encoded_addr, ENCODED_ADDR_BUFFER_SIZE) !=
+                parser_ok) {
+                return zxerr_unknown;
+            }
+            pageString(outVal, outValLen, encoded_addr, pageIdx, pageCount);
+            return zxerr_ok;
+        }
+        case 1: {
+            snprintf(outKey, outKeyLen, "Address Index");
+            if (proven_account == 0) {
+                pageString(outVal, outValLen, "Main Account", pageIdx, pageCount);
+                return zxerr_ok;
+            }
+            char buffer[30] = {0};
+            snprintf(buffer, sizeof(buffer), "Sub-Account #%d", proven_account);
+            pageString(outVal, outValLen, buffer, pageIdx, pageCount);
+            return zxerr_ok;
+        }
+        case 2:
+            snprintf(outKey, outKeyLen, "Challenge");
+            pageString(outVal, outValLen, challenge_text, pageIdx, pageCount);
+            return zxerr_ok;
+        default:
+            return zxerr_no_data;
+    }
+}
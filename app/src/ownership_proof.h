@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *   (c) 2018 - 2024 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+#pragma once
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+#include <stdbool.h>
+#include <stdint.h>
+
+#include "keys_def.h"
+#include "zxerror.h"
+
+// Proves ownership of the address at address_index, keeping the challenge to show for review
+zxerr_t ownership_proof_init(address_index_t address_index, const uint8_t *challenge, uint16_t challengeLen,
+                             uint8_t *output, uint16_t outputLen, uint16_t *written);
+
+// Return the number of items in the ownership proof view
+zxerr_t ownership_proof_getNumItems(uint8_t *num_items);
+
+// Gets an specific item from the ownership proof view (including paging)
+zxerr_t ownership_proof_getItem(int8_t displayIdx, char *outKey, uint16_t outKeyLen, char *outValue,
+                                uint16_t outValueLen, uint8_t pageIdx, uint8_t *pageCount);
+
+#ifdef __cplusplus
+}
+#endif
@@ -28,6 +28,7 @@
 #include "crypto.h"
 #include "delegate.h"
 #include "delegator_vote.h"
+#include "flow_summary.h"
 #include "ics20_withdrawal.h"
 #include "memo.h"
 #include "output.h"
@@ -38,6 +39,7 @@
 #include "position_close.h"
 #include "position_open.h"
 #include "position_withdraw.h"
+#include "rslib.h"
 #include "spend.h"
 #include "swap.h"
 #include "tx_metadata.h"
@@ -67,6 +69,20 @@ parser_error_t parser_parse(parser_context_t *ctx, const uint8_t *data, size_t d
     return _read(ctx, tx_obj);
 }
 
+parser_error_t parser_parseAuthorizeRequest(parser_context_t *ctx, const uint8_t *data, size_t dataLen,
+                                           parser_tx_t *tx_obj) {
+    if (data == NULL || tx_obj == NULL || dataLen > UINT16_MAX) {
+        return parser_unexpected_error;
+    }
+
+    // Pre-authorizations are verified over the plan bytes before the plan itself is parsed
+    const bytes_t request = {.ptr = data, .len = (uint16_t)dataLen};
+    bytes_t plan = {0};
+    CHECK_ERROR(rs_parse_authorize_request(&request, &plan, &tx_obj->pre_authorizations))
+
+    return parser_parse(ctx, plan.ptr, plan.len, tx_obj);
+}
+
 parser_error_t parser_computeEffectHash(parser_context_t *ctx) {
 #if defined(LEDGER_SPECIFIC)
     io_seproxyhal_io_heartbeat();
@@ -82,6 +98,7 @@ parser_error_t parser_computeEffectHash(parser_context_t *ctx) {
         CHECK_ERROR(compute_action_hash(&ctx->tx_obj->actions_plan[i], &ctx->tx_obj->plan.memo.key,
                                         &ctx->tx_obj->plan.actions.hashes[i]));
     }
+    CHECK_ERROR(compute_flow_summary(ctx->tx_obj, &ctx->tx_obj->flow_summary));
 
 #if defined(LEDGER_SPECIFIC)
     io_seproxyhal_io_heartbeat();
@@ -96,7 +113,32 @@ parser_error_t parser_computeEffectHash(parser_context_t *ctx) {
     return parser_ok;
 }
 
+parser_error_t parser_checkBalance(parser_context_t *ctx) {
+    if (ctx == NULL || ctx->tx_obj == NULL) {
+        return parser_unexpected_error;
+    }
+    return check_plan_balance(ctx->tx_obj);
+}
+
 parser_error_t parser_validate(parser_context_t *ctx) {
+    // Delegator votes are checked against the staked note, and (un)delegations against
+    // the validator's rate data, before they are shown
+    for (uint16_t i = 0; i < ctx->tx_obj->plan.actions.qty; i++) {
+        switch (ctx->tx_obj->actions_plan[i].action_type) {
+            case penumbra_core_transaction_v1_ActionPlan_delegator_vote_tag:
+                CHECK_ERROR(delegator_vote_validate(ctx, &ctx->tx_obj->actions_plan[i].action.delegator_vote));
+                break;
+            case penumbra_core_transaction_v1_ActionPlan_delegate_tag:
+                CHECK_ERROR(delegate_validate(ctx, &ctx->tx_obj->actions_plan[i].action.delegate));
+                break;
+            case penumbra_core_transaction_v1_ActionPlan_undelegate_tag:
+                CHECK_ERROR(undelegate_validate(ctx, &ctx->tx_obj->actions_plan[i].action.undelegate));
+                break;
+            default:
+                break;
+        }
+    }
+
     // Iterate through all items to check that all can be shown and are valid
     uint8_t numItems = 0;
     CHECK_ERROR(parser_getNumItems(ctx, &numItems))
@@ -122,9 +164,17 @@ parser_error_t parser_getNumItems(const parser_context_t *ctx, uint8_t *num_item
     CHECK_ERROR(memo_getNumItems(ctx, &memo_num_items))
     *num_items += memo_num_items;
 
+    // the summary of what leaves and enters the wallet goes before the actions
+    uint8_t summary_num_items = 0;
+    CHECK_ERROR(flow_summary_getNumItems(ctx, &summary_num_items))
+    *num_items += summary_num_items;
+
     // Add one item for each action
     *num_items += ctx->tx_obj->plan.actions.qty;
 
+    // and one for each co-signer that pre-authorized the plan
+    *num_items += ctx->tx_obj->pre_authorizations.qty;
+
     if (*num_items == 0) {
         return parser_unexpected_number_items;
     }
@@ -172,11 +222,35 @@ parser_error_t parser_getItem(const parser_context_t *ctx, uint8_t displayIdx, c
                                  pageCount))
     }
 
+    // Print flow summary
+    uint8_t summary_num_items = 0;
+    CHECK_ERROR(flow_summary_getNumItems(ctx, &summary_num_items))
+    const uint8_t actions_start = parameters_num_items + memo_num_items + summary_num_items;
+    if (displayIdx >= parameters_num_items + memo_num_items && displayIdx < actions_start) {
+        return flow_summary_getItem(ctx, displayIdx - parameters_num_items - memo_num_items, outKey, outKeyLen, outVal,
+                                    outValLen, pageIdx, pageCount);
+    }
+
+    // Print pre-authorizations
+    const uint8_t actions_end = actions_start + ctx->tx_obj->plan.actions.qty;
+    if (displayIdx >= actions_end) {
+        const uint8_t pre_authorization_idx = displayIdx - actions_end;
+        if (pre_authorization_idx >= ctx->tx_obj->pre_authorizations.qty) {
+            return parser_unexpected_error;
+        }
+        char vk_hex[2 * ED25519_VK_LEN + 1] = {0};
+        array_to_hexstr(vk_hex, sizeof(vk_hex), ctx->tx_obj->pre_authorizations.vks[pre_authorization_idx],
+                        ED25519_VK_LEN);
+        snprintf(outKey, outKeyLen, "Pre-authorized by");
+        pageString(outVal, outValLen, vk_hex, pageIdx, pageCount);
+        return parser_ok;
+    }
+
     // Print actions
-    if (displayIdx >= parameters_num_items + memo_num_items) {
+    if (displayIdx >= actions_start) {
         // Increment action_idx only if displayIdx change
         if (displayIdx != action_idx) {
-            action_idx = displayIdx - parameters_num_items - memo_num_items;
+            action_idx = displayIdx - actions_start;
         }
         if (action_idx >= ctx->tx_obj->plan.actions.qty) {
             return parser_unexpected_error;
@@ -256,6 +330,6 @@ parser_error_t parser_getItem(const parser_context_t *ctx, uint8_t displayIdx, c
     return parser_ok;
 }
 
-parser_error_t parser_parseTxMetadata(parser_context_t *ctx, const uint8_t *data, size_t dataLen) {
-    return metadata_parse(ctx, data, dataLen);
+parser_error_t parser_parseTxMetadata(parser_context_t *ctx, const uint8_t *data, size_t dataLen, uint8_t format) {
+    return metadata_parse(ctx, data, dataLen, format);
 }
@@ -444,6 +444,28 @@ const char *parser_getErrorDescription(parser_error_t err) {
             return "Action decode error";
         case parser_clue_plan_decode_error:
             return "Clue plan decode error";
+        case parser_division_by_zero:
+            return "Division by zero";
+        case parser_invalid_trading_pair:
+            return "Invalid trading pair";
+        case parser_invalid_exchange_rate:
+            return "Invalid exchange rate";
+        case parser_unspecified_vote:
+            return "Unspecified vote";
+        case parser_staked_note_not_delegation_token:
+            return "Staked note is not a delegation token";
+        case parser_staked_note_not_owned:
+            return "Staked note not owned";
+        case parser_zero_unbonded_amount:
+            return "Zero unbonded amount";
+        case parser_unbalanced_plan:
+            return "Plan does not balance";
+        case parser_binding_signature_mismatch:
+            return "Binding signature mismatch";
+        case parser_position_metadata_unsupported:
+            return "Position metadata unsupported";
+        case parser_invalid_dutch_auction:
+            return "Invalid Dutch auction";
 
         default:
             return "Unrecognized error code";
@@ -20,6 +20,7 @@
 #include "keys_def.h"
 #include "protobuf/penumbra/core/transaction/v1/transaction.pb.h"
 #include "rslib.h"
+#include "zxmacros.h"
 
 parser_error_t compute_effect_hash(transaction_plan_t *plan, uint8_t *effect_hash, uint16_t effect_hash_len) {
     if (plan == NULL || effect_hash == NULL) return parser_unexpected_error;
@@ -60,13 +61,13 @@ parser_error_t compute_action_hash(action_t *action, bytes_t *memo_key, hash_t *
             break;
 #endif
         case penumbra_core_transaction_v1_ActionPlan_ics20_withdrawal_tag:
+            err = rs_generic_action_hash(&action->action_data, action->action_type, (uint8_t *)output, 64);
+            break;
         case penumbra_core_transaction_v1_ActionPlan_delegate_tag:
+            err = rs_delegate_action_hash(&action->action.delegate, (uint8_t *)output, 64);
+            break;
         case penumbra_core_transaction_v1_ActionPlan_undelegate_tag:
-        case penumbra_core_transaction_v1_ActionPlan_position_open_plan_tag:
-        case penumbra_core_transaction_v1_ActionPlan_position_close_tag:
-        case penumbra_core_transaction_v1_ActionPlan_action_dutch_auction_schedule_tag:
-        case penumbra_core_transaction_v1_ActionPlan_action_dutch_auction_end_tag:
-            err = rs_generic_action_hash(&action->action_data, action->action_type, (uint8_t *)output, 64);
+            err = rs_undelegate_action_hash(&action->action.undelegate, (uint8_t *)output, 64);
             break;
         case penumbra_core_transaction_v1_ActionPlan_undelegate_claim_tag:
             err = rs_undelegate_claim_action_hash(&action->action.undelegate_claim, (uint8_t *)output, 64);
@@ -74,9 +75,23 @@ parser_error_t compute_action_hash(action_t *action, bytes_t *memo_key, hash_t *
         case penumbra_core_transaction_v1_ActionPlan_delegator_vote_tag:
             err = rs_delegator_vote_action_hash(&action->action.delegator_vote, (uint8_t *)output, 64);
             break;
+        case penumbra_core_transaction_v1_ActionPlan_position_open_plan_tag:
+            err = rs_position_open_action_hash(&action->action.position_open, (uint8_t *)output, 64);
+            break;
+        case penumbra_core_transaction_v1_ActionPlan_position_close_tag:
+            err = rs_position_close_action_hash(&action->action.position_close, (uint8_t *)output, 64);
+            break;
         case penumbra_core_transaction_v1_ActionPlan_position_withdraw_tag:
             err = rs_position_withdraw_action_hash(&action->action.position_withdraw, (uint8_t *)output, 64);
             break;
+        case penumbra_core_transaction_v1_ActionPlan_action_dutch_auction_schedule_tag:
+            err = rs_action_dutch_auction_schedule_action_hash(&action->action.action_dutch_auction_schedule,
+                                                               (uint8_t *)output, 64);
+            break;
+        case penumbra_core_transaction_v1_ActionPlan_action_dutch_auction_end_tag:
+            err = rs_action_dutch_auction_end_action_hash(&action->action.action_dutch_auction_end, (uint8_t *)output,
+                                                          64);
+            break;
         case penumbra_core_transaction_v1_ActionPlan_action_dutch_auction_withdraw_tag:
             err = rs_action_dutch_auction_withdraw_action_hash(&action->action.action_dutch_auction_withdraw,
                                                                (uint8_t *)output, 64);
@@ -87,3 +102,43 @@ parser_error_t compute_action_hash(action_t *action, bytes_t *memo_key, hash_t *
 
     return err;
 }
+
+parser_error_t compute_flow_summary(const parser_tx_t *tx_obj, flow_summary_t *summary) {
+    if (tx_obj == NULL || summary == NULL) return parser_unexpected_error;
+
+    // Assets that do not fit mark the summary incomplete instead of failing
+    MEMZERO(summary, sizeof(flow_summary_t));
+    for (uint16_t i = 0; i < tx_obj->plan.actions.qty; i++) {
+        const action_t *action = &tx_obj->actions_plan[i];
+        CHECK_ERROR(rs_flow_summary_add_action(action->action_type, &action->action, summary));
+    }
+
+    return rs_flow_summary_add_fee(&tx_obj->parameters_plan.fee, summary);
+}
+
+parser_error_t check_plan_balance(const parser_tx_t *tx_obj) {
+    if (tx_obj == NULL) return parser_unexpected_error;
+
+    balance_t plan_balance = {0};
+    for (uint16_t i = 0; i < tx_obj->plan.actions.qty; i++) {
+        const action_t *action = &tx_obj->actions_plan[i];
+        CHECK_ERROR(rs_plan_balance_add(action->action_type, &action->action, &plan_balance));
+    }
+
+    return rs_plan_balance_check(&plan_balance, &tx_obj->parameters_plan.fee);
+}
+
+parser_error_t compute_binding_signature(const parser_tx_t *tx_obj, uint8_t *signature, uint16_t len) {
+    if (tx_obj == NULL || signature == NULL) return parser_unexpected_error;
+
+    binding_t binding = {0};
+    for (uint16_t i = 0; i < tx_obj->plan.actions.qty; i++) {
+        const action_t *action = &tx_obj->actions_plan[i];
+        CHECK_ERROR(rs_binding_add_action(action->action_type, &action->action, &binding));
+    }
+
+    bytes_t effect_hash = {.ptr = tx_obj->effect_hash, .len = sizeof(tx_obj->effect_hash)};
+    parser_error_t err = rs_sign_binding(&effect_hash, &binding, &tx_obj->parameters_plan.fee, signature, len);
+    MEMZERO(&binding, sizeof(binding));
+    return err;
+}
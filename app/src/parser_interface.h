@@ -29,6 +29,9 @@ extern "C" {
 parser_error_t compute_parameters_hash(bytes_t *parameters_bytes, hash_t *output);
 parser_error_t compute_effect_hash(transaction_plan_t *plan, uint8_t *effect_hash, uint16_t effect_hash_len);
 parser_error_t compute_action_hash(action_t *action, bytes_t *memo_key, hash_t *output);
+parser_error_t compute_flow_summary(const parser_tx_t *tx_obj, flow_summary_t *summary);
+parser_error_t check_plan_balance(const parser_tx_t *tx_obj);
+parser_error_t compute_binding_signature(const parser_tx_t *tx_obj, uint8_t *signature, uint16_t len);
 
 #ifdef __cplusplus
 }
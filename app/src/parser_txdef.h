@@ -32,14 +32,19 @@ extern "C" {
 
 #define DETECTION_DATA_QTY 16
 #define ACTIONS_QTY 16
+#define FLOW_ASSETS_QTY 16
+#define BALANCE_ASSETS_QTY 16
+#define PRE_AUTHORIZATIONS_QTY 4
 #define MAX_CALLBACK_ARRAY_SIZE 5
 
 #define ASSET_ID_LEN 32
 #define RSEED_LEN 32
 #define CHAIN_ID_LEN 32
+#define BLINDING_LEN 32
+#define BALANCE_COMMITMENT_LEN 32
+#define ED25519_VK_LEN 32
 
 #define MAX_SYMBOL_LEN 80
-#define MAX_ASSET_NAME_LEN 120
 
 typedef enum { VOTE_UNSPECIFIED = 0, VOTE_ABSTAIN = 1, VOTE_YES = 2, VOTE_NO = 3 } governance_vote_e;
 
@@ -179,6 +184,9 @@ typedef struct {
 typedef struct {
     bool has_phi;
     trading_function_t phi;
+    bytes_t nonce;
+    bool has_state;
+    position_state_t state;
     bool has_reserves;
     reserves_t reserves;
     bool close_on_fill;
@@ -200,6 +208,7 @@ typedef struct {
     uint64_t start_height;
     uint64_t end_height;
     uint64_t step_count;
+    bytes_t nonce;
 } dutch_auction_description_t;
 
 typedef struct {
@@ -231,8 +240,11 @@ typedef struct {
 typedef struct {
     bool has_validator_identity;
     identity_key_t validator_identity;
+    uint64_t epoch_index;
     bool has_unbonded_amount;
     amount_t unbonded_amount;
+    bool has_delegation_amount;
+    amount_t delegation_amount;
 } delegate_plan_t;
 
 typedef struct {
@@ -282,6 +294,7 @@ typedef struct {
 typedef struct {
     bool has_position;
     position_t position;
+    bytes_t encrypted_metadata;
 } position_open_plan_t;
 
 typedef struct {
@@ -387,29 +400,80 @@ typedef struct {
     detection_data_t detection_data;
 } transaction_plan_t;
 
+typedef struct {
+    uint8_t asset_id[ASSET_ID_LEN];
+    amount_t amount;
+    bool provided;
+} imbalance_t;
+
+typedef struct {
+    uint8_t qty;
+    imbalance_t imbalances[BALANCE_ASSETS_QTY];
+} balance_t;
+
+// Running sums of the synthetic blinding factors and balance commitments
+// of a plan's actions. All zeros is the empty sum.
+typedef struct {
+    uint8_t blinding[BLINDING_LEN];
+    uint8_t commitment[BALANCE_COMMITMENT_LEN];
+} binding_t;
+
+typedef struct {
+    uint8_t asset_id[ASSET_ID_LEN];
+    amount_t sent;
+    amount_t received;
+    amount_t fee;
+} asset_flow_t;
+
+typedef struct {
+    uint8_t qty;
+    // Set when the summary could not account for every asset
+    bool incomplete;
+    asset_flow_t flows[FLOW_ASSETS_QTY];
+} flow_summary_t;
+
+// Keys of the co-signers whose pre-authorizations of the plan were verified
+typedef struct {
+    uint8_t qty;
+    uint8_t vks[PRE_AUTHORIZATIONS_QTY][ED25519_VK_LEN];
+} pre_authorizations_t;
+
 typedef struct {
     transaction_plan_t plan;
     action_t actions_plan[ACTIONS_QTY];
     parameters_t parameters_plan;
+    flow_summary_t flow_summary;
+    pre_authorizations_t pre_authorizations;
     uint8_t effect_hash[64];
 } parser_tx_t;
 
 typedef struct {
-    uint8_t asset_id[ASSET_ID_LEN];
-    const char symbol[MAX_SYMBOL_LEN];
-    const char name[MAX_ASSET_NAME_LEN];
-    uint16_t decimals;
+    char symbol[MAX_SYMBOL_LEN];
+    uint8_t decimals;
 } asset_info_t;
 
 // This struct defines
 // the metadata used to handle assets
 // that are not listed in our internal table
-// but that the user provide when signing a transaction
+// but that the user provide when signing a transaction.
+// It is filled by rs_parse_denom_metadata, keep in sync with DenomMetadataC
 typedef struct {
     char denom[MAX_DENOM_LEN];
     uint8_t len;
+    char display[MAX_DENOM_LEN];
+    uint8_t display_len;
+    uint8_t display_exponent;
+    uint8_t asset_id[ASSET_ID_LEN];
 } tx_metadata_t;
 
+// The exchange rate of a validator's delegation tokens, provided by the host
+// to check delegations and undelegations against.
+// It is filled by rs_parse_rate_data, keep in sync with RateDataC
+typedef struct {
+    uint8_t validator_identity[IDENTITY_KEY_LEN];
+    amount_t exchange_rate;
+} rate_data_t;
+
 #ifdef __cplusplus
 }
 #endif
@@ -90,6 +90,10 @@ parser_error_t action_dutch_auction_end_printValue(const parser_context_t *ctx,
     CHECK_ERROR(encodeAuctionId(action_dutch_auction_end->auction_id.inner.ptr,
                                 action_dutch_auction_end->auction_id.inner.len, outVal + written_value,
                                 outValLen - written_value));
+    written_value = strlen(outVal);
+
+    // ending burns the opened auction NFT and mints the closed one
+    snprintf(outVal + written_value, outValLen - written_value, " Auction NFT: opened -> closed");
 
     return parser_ok;
 }
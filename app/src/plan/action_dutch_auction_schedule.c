@@ -17,6 +17,7 @@
 
 #include "note.h"
 #include "parser_pb_utils.h"
+#include "rslib.h"
 #include "ui_utils.h"
 #include "zxformat.h"
 
@@ -29,12 +30,14 @@ parser_error_t decode_action_dutch_auction_schedule_plan(
     CHECK_APP_CANARY()
 
     // Set up fixed size fields
-    fixed_size_field_t input_asset_id_arg, output_asset_id_arg;
+    fixed_size_field_t input_asset_id_arg, output_asset_id_arg, nonce_arg;
 
     setup_decode_fixed_field(&action_dutch_auction_schedule_pb.description.input.asset_id.inner, &input_asset_id_arg,
                              &action_dutch_auction_schedule->description.input.asset_id.inner, ASSET_ID_LEN);
     setup_decode_fixed_field(&action_dutch_auction_schedule_pb.description.output_id.inner, &output_asset_id_arg,
                              &action_dutch_auction_schedule->description.output_id.inner, ASSET_ID_LEN);
+    setup_decode_fixed_field(&action_dutch_auction_schedule_pb.description.nonce, &nonce_arg,
+                             &action_dutch_auction_schedule->description.nonce, DUTCH_AUCTION_NONCE_LEN);
 
     if (!pb_decode(&stream, penumbra_core_component_auction_v1_ActionDutchAuctionSchedule_fields,
                    &action_dutch_auction_schedule_pb)) {
@@ -122,9 +125,17 @@ parser_error_t action_dutch_auction_schedule_printValue(
     MEMZERO(outVal, outValLen);
 
     // add action title
-    snprintf(outVal, outValLen, "DutchAuctionSchedule Selling: ");
+    snprintf(outVal, outValLen, "DutchAuctionSchedule Auction ID: ");
     uint16_t written_value = strlen(outVal);
 
+    // add auction id
+    CHECK_ERROR(rs_action_dutch_auction_schedule_auction_id(
+        action_dutch_auction_schedule, (uint8_t *)outVal + written_value, outValLen - written_value));
+    written_value = strlen(outVal);
+
+    snprintf(outVal + written_value, outValLen - written_value, " Selling: ");
+    written_value = strlen(outVal);
+
     // Selling value
     CHECK_ERROR(printValue(ctx, &action_dutch_auction_schedule->description.input,
                            &ctx->tx_obj->parameters_plan.chain_id, true, outVal + written_value,
@@ -186,6 +197,14 @@ parser_error_t action_dutch_auction_schedule_printValue(
                            outValLen - written_value));
     written_value = strlen(outVal);
 
+    // Worst price, offered at the last step of the auction
+    snprintf(outVal + written_value, outValLen - written_value, " Worst unit price: ");
+    written_value = strlen(outVal);
+    CHECK_ERROR(rs_action_dutch_auction_schedule_price(action_dutch_auction_schedule,
+                                                       action_dutch_auction_schedule->description.step_count - 1,
+                                                       (uint8_t *)outVal + written_value, outValLen - written_value));
+    written_value = strlen(outVal);
+
     // Start block height
     snprintf(outVal + written_value, outValLen - written_value, " Start block height: ");
     written_value = strlen(outVal);
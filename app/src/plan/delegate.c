@@ -19,6 +19,7 @@
 #include "note.h"
 #include "parser_pb_utils.h"
 #include "rslib.h"
+#include "tx_metadata.h"
 #include "ui_utils.h"
 #include "zxformat.h"
 
@@ -38,14 +39,37 @@ parser_error_t decode_delegate_plan(const bytes_t *data, delegate_plan_t *delega
     }
 
     delegate->has_validator_identity = delegate_plan.has_validator_identity;
+    delegate->epoch_index = delegate_plan.epoch_index;
+    delegate->has_unbonded_amount = delegate_plan.has_unbonded_amount;
     if (delegate_plan.has_unbonded_amount) {
         delegate->unbonded_amount.lo = delegate_plan.unbonded_amount.lo;
         delegate->unbonded_amount.hi = delegate_plan.unbonded_amount.hi;
     }
+    delegate->has_delegation_amount = delegate_plan.has_delegation_amount;
+    if (delegate_plan.has_delegation_amount) {
+        delegate->delegation_amount.lo = delegate_plan.delegation_amount.lo;
+        delegate->delegation_amount.hi = delegate_plan.delegation_amount.hi;
+    }
 
     return parser_ok;
 }
 
+parser_error_t delegate_validate(const parser_context_t *ctx, const delegate_plan_t *delegate) {
+    if (ctx == NULL || delegate == NULL) {
+        return parser_no_data;
+    }
+
+    // The validator's exchange rate is only known through the transaction metadata,
+    // so the delegation tokens minted can only be checked when the host provides it
+    const rate_data_t *rate_data =
+        metadata_getRateData(ctx->rate_data, ctx->rate_data_len, &delegate->validator_identity);
+    if (rate_data == NULL) {
+        return parser_ok;
+    }
+
+    return rs_delegate_check_rate(delegate, rate_data);
+}
+
 parser_error_t delegate_getNumItems(const parser_context_t *ctx, uint8_t *num_items) {
     UNUSED(ctx);
     *num_items = 1;
@@ -105,6 +129,21 @@ parser_error_t delegate_printValue(const parser_context_t *ctx, const delegate_p
     local_value.has_asset_id = true;
     CHECK_ERROR(printValue(ctx, &local_value, &ctx->tx_obj->parameters_plan.chain_id, true, outVal + written_value,
                            outValLen - written_value));
+    written_value = strlen(outVal);
+
+    // add "Output"
+    snprintf(outVal + written_value, outValLen - written_value, " Output ");
+    written_value = strlen(outVal);
+
+    // add delegation amount
+    uint8_t delegation_asset_id[ASSET_ID_LEN] = {0};
+    CHECK_ERROR(rs_delegation_token_asset_id(&delegate->validator_identity, delegation_asset_id, ASSET_ID_LEN));
+    value_t delegation_value = {.amount = delegate->delegation_amount,
+                                .asset_id.inner = {.ptr = delegation_asset_id, .len = ASSET_ID_LEN},
+                                .has_amount = true,
+                                .has_asset_id = true};
+    CHECK_ERROR(printValue(ctx, &delegation_value, &ctx->tx_obj->parameters_plan.chain_id, true,
+                           outVal + written_value, outValLen - written_value));
 
     return parser_ok;
 }
\ No newline at end of file
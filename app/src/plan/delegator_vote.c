@@ -16,9 +16,11 @@
 
 #include "delegator_vote.h"
 
+#include "constants.h"
 #include "note.h"
 #include "parser_pb_utils.h"
 #include "rslib.h"
+#include "tx_metadata.h"
 #include "ui_utils.h"
 #include "zxformat.h"
 
@@ -69,7 +71,7 @@ parser_error_t decode_delegator_vote_plan(const bytes_t *data, delegator_vote_pl
     if (delegator_vote_plan.has_vote) {
         delegator_vote->vote = delegator_vote_plan.vote.vote;
         if (delegator_vote_plan.vote.vote == VOTE_UNSPECIFIED) {
-            return parser_unexpected_error;
+            return parser_unspecified_vote;
         }
     }
     delegator_vote->has_staked_note = delegator_vote_plan.has_staked_note;
@@ -97,6 +99,24 @@ parser_error_t decode_delegator_vote_plan(const bytes_t *data, delegator_vote_pl
     return parser_ok;
 }
 
+parser_error_t delegator_vote_validate(const parser_context_t *ctx, delegator_vote_plan_t *delegator_vote) {
+    if (ctx == NULL || delegator_vote == NULL) {
+        return parser_no_data;
+    }
+
+    // The staked note's denom is only known through the transaction metadata, so
+    // the delegation token check is skipped when the host does not provide it
+    char denom[MAX_DENOM_LEN + 1] = {0};
+    uint8_t denom_len = 0;
+    const bytes_t *asset_id = &delegator_vote->staked_note.value.asset_id.inner;
+    if (asset_id->ptr != NULL && asset_id->len != 0) {
+        denom_len = metadata_getDenom(&ctx->tx_metadata[0], ctx->tx_metadata_len, asset_id, denom, MAX_DENOM_LEN + 1);
+    }
+
+    bytes_t staked_denom = {.ptr = (const uint8_t *)denom, .len = denom_len};
+    return rs_delegator_vote_validate(delegator_vote, &staked_denom);
+}
+
 parser_error_t delegator_vote_getNumItems(const parser_context_t *ctx, uint8_t *num_items) {
     UNUSED(ctx);
     *num_items = 1;
@@ -24,6 +24,7 @@ extern "C" {
 #endif
 
 parser_error_t decode_delegator_vote_plan(const bytes_t *data, delegator_vote_plan_t *delegator_vote);
+parser_error_t delegator_vote_validate(const parser_context_t *ctx, delegator_vote_plan_t *delegator_vote);
 parser_error_t delegator_vote_getNumItems(const parser_context_t *ctx, uint8_t *num_items);
 parser_error_t delegator_vote_getItem(const parser_context_t *ctx, const delegator_vote_plan_t *delegator_vote,
                                       uint8_t actionIdx, char *outKey, uint16_t outKeyLen, char *outVal,
@@ -17,6 +17,7 @@
 
 #include "note.h"
 #include "parser_pb_utils.h"
+#include "rslib.h"
 #include "ui_utils.h"
 #include "zxformat.h"
 
@@ -28,11 +29,16 @@ parser_error_t decode_position_open_plan(const bytes_t *data, position_open_plan
     CHECK_APP_CANARY()
 
     // Set up fixed size fields
-    fixed_size_field_t pair_asset_1, pair_asset_2;
+    fixed_size_field_t pair_asset_1, pair_asset_2, nonce;
+    variable_size_field_t encrypted_metadata;
     setup_decode_fixed_field(&position_open_pb.position.phi.pair.asset_1.inner, &pair_asset_1,
                              &position_open->position.phi.pair.asset_1.inner, ASSET_ID_LEN);
     setup_decode_fixed_field(&position_open_pb.position.phi.pair.asset_2.inner, &pair_asset_2,
                              &position_open->position.phi.pair.asset_2.inner, ASSET_ID_LEN);
+    setup_decode_fixed_field(&position_open_pb.position.nonce, &nonce, &position_open->position.nonce,
+                             POSITION_NONCE_LEN);
+    setup_decode_variable_field(&position_open_pb.encrypted_metadata, &encrypted_metadata,
+                                &position_open->encrypted_metadata);
 
     if (!pb_decode(&stream, penumbra_core_component_dex_v1_PositionOpen_fields, &position_open_pb)) {
         return parser_position_open_plan_error;
@@ -60,9 +66,16 @@ parser_error_t decode_position_open_plan(const bytes_t *data, position_open_plan
             if (position_open_pb.position.phi.has_pair) {
                 position_open->position.phi.pair.has_asset_1 = position_open_pb.position.phi.pair.has_asset_1;
                 position_open->position.phi.pair.has_asset_2 = position_open_pb.position.phi.pair.has_asset_2;
+                CHECK_ERROR(rs_trading_pair_validate(&position_open->position.phi.pair))
             }
         }
 
+        position_open->position.has_state = position_open_pb.position.has_state;
+        if (position_open_pb.position.has_state) {
+            position_open->position.state.state = (position_state_enum_t)position_open_pb.position.state.state;
+            position_open->position.state.sequence = position_open_pb.position.state.sequence;
+        }
+
         position_open->position.has_reserves = position_open_pb.position.has_reserves;
         if (position_open_pb.position.has_reserves) {
             position_open->position.reserves.has_r1 = position_open_pb.position.reserves.has_r1;
@@ -119,9 +132,17 @@ parser_error_t position_open_printValue(const parser_context_t *ctx, const posit
     MEMZERO(outVal, outValLen);
 
     // add action title
-    snprintf(outVal, outValLen, "PositionOpen Reserves 1: ");
+    snprintf(outVal, outValLen, "PositionOpen Position ID ");
     uint16_t written_value = strlen(outVal);
 
+    // add the ID of the position being created
+    CHECK_ERROR(
+        rs_position_open_position_id(position_open, (uint8_t *)outVal + written_value, outValLen - written_value))
+    written_value = strlen(outVal);
+
+    snprintf(outVal + written_value, outValLen - written_value, " Reserves 1: ");
+    written_value = strlen(outVal);
+
     // add value r1
     value_t r1_amount = {
         .amount = position_open->position.reserves.r1,
@@ -170,11 +191,28 @@ parser_error_t position_open_printValue(const parser_context_t *ctx, const posit
     snprintf(outVal + written_value, outValLen - written_value, "%s", value_q_str);
     written_value = strlen(outVal);
 
+    // add implied prices in both directions
+    snprintf(outVal + written_value, outValLen - written_value, " Price 2 per 1: ");
+    written_value = strlen(outVal);
+    char price_str[PRICE_STR_MAX_LEN] = {0};
+    CHECK_ERROR(rs_position_open_implied_price(position_open, false, (uint8_t *)price_str, sizeof(price_str)))
+    snprintf(outVal + written_value, outValLen - written_value, "%s", price_str);
+    written_value = strlen(outVal);
+
+    snprintf(outVal + written_value, outValLen - written_value, " Price 1 per 2: ");
+    written_value = strlen(outVal);
+    MEMZERO(price_str, sizeof(price_str));
+    CHECK_ERROR(rs_position_open_implied_price(position_open, true, (uint8_t *)price_str, sizeof(price_str)))
+    snprintf(outVal + written_value, outValLen - written_value, "%s", price_str);
+    written_value = strlen(outVal);
+
     // add "Fee: "
     snprintf(outVal + written_value, outValLen - written_value, " Fee: ");
     written_value = strlen(outVal);
     uint32_to_str(outVal + written_value, outValLen - written_value, position_open->position.phi.component.fee);
     written_value = strlen(outVal);
+    snprintf(outVal + written_value, outValLen - written_value, " bps");
+    written_value = strlen(outVal);
 
     // add close_on_fill
     if (position_open->position.close_on_fill) {
@@ -17,6 +17,7 @@
 
 #include "note.h"
 #include "parser_pb_utils.h"
+#include "rslib.h"
 #include "ui_utils.h"
 #include "zxformat.h"
 
@@ -85,6 +86,7 @@ parser_error_t decode_position_withdraw_plan(const bytes_t *data, position_withd
     if (position_withdraw->has_pair) {
         position_withdraw->pair.has_asset_1 = position_withdraw_pb.pair.has_asset_1;
         position_withdraw->pair.has_asset_2 = position_withdraw_pb.pair.has_asset_2;
+        CHECK_ERROR(rs_trading_pair_validate(&position_withdraw->pair))
     }
     position_withdraw->sequence = position_withdraw_pb.sequence;
 
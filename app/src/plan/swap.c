@@ -18,6 +18,7 @@
 
 #include "note.h"
 #include "parser_pb_utils.h"
+#include "rslib.h"
 #include "ui_utils.h"
 #include "zxformat.h"
 
@@ -54,6 +55,7 @@ parser_error_t decode_swap_plan(const bytes_t *data, swap_plan_t *swap) {
     if (swap->swap_plaintext.has_trading_pair) {
         swap->swap_plaintext.trading_pair.has_asset_1 = swap_plan.swap_plaintext.trading_pair.has_asset_1;
         swap->swap_plaintext.trading_pair.has_asset_2 = swap_plan.swap_plaintext.trading_pair.has_asset_2;
+        CHECK_ERROR(rs_trading_pair_validate(&swap->swap_plaintext.trading_pair))
     }
 
     swap->swap_plaintext.has_delta_1_i = swap_plan.swap_plaintext.has_delta_1_i;
@@ -132,29 +134,25 @@ parser_error_t swap_printValue(const parser_context_t *ctx, const swap_plan_t *s
     snprintf(outVal, outValLen, "Swap ");
     uint16_t written_value = strlen(outVal);
 
-    // add value
+    // add value, oriented as "sell input for output"
+    // decode_swap_plan refuses two-sided swaps, so exactly one asset is sold
+    bool sells_asset_1 = false;
+    bool sells_asset_2 = false;
+    CHECK_ERROR(rs_swap_direction(swap, &sells_asset_1, &sells_asset_2))
+
+    const trading_pair_t *pair = &swap->swap_plaintext.trading_pair;
     value_t output_value = {0};
     value_t input_value = {0};
-    if (!is_delta_empty(&swap->swap_plaintext.delta_1_i, swap->swap_plaintext.has_delta_1_i)) {
+    if (sells_asset_1) {
         input_value.amount.hi = swap->swap_plaintext.delta_1_i.hi;
         input_value.amount.lo = swap->swap_plaintext.delta_1_i.lo;
-        input_value.asset_id.inner.ptr = swap->swap_plaintext.trading_pair.asset_1.inner.ptr;
-        input_value.asset_id.inner.len = swap->swap_plaintext.trading_pair.asset_1.inner.len;
-
-        output_value.amount.hi = 0;
-        output_value.amount.lo = 0;
-        output_value.asset_id.inner.ptr = swap->swap_plaintext.trading_pair.asset_2.inner.ptr;
-        output_value.asset_id.inner.len = swap->swap_plaintext.trading_pair.asset_2.inner.len;
+        input_value.asset_id.inner = pair->asset_1.inner;
+        output_value.asset_id.inner = pair->asset_2.inner;
     } else {
         input_value.amount.hi = swap->swap_plaintext.delta_2_i.hi;
         input_value.amount.lo = swap->swap_plaintext.delta_2_i.lo;
-        input_value.asset_id.inner.ptr = swap->swap_plaintext.trading_pair.asset_2.inner.ptr;
-        input_value.asset_id.inner.len = swap->swap_plaintext.trading_pair.asset_2.inner.len;
-
-        output_value.amount.hi = 0;
-        output_value.amount.lo = 0;
-        output_value.asset_id.inner.ptr = swap->swap_plaintext.trading_pair.asset_1.inner.ptr;
-        output_value.asset_id.inner.len = swap->swap_plaintext.trading_pair.asset_1.inner.len;
+        input_value.asset_id.inner = pair->asset_2.inner;
+        output_value.asset_id.inner = pair->asset_1.inner;
     }
     input_value.has_asset_id = true;
     input_value.has_amount = true;
@@ -172,8 +170,8 @@ parser_error_t swap_printValue(const parser_context_t *ctx, const swap_plan_t *s
     snprintf(outVal + written_value, outValLen - written_value, " Output Asset ");
     written_value = strlen(outVal);
 
-    CHECK_ERROR(printAssetIdFromValue(ctx, &input_value, &ctx->tx_obj->parameters_plan.chain_id, outVal + written_value,
-                                      outValLen - written_value));
+    CHECK_ERROR(printAssetIdFromValue(ctx, &output_value, &ctx->tx_obj->parameters_plan.chain_id,
+                                      outVal + written_value, outValLen - written_value));
     written_value = strlen(outVal);
 
     snprintf(outVal + written_value, outValLen - written_value, " Claim Fee ");
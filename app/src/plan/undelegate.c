@@ -18,6 +18,7 @@
 #include "note.h"
 #include "parser_pb_utils.h"
 #include "rslib.h"
+#include "tx_metadata.h"
 #include "ui_utils.h"
 #include "zxformat.h"
 
@@ -38,14 +39,17 @@ parser_error_t decode_undelegate_plan(const bytes_t *data, undelegate_plan_t *un
     }
 
     undelegate->has_validator_identity = undelegate_plan.has_validator_identity;
+    undelegate->has_unbonded_amount = undelegate_plan.has_unbonded_amount;
     if (undelegate_plan.has_unbonded_amount) {
         undelegate->unbonded_amount.lo = undelegate_plan.unbonded_amount.lo;
         undelegate->unbonded_amount.hi = undelegate_plan.unbonded_amount.hi;
     }
+    undelegate->has_delegation_amount = undelegate_plan.has_delegation_amount;
     if (undelegate_plan.has_delegation_amount) {
         undelegate->delegation_amount.lo = undelegate_plan.delegation_amount.lo;
         undelegate->delegation_amount.hi = undelegate_plan.delegation_amount.hi;
     }
+    undelegate->has_from_epoch = undelegate_plan.has_from_epoch;
     if (undelegate_plan.has_from_epoch) {
         undelegate->from_epoch.index = undelegate_plan.from_epoch.index;
         undelegate->from_epoch.start_height = undelegate_plan.from_epoch.start_height;
@@ -54,6 +58,22 @@ parser_error_t decode_undelegate_plan(const bytes_t *data, undelegate_plan_t *un
     return parser_ok;
 }
 
+parser_error_t undelegate_validate(const parser_context_t *ctx, const undelegate_plan_t *undelegate) {
+    if (ctx == NULL || undelegate == NULL) {
+        return parser_no_data;
+    }
+
+    // The validator's exchange rate is only known through the transaction metadata,
+    // so the staking tokens released can only be checked when the host provides it
+    const rate_data_t *rate_data =
+        metadata_getRateData(ctx->rate_data, ctx->rate_data_len, &undelegate->validator_identity);
+    if (rate_data == NULL) {
+        return parser_ok;
+    }
+
+    return rs_undelegate_check_rate(undelegate, rate_data);
+}
+
 parser_error_t undelegate_getNumItems(const parser_context_t *ctx, uint8_t *num_items) {
     UNUSED(ctx);
     *num_items = 1;
@@ -106,11 +126,8 @@ parser_error_t undelegate_printValue(const parser_context_t *ctx, const undelega
     written_value = strlen(outVal);
 
     // add delegate amount
-    uint8_t metadata_buffer[150] = {0};
-    snprintf((char *)metadata_buffer, sizeof(metadata_buffer), "udelegation_%s", validator_identity_bytes);
-    bytes_t metadata = {.ptr = metadata_buffer, .len = strlen((char *)metadata_buffer)};
     uint8_t asset_id_bytes[ASSET_ID_LEN] = {0};
-    rs_get_asset_id_from_metadata(&metadata, asset_id_bytes, ASSET_ID_LEN);
+    CHECK_ERROR(rs_delegation_token_asset_id(&undelegate->validator_identity, asset_id_bytes, ASSET_ID_LEN));
 
     value_t local_delegate_amount = {.amount = undelegate->delegation_amount,
                                      .asset_id.inner = {.ptr = asset_id_bytes, .len = ASSET_ID_LEN},
@@ -125,18 +142,8 @@ parser_error_t undelegate_printValue(const parser_context_t *ctx, const undelega
     written_value = strlen(outVal);
 
     // add unbonded amount
-    snprintf((char *)metadata_buffer, sizeof(metadata_buffer), "uunbonding_start_at_");
-    uint16_t written_value_metadata = strlen((char *)metadata_buffer);
-    uint64_to_str((char *)metadata_buffer + written_value_metadata, sizeof(metadata_buffer) - written_value_metadata,
-                  undelegate->from_epoch.index);
-    written_value_metadata = strlen((char *)metadata_buffer);
-    snprintf((char *)metadata_buffer + written_value_metadata, sizeof(metadata_buffer) - written_value_metadata, "_");
-    written_value_metadata = strlen((char *)metadata_buffer);
-    snprintf((char *)metadata_buffer + written_value_metadata, sizeof(metadata_buffer) - written_value_metadata, "%s",
-             validator_identity_bytes);
-    written_value_metadata = strlen((char *)metadata_buffer);
-    metadata.len = written_value_metadata;
-    rs_get_asset_id_from_metadata(&metadata, asset_id_bytes, ASSET_ID_LEN);
+    CHECK_ERROR(rs_unbonding_token_asset_id(&undelegate->validator_identity, undelegate->from_epoch.start_height,
+                                            asset_id_bytes, ASSET_ID_LEN));
 
     value_t local_unbonded_amount = {.amount = undelegate->unbonded_amount,
                                      .asset_id.inner = {.ptr = asset_id_bytes, .len = ASSET_ID_LEN},
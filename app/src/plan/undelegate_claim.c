@@ -66,7 +66,7 @@ parser_error_t undelegate_claim_getItem(const parser_context_t *ctx, const undel
         return err;
     }
 
-    char bufferUI[UNDELEGATE_DISPLAY_MAX_LEN] = {0};
+    char bufferUI[UNDELEGATE_CLAIM_DISPLAY_MAX_LEN] = {0};
 
     snprintf(outKey, outKeyLen, "Action_%d", actionIdx + 1);
     CHECK_ERROR(undelegate_claim_printValue(ctx, undelegate, bufferUI, sizeof(bufferUI)));
@@ -81,7 +81,7 @@ parser_error_t undelegate_claim_printValue(const parser_context_t *ctx, const un
         return parser_no_data;
     }
 
-    if (outValLen < UNDELEGATE_DISPLAY_MAX_LEN) {
+    if (outValLen < UNDELEGATE_CLAIM_DISPLAY_MAX_LEN) {
         return parser_unexpected_buffer_end;
     }
 
@@ -123,5 +123,31 @@ parser_error_t undelegate_claim_printValue(const parser_context_t *ctx, const un
                                      .has_asset_id = true};
     CHECK_ERROR(printValue(ctx, &local_unbonded_amount, &ctx->tx_obj->parameters_plan.chain_id, true,
                            outVal + written_value, outValLen - written_value));
+    written_value = strlen(outVal);
+
+    // add slashing penalty and the staking tokens claimed
+    char penalty[PENALTY_STR_MAX_LEN] = {0};
+    bool is_slashed = false;
+    static const uint8_t staking_asset_id[ASSET_ID_LEN] = STAKING_TOKEN_ASSET_ID_BYTES;
+    value_t slashed_amount = {.asset_id.inner = {.ptr = staking_asset_id, .len = ASSET_ID_LEN},
+                              .has_amount = true,
+                              .has_asset_id = true};
+    value_t claimed_amount = slashed_amount;
+    CHECK_ERROR(rs_undelegate_claim_penalty(undelegate, (uint8_t *)penalty, sizeof(penalty), &slashed_amount.amount,
+                                            &claimed_amount.amount, &is_slashed));
+
+    if (is_slashed) {
+        snprintf(outVal + written_value, outValLen - written_value, " Slashed %s%%: ", penalty);
+        written_value = strlen(outVal);
+        CHECK_ERROR(printValue(ctx, &slashed_amount, &ctx->tx_obj->parameters_plan.chain_id, true,
+                               outVal + written_value, outValLen - written_value));
+        written_value = strlen(outVal);
+    }
+
+    snprintf(outVal + written_value, outValLen - written_value, " Claimed: ");
+    written_value = strlen(outVal);
+    CHECK_ERROR(printValue(ctx, &claimed_amount, &ctx->tx_obj->parameters_plan.chain_id, true,
+                           outVal + written_value, outValLen - written_value));
+
     return parser_ok;
 }
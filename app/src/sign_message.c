@@ -0,0 +1,87 @@
+/*******************************************************************************
+ *   (c) 2018 - 2024 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+
+#include "sign_message.h"
+
+#include <stdio.h>
+
+#include "coin.h"
+#include "crypto.h"
+#include "keys_def.h"
+#include "zxformat.h"
+#include "zxmacros.h"
+
+// The message exactly as it gets signed, null terminated for display
+static char message[SIGN_MESSAGE_MAX_LEN + 1] = {0};
+static uint16_t message_len = 0;
+static uint8_t randomizer[KEY_LEN] = {0};
+
+static void sign_message_clear() {
+    MEMZERO(message, sizeof(message));
+    MEMZERO(randomizer, sizeof(randomizer));
+    message_len = 0;
+}
+
+zxerr_t sign_message_init(const uint8_t *msgRandomizer, const uint8_t *msg, uint16_t msgLen) {
+    sign_message_clear();
+    if (msgRandomizer == NULL || msg == NULL || msgLen == 0 || msgLen > SIGN_MESSAGE_MAX_LEN) {
+        return zxerr_no_data;
+    }
+
+    // Only what can be shown in full gets signed, so printable ASCII alone
+    for (uint16_t i = 0; i < msgLen; i++) {
+        if (msg[i] < 0x20 || msg[i] > 0x7E) {
+            return zxerr_invalid_crypto_settings;
+        }
+    }
+
+    MEMCPY(message, msg, msgLen);
+    MEMCPY(randomizer, msgRandomizer, sizeof(randomizer));
+    message_len = msgLen;
+
+    return zxerr_ok;
+}
+
+zxerr_t sign_message_sign(uint8_t *output, uint16_t outputLen, uint16_t *written) {
+    if (message_len == 0) {
+        return zxerr_no_data;
+    }
+
+    zxerr_t err = crypto_signMessage((const uint8_t *)message, message_len, randomizer, output, outputLen, written);
+    sign_message_clear();
+
+    return err;
+}
+
+zxerr_t sign_message_getNumItems(uint8_t *num_items) {
+    zemu_log_stack("sign_message_getNumItems");
+    *num_items = 1;
+    return zxerr_ok;
+}
+
+zxerr_t sign_message_getItem(int8_t displayIdx, char *outKey, uint16_t outKeyLen, char *outVal, uint16_t outValLen,
+                             uint8_t pageIdx, uint8_t *pageCount) {
+    ZEMU_LOGF(50, "[sign_message_getItem] %d/%d\n", displayIdx, pageIdx)
+
+    switch (displayIdx) {
+        case 0:
+            snprintf(outKey, outKeyLen, "Sign Message");
+            pageString(outVal, outValLen, message, pageIdx, pageCount);
+            return zxerr_ok;
+        default:
+            return zxerr_no_data;
+    }
+}
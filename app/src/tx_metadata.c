@@ -18,16 +18,140 @@
 
 #include <zxmacros.h>
 
+#include "coin.h"
 #include "constants.h"
 #include "parser_txdef.h"
 #include "rslib.h"
 
-parser_error_t metadata_parse(parser_context_t *ctx, const uint8_t *data, size_t dataLen) {
+// A raw base denom, prefixed by its one byte length
+static parser_error_t metadata_parse_denom(const uint8_t *data, size_t dataLen, size_t *data_offset,
+                                           tx_metadata_t *metadata) {
+    // Check that there is at least one byte left for the length
+    if (*data_offset >= dataLen) {
+        return parser_unexpected_buffer_end;
+    }
+
+    // Read the length of the current string
+    uint8_t len = data[*data_offset];
+    *data_offset += 1;
+
+    // Validate the length to be within
+    // the bounds of the denomination length minus
+    // the null terminator character
+    if (len > MAX_DENOM_LEN - 1) {
+        return parser_value_out_of_range;
+    }
+
+    // Check that there are enough bytes left for the string data
+    if (*data_offset + len > dataLen) {
+        return parser_unexpected_buffer_end;
+    }
+
+    // Copy the string data into the metadata array, without a display denom
+    MEMCPY(metadata->denom, &data[*data_offset], len);
+    metadata->len = len;
+
+    const bytes_t denom = {.ptr = (const uint8_t *)metadata->denom, .len = len};
+    CHECK_ERROR(rs_get_asset_id_from_metadata(&denom, metadata->asset_id, ASSET_ID_LEN));
+
+    *data_offset += len;
+    return parser_ok;
+}
+
+// An encoded penumbra.core.asset.v1.Metadata, prefixed by its little-endian u16 length
+static parser_error_t metadata_parse_proto(const uint8_t *data, size_t dataLen, size_t *data_offset,
+                                           tx_metadata_t *metadata) {
+    // Check that there are two bytes left for the length
+    if (*data_offset + 2 > dataLen) {
+        return parser_unexpected_buffer_end;
+    }
+
+    // Read the little-endian length of the current entry
+    uint16_t len = (uint16_t)data[*data_offset] | ((uint16_t)data[*data_offset + 1] << 8);
+    *data_offset += 2;
+
+    // Check that there are enough bytes left for the entry
+    if (*data_offset + len > dataLen) {
+        return parser_unexpected_buffer_end;
+    }
+
+    // Parse the metadata, checking that its base denom matches the asset id
+    const bytes_t encoded = {.ptr = &data[*data_offset], .len = len};
+    CHECK_ERROR(rs_parse_denom_metadata(&encoded, metadata));
+
+    *data_offset += len;
+    return parser_ok;
+}
+
+// An encoded penumbra.core.component.stake.v1.RateData, prefixed by its little-endian u16 length
+static parser_error_t metadata_parse_rate_data(const uint8_t *data, size_t dataLen, size_t *data_offset,
+                                               rate_data_t *rate_data) {
+    // Check that there are two bytes left for the length
+    if (*data_offset + 2 > dataLen) {
+        return parser_unexpected_buffer_end;
+    }
+
+    // Read the little-endian length of the current entry
+    uint16_t len = (uint16_t)data[*data_offset] | ((uint16_t)data[*data_offset + 1] << 8);
+    *data_offset += 2;
+
+    // Check that there are enough bytes left for the entry
+    if (*data_offset + len > dataLen) {
+        return parser_unexpected_buffer_end;
+    }
+
+    const bytes_t encoded = {.ptr = &data[*data_offset], .len = len};
+    CHECK_ERROR(rs_parse_rate_data(&encoded, rate_data));
+
+    *data_offset += len;
+    return parser_ok;
+}
+
+// Rate data replaces the rate data sent before, and leaves the denoms alone
+static parser_error_t metadata_parse_rates(parser_context_t *ctx, const uint8_t *data, size_t dataLen) {
+    MEMZERO(ctx->rate_data, sizeof(ctx->rate_data));
+    // Only expose the entries once all of them parsed successfully
+    ctx->rate_data_len = 0;
+
+    if (dataLen < 1) {
+        return parser_unexpected_buffer_end;
+    }
+
+    const uint8_t num_rates = data[0];
+    if (num_rates > MAX_RATE_DATA_LEN) {
+        return parser_unexpected_number_items;
+    }
+
+    size_t data_offset = 1;
+    for (uint8_t i = 0; i < num_rates; i++) {
+        CHECK_ERROR(metadata_parse_rate_data(data, dataLen, &data_offset, &ctx->rate_data[i]));
+    }
+
+    if (data_offset != dataLen) {
+        return parser_unexpected_value;
+    }
+
+    ctx->rate_data_len = num_rates;
+
+    return parser_ok;
+}
+
+parser_error_t metadata_parse(parser_context_t *ctx, const uint8_t *data, size_t dataLen, uint8_t format) {
     if (ctx == NULL || data == NULL) {
         return parser_unexpected_error;
     }
 
+    if (format == P2_METADATA_RATE_DATA) {
+        return metadata_parse_rates(ctx, data, dataLen);
+    }
+
     MEMZERO(ctx->tx_metadata, sizeof(tx_metadata_t) * MAX_TX_METADATA_LEN);
+    // Only expose the entries once all of them parsed successfully
+    ctx->tx_metadata_len = 0;
+
+    if (format != P2_METADATA_DENOMS && format != P2_METADATA_PROTO) {
+        return parser_unexpected_value;
+    }
 
     // Check that dataLen is at least 1 to read the number of metadata strings
     if (dataLen < 1) {
@@ -42,38 +166,14 @@ parser_error_t metadata_parse(parser_context_t *ctx, const uint8_t *data, size_t
         return parser_unexpected_number_items;
     }
 
-    ctx->tx_metadata_len = num_strings;
-
     size_t data_offset = 1;  // Start after the num_strings byte
 
-    // Iterate over each metadata string
-    for (uint8_t i = 0; i < ctx->tx_metadata_len; i++) {
-        // Check that there is at least one byte left for the length
-        if (data_offset >= dataLen) {
-            return parser_unexpected_buffer_end;
-        }
-
-        // Read the length of the current string
-        uint8_t len = data[data_offset];
-        data_offset += 1;
-
-        // Validate the length to be within
-        // the bounds of the denomination length minus
-        // the null terminator character
-        if (len > MAX_DENOM_LEN - 1) {
-            return parser_value_out_of_range;
+    for (uint8_t i = 0; i < num_strings; i++) {
+        if (format == P2_METADATA_DENOMS) {
+            CHECK_ERROR(metadata_parse_denom(data, dataLen, &data_offset, &ctx->tx_metadata[i]));
+        } else {
+            CHECK_ERROR(metadata_parse_proto(data, dataLen, &data_offset, &ctx->tx_metadata[i]));
         }
-
-        // Check that there are enough bytes left for the string data
-        if (data_offset + len > dataLen) {
-            return parser_unexpected_buffer_end;
-        }
-
-        // Copy the string data into the metadata array
-        MEMCPY(ctx->tx_metadata[i].denom, &data[data_offset], len);
-        ctx->tx_metadata[i].len = len;
-
-        data_offset += len;
     }
 
     // Optional: Check if there are extra bytes left in the data buffer
@@ -82,38 +182,80 @@ parser_error_t metadata_parse(parser_context_t *ctx, const uint8_t *data, size_t
         return parser_unexpected_value;
     }
 
+    ctx->tx_metadata_len = num_strings;
+
     return parser_ok;
 }
 
 parser_error_t metadata_toAssetId(const tx_metadata_t *metadata, uint8_t *asset, uint16_t asset_len) {
-    bytes_t data = {0};
-    data.ptr = (uint8_t *)&metadata->denom[0];
-    data.len = metadata->len;
+    if (metadata == NULL || asset == NULL) {
+        return parser_no_data;
+    }
+    if (asset_len != ASSET_ID_LEN) {
+        return parser_invalid_length;
+    }
 
-    CHECK_ERROR(rs_get_asset_id_from_metadata(&data, asset, asset_len));
+    // The asset id was checked against the base denom when parsing the metadata
+    MEMCPY(asset, metadata->asset_id, ASSET_ID_LEN);
 
     return parser_ok;
 }
 
+static const tx_metadata_t *metadata_find(const tx_metadata_t *metadata, uint8_t metadataLen, const bytes_t *asset) {
+    if (metadataLen == 0 || metadata == NULL || asset == NULL || asset->ptr == NULL || asset->len != ASSET_ID_LEN) {
+        return NULL;
+    }
+
+    for (uint8_t i = 0; i < metadataLen; ++i) {
+        if (MEMCMP(metadata[i].asset_id, asset->ptr, ASSET_ID_LEN) == 0) {
+            return &metadata[i];
+        }
+    }
+    return NULL;
+}
+
 uint8_t metadata_getDenom(const tx_metadata_t *metadata, uint8_t metadataLen, const bytes_t *asset, char *denom,
                           uint8_t len) {
-    if (metadataLen == 0 || metadata == NULL || asset == NULL || asset->len == 0 || denom == NULL || len == 0) {
+    if (denom == NULL || len == 0) {
         return 0;
     }
 
-    uint8_t computed_asset[ASSET_ID_LEN] = {0};
-    const tx_metadata_t *found = NULL;
+    const tx_metadata_t *found = metadata_find(metadata, metadataLen, asset);
+    if (found == NULL || found->len > len) {
+        return 0;  // Asset not found
+    }
 
-    for (uint8_t i = 0; i < metadataLen; ++i) {
-        found = &metadata[i];
-        CHECK_ERROR(metadata_toAssetId(found, computed_asset, ASSET_ID_LEN));
-
-        if (MEMCMP(computed_asset, asset->ptr, ASSET_ID_LEN) == 0) {
-            if (found->len <= len) {
-                MEMCPY(denom, found->denom, found->len);
-                return found->len;
-            }
+    MEMCPY(denom, found->denom, found->len);
+    return found->len;
+}
+
+uint8_t metadata_getDisplay(const tx_metadata_t *metadata, uint8_t metadataLen, const bytes_t *asset, char *display,
+                            uint8_t len, uint8_t *exponent) {
+    if (display == NULL || len == 0 || exponent == NULL) {
+        return 0;
+    }
+
+    const tx_metadata_t *found = metadata_find(metadata, metadataLen, asset);
+    if (found == NULL || found->display_len > len) {
+        return 0;  // Asset not found
+    }
+
+    MEMCPY(display, found->display, found->display_len);
+    *exponent = found->display_exponent;
+    return found->display_len;
+}
+
+const rate_data_t *metadata_getRateData(const rate_data_t *rate_data, uint8_t rateDataLen,
+                                        const identity_key_t *validator_identity) {
+    if (rate_data == NULL || validator_identity == NULL || validator_identity->ik.ptr == NULL ||
+        validator_identity->ik.len != IDENTITY_KEY_LEN) {
+        return NULL;
+    }
+
+    for (uint8_t i = 0; i < rateDataLen; ++i) {
+        if (MEMCMP(rate_data[i].validator_identity, validator_identity->ik.ptr, IDENTITY_KEY_LEN) == 0) {
+            return &rate_data[i];
         }
     }
-    return 0;  // Asset not found
+    return NULL;
 }
@@ -20,7 +20,12 @@
 extern "C" {
 #endif
 
-parser_error_t metadata_parse(parser_context_t *ctx, const uint8_t *data, size_t dataLen);
+/**
+ * @brief Parses the metadata entries sent ahead of a transaction
+ * @param format P2_METADATA_DENOMS for raw base denoms, P2_METADATA_PROTO for encoded Metadata,
+ *               P2_METADATA_RATE_DATA for encoded validator RateData
+ */
+parser_error_t metadata_parse(parser_context_t *ctx, const uint8_t *data, size_t dataLen, uint8_t format);
 
 parser_error_t metadata_toAssetId(const tx_metadata_t *metadata, uint8_t *assetId, uint16_t assetIdLen);
 
@@ -36,6 +41,29 @@ parser_error_t metadata_toAssetId(const tx_metadata_t *metadata, uint8_t *assetI
 uint8_t metadata_getDenom(const tx_metadata_t *metadata, uint8_t metadataLen, const bytes_t *asset, char *denom,
                           uint8_t len);
 
+/**
+ * @brief Retrieves the display denomination and its exponent for a given asset from metadata array
+ * @param metadata Array of transaction metadata entries
+ * @param metadataLen Length of the metadata array
+ * @param asset Target asset ID to search for
+ * @param display Buffer to store the display denomination string
+ * @param len Length of the display denomination buffer
+ * @param exponent Number of decimals of the display denomination
+ * @return Length of display denomination copied, 0 if not found or error
+ */
+uint8_t metadata_getDisplay(const tx_metadata_t *metadata, uint8_t metadataLen, const bytes_t *asset, char *display,
+                            uint8_t len, uint8_t *exponent);
+
+/**
+ * @brief Finds the rate data of a validator
+ * @param rate_data Array of rate data entries
+ * @param rateDataLen Length of the rate data array
+ * @param validator_identity Identity key of the validator to search for
+ * @return The validator's rate data, NULL if not found or error
+ */
+const rate_data_t *metadata_getRateData(const rate_data_t *rate_data, uint8_t rateDataLen,
+                                        const identity_key_t *validator_identity);
+
 #ifdef __cplusplus
 }
 #endif
@@ -3,12 +3,31 @@
 #include "coin.h"
 #include "constants.h"
 #include "known_assets.h"
+#include "rslib.h"
 #include "tx_metadata.h"
 #include "ui_utils.h"
 #include "zxformat.h"
 
 bool is_zero_amount(const value_t *value) { return value->amount.hi == 0 && value->amount.lo == 0; }
 
+// Replaces a delegation or unbonding base denom with its readable form, any other denom is kept as is.
+// Returns whether the denom was replaced.
+static bool formatStakingDenom(char *denom, uint16_t denomSize, uint8_t *denomLen) {
+    char rendered[MAX_DENOM_LEN + 1] = {0};
+    uint16_t rendered_len = 0;
+    const bytes_t data = {.ptr = (const uint8_t *)denom, .len = *denomLen};
+
+    if (rs_format_staking_denom(&data, (uint8_t *)rendered, sizeof(rendered), &rendered_len) != parser_ok ||
+        rendered_len == 0 || rendered_len >= denomSize) {
+        return false;
+    }
+
+    MEMZERO(denom, denomSize);
+    MEMCPY(denom, rendered, rendered_len);
+    *denomLen = (uint8_t)rendered_len;
+    return true;
+}
+
 parser_error_t printValue(const parser_context_t *ctx, const value_t *value, const bytes_t *chain_id,
                           const bool format_amount, char *outVal, uint16_t outValLen) {
     if (ctx == NULL || value == NULL || outVal == NULL || chain_id == NULL) {
@@ -21,16 +40,14 @@ parser_error_t printValue(const parser_context_t *ctx, const value_t *value, con
 
     MEMZERO(outVal, outValLen);
 
-    char amount_str[U128_STR_MAX_LEN] = {0};
+    char amount_str[AMOUNT_STR_MAX_LEN] = {0};
 
     // convert to string note.amount
-    CHECK_ERROR(uint128_to_str(amount_str, U128_STR_MAX_LEN, value->amount.hi, value->amount.lo))
+    CHECK_ERROR(rs_format_amount(&value->amount, 0, (uint8_t *)amount_str, sizeof(amount_str)))
 
     // lookup at asset table
-    const asset_info_t *known_asset = NULL;
-    if (value->has_asset_id) {
-        known_asset = asset_info_from_table(value->asset_id.inner.ptr);
-    }
+    asset_info_t known_asset = {0};
+    const bool is_known_asset = value->has_asset_id && asset_info_from_table(value->asset_id.inner.ptr, &known_asset);
 
     // There are three cases:
     // Case 1: Known assets (decimal + space + symbol)
@@ -39,19 +56,15 @@ parser_error_t printValue(const parser_context_t *ctx, const value_t *value, con
     // where asset_id is unknown and not metadata was provided for it
 
     // Case 1: Known assets
-    if (known_asset != NULL) {
+    if (is_known_asset) {
         // check if chain id is penumbra-1
         if (strncmp((const char *)chain_id->ptr, DEFAULT_CHAIN_ID, chain_id->len) == 0) {
-            if (format_amount) {
-                return printNumber(amount_str, value->has_amount, COIN_AMOUNT_DECIMAL_PLACES, known_asset->symbol, "",
-                                   outVal, outValLen);
-            } else {
-                return printNumber(amount_str, value->has_amount, 0, known_asset->symbol, "", outVal, outValLen);
-            }
+            const uint8_t exponent = format_amount ? known_asset.decimals : 0;
+            return printAmount(&value->amount, value->has_amount, exponent, known_asset.symbol, outVal, outValLen);
         } else {
             // check in denom the format data
             bool was_printed = false;
-            CHECK_ERROR(tryPrintDenom(ctx, value, amount_str, outVal, outValLen, &was_printed));
+            CHECK_ERROR(tryPrintDenom(ctx, value, amount_str, format_amount, outVal, outValLen, &was_printed));
             if (was_printed) {
                 return parser_ok;
             }
@@ -63,10 +76,11 @@ parser_error_t printValue(const parser_context_t *ctx, const value_t *value, con
     }
 
     // Case 2: Base denom (integer + space + denom) taken from transaction
-    // for this we use the parser_context_t to access the transaction metadata
+    // for this we use the parser_context_t to access the transaction metadata,
+    // or (decimal + space + display denom) when the metadata gives its decimals
     // if not found, we default to case 3
     bool was_printed = false;
-    CHECK_ERROR(tryPrintDenom(ctx, value, amount_str, outVal, outValLen, &was_printed));
+    CHECK_ERROR(tryPrintDenom(ctx, value, amount_str, format_amount, outVal, outValLen, &was_printed));
     if (was_printed) {
         return parser_ok;
     }
@@ -103,8 +117,8 @@ parser_error_t printFee(const parser_context_t *ctx, const value_t *value, const
     return parser_ok;
 }
 
-parser_error_t tryPrintDenom(const parser_context_t *ctx, const value_t *value, const char *amount_str, char *outVal,
-                             uint16_t outValLen, bool *was_printed) {
+parser_error_t tryPrintDenom(const parser_context_t *ctx, const value_t *value, const char *amount_str,
+                             const bool format_amount, char *outVal, uint16_t outValLen, bool *was_printed) {
     if (ctx == NULL || value == NULL || outVal == NULL || amount_str == NULL) {
         return parser_no_data;
     }
@@ -118,6 +132,24 @@ parser_error_t tryPrintDenom(const parser_context_t *ctx, const value_t *value,
                                       MAX_DENOM_LEN + 1);
     }
 
+    bool is_staking_denom = false;
+    if (trace_len != 0) {
+        is_staking_denom = formatStakingDenom(denom, sizeof(denom), &trace_len);
+    }
+
+    // Show the amount in the display denom when the metadata provides one with decimals
+    if (trace_len != 0 && !is_staking_denom && format_amount) {
+        char display[MAX_DENOM_LEN + 1] = {0};
+        uint8_t exponent = 0;
+        const uint8_t display_len = metadata_getDisplay(&ctx->tx_metadata[0], ctx->tx_metadata_len,
+                                                        &value->asset_id.inner, display, MAX_DENOM_LEN, &exponent);
+        if (display_len != 0 && exponent != 0) {
+            CHECK_ERROR(printAmount(&value->amount, true, exponent, display, outVal, outValLen))
+            *was_printed = true;
+            return parser_ok;
+        }
+    }
+
     if (trace_len != 0) {
         // We found denom trace in provided transaction metadata
         snprintf(outVal, outValLen - 1, "%s", amount_str);
@@ -163,31 +195,22 @@ parser_error_t printFallback(const value_t *value, const char *amount_str, bool
                         outValLen - written - 1);
 }
 
-parser_error_t printNumber(const char *amount, bool has_amount, uint8_t decimalPlaces, const char *postfix,
-                           const char *prefix, char *outVal, uint16_t outValLen) {
-    char amount_trimmed[VALUE_DISPLAY_MAX_LEN] = {0};
-    if (has_amount) {
-        if (strcmp(amount, "0") == 0) {
-            snprintf(amount_trimmed, VALUE_DISPLAY_MAX_LEN, "%s", "0");
-        } else {
-            if (fpstr_to_str(amount_trimmed, VALUE_DISPLAY_MAX_LEN, amount, decimalPlaces) != 0) {
-                return parser_unexpected_value;
-            }
-        }
-
-        number_inplace_trimming(amount_trimmed, 1);
+parser_error_t printAmount(const amount_t *amount, bool has_amount, uint8_t exponent, const char *denom, char *outVal,
+                           uint16_t outValLen) {
+    if (amount == NULL || denom == NULL || outVal == NULL || outValLen == 0) {
+        return parser_no_data;
+    }
 
-        // add space
-        size_t fpstr_len = strlen(amount_trimmed);
-        amount_trimmed[fpstr_len] = ' ';
+    char amount_str[AMOUNT_STR_MAX_LEN] = {0};
+    if (has_amount) {
+        CHECK_ERROR(rs_format_amount(amount, exponent, (uint8_t *)amount_str, sizeof(amount_str)))
     }
 
-    if (z_str3join(amount_trimmed, VALUE_DISPLAY_MAX_LEN, prefix, postfix) != zxerr_ok) {
+    const int written = snprintf(outVal, outValLen, "%s%s%s", amount_str, has_amount ? " " : "", denom);
+    if (written < 0 || written >= outValLen) {
         return parser_unexpected_buffer_end;
     }
 
-    snprintf(outVal, outValLen, "%s", amount_trimmed);
-
     return parser_ok;
 }
 
@@ -204,16 +227,14 @@ parser_error_t printAssetIdFromValue(const parser_context_t *ctx, const value_t
     MEMZERO(outVal, outValLen);
 
     // lookup at asset table
-    const asset_info_t *known_asset = NULL;
-    if (value->has_asset_id) {
-        known_asset = asset_info_from_table(value->asset_id.inner.ptr);
-    }
+    asset_info_t known_asset = {0};
+    const bool is_known_asset = value->has_asset_id && asset_info_from_table(value->asset_id.inner.ptr, &known_asset);
 
     // Case 1: Known assets
-    if (known_asset != NULL) {
+    if (is_known_asset) {
         // check if chain id is penumbra-1
         if (strncmp((const char *)chain_id->ptr, DEFAULT_CHAIN_ID, chain_id->len) == 0) {
-            snprintf(outVal, outValLen, "%s", known_asset->symbol);
+            snprintf(outVal, outValLen, "%s", known_asset.symbol);
         } else {
             CHECK_ERROR(printAssetId(value->asset_id.inner.ptr, value->asset_id.inner.len, outVal, outValLen));
         }
@@ -230,6 +251,10 @@ parser_error_t printAssetIdFromValue(const parser_context_t *ctx, const value_t
                                       MAX_DENOM_LEN + 1);
     }
 
+    if (trace_len != 0) {
+        formatStakingDenom(denom, sizeof(denom), &trace_len);
+    }
+
     if (trace_len != 0) {
         MEMCPY(&outVal, denom, trace_len);
         outVal[trace_len] = '\0';
@@ -31,12 +31,12 @@ parser_error_t printValue(const parser_context_t *ctx, const value_t *value, con
 parser_error_t printFee(const parser_context_t *ctx, const value_t *value, const bytes_t *chain_id, char *outVal,
                         uint16_t outValLen);
 
-parser_error_t tryPrintDenom(const parser_context_t *ctx, const value_t *value, const char *amount_str, char *outVal,
-                             uint16_t outValLen, bool *was_printed);
+parser_error_t tryPrintDenom(const parser_context_t *ctx, const value_t *value, const char *amount_str,
+                             const bool format_amount, char *outVal, uint16_t outValLen, bool *was_printed);
 parser_error_t printFallback(const value_t *value, const char *amount_str, bool has_amount, char *outVal,
                              uint16_t outValLen);
-parser_error_t printNumber(const char *amount, bool has_amount, uint8_t decimalPlaces, const char *postfix,
-                           const char *prefix, char *outVal, uint16_t outValLen);
+parser_error_t printAmount(const amount_t *amount, bool has_amount, uint8_t exponent, const char *denom, char *outVal,
+                           uint16_t outValLen);
 parser_error_t printAssetIdFromValue(const parser_context_t *ctx, const value_t *value, const bytes_t *chain_id,
                                      char *outVal, uint16_t outValLen);
 
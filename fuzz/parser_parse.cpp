@@ -2,6 +2,7 @@
 #include <cstdint>
 #include <cstdio>
 
+#include "coin.h"
 #include "parser.h"
 #include "parser_common.h"
 #include "parser_txdef.h"
@@ -34,7 +35,12 @@ extern "C" int LLVMFuzzerTestOneInput(const uint8_t *data, size_t size) {
         return 0;
     }
 
-    rc = parser_parseTxMetadata(&ctx, data, size);
+    // random plans rarely balance, keep going to reach the UI
+    (void)parser_checkBalance(&ctx);
+
+    // exercise both metadata encodings
+    const uint8_t format = (size % 2 == 0) ? P2_METADATA_DENOMS : P2_METADATA_PROTO;
+    rc = parser_parseTxMetadata(&ctx, data, size, format);
     if (rc != parser_ok) {
         return 0;
     }
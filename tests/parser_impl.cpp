@@ -25,6 +25,7 @@
 #include "parser.h"
 #include "parser_interface.h"
 #include "parser_txdef.h"
+#include "utils/common.h"
 #include "zxformat.h"
 
 using namespace std;
@@ -67,3 +68,111 @@ TEST(SCALE, ReadBytes) {
 
     EXPECT_EQ(std::string(actual), expected);
 }
+
+static void check_plan_balance_of(const char *blob, parser_error_t expected) {
+    parser_context_t ctx = {0};
+    parser_tx_t tx_obj = {0};
+    parser_error_t err;
+
+    uint8_t buffer[6000];
+    auto bufferLen = parseHexString(buffer, sizeof(buffer), blob);
+
+    err = parser_parse(&ctx, buffer, bufferLen, &tx_obj);
+    ASSERT_EQ(err, parser_ok) << parser_getErrorDescription(err);
+
+    err = parser_computeEffectHash(&ctx);
+    ASSERT_EQ(err, parser_ok) << parser_getErrorDescription(err);
+
+    err = parser_checkBalance(&ctx);
+    EXPECT_EQ(err, expected) << parser_getErrorDescription(err);
+}
+
+TEST(SCALE, CheckBalanceUnbalancedPlan) {
+    // A single spend that does not even cover the fee
+    check_plan_balance_of(
+        "0abe020abb020aa8010a300a0a08cbe5fdba84e7fff00712220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf"
+        "322ca96a101220931e59aa772ba57d44dc85d301dfb75dc1636b96fe57fa25c249a0b944d18ab71a520a50890bc98e3698aa4578e419"
+        "b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09d"
+        "b65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a202d6bdd168c08c681ed138083d9feece3e281dd293e1b8286ce7ba07007"
+        "a579042220c6b3f83c86a087f881249186bbc65c32ca269ffa9cd7f91d2b6f1fcd011d0b012a20d30887aaf837dedb1943bb44e5fa40"
+        "06ec01b9475acc1ff4f3053608583a190532204dc0e0a6902ac14c6585da44589c701e3a6bf74f1676e8043aa1d040d35cbb03124b12"
+        "3b65757170777863766f71696a746f7464656a666a787968766e767a656d62696c2d3331393637353439363732343637323134383135"
+        "3837363438301a0c0a0a08a7dcb5f99dc5888109",
+        parser_unbalanced_plan);
+}
+
+TEST(SCALE, CheckBalanceBalancedPlan) {
+    // The same spend topped up by a second one that covers the rest of the fee
+    check_plan_balance_of(
+        "0abe020abb020aa8010a300a0a08cbe5fdba84e7fff00712220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf"
+        "322ca96a101220931e59aa772ba57d44dc85d301dfb75dc1636b96fe57fa25c249a0b944d18ab71a520a50890bc98e3698aa4578e419"
+        "b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09d"
+        "b65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a202d6bdd168c08c681ed138083d9feece3e281dd293e1b8286ce7ba07007"
+        "a579042220c6b3f83c86a087f881249186bbc65c32ca269ffa9cd7f91d2b6f1fcd011d0b012a20d30887aaf837dedb1943bb44e5fa40"
+        "06ec01b9475acc1ff4f3053608583a190532204dc0e0a6902ac14c6585da44589c701e3a6bf74f1676e8043aa1d040d35cbb030abe02"
+        "0abb020aa8010a300a0a08dcf6b7be99de88900112220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca9"
+        "6a1012209e89d3083410174c9ddf76db32d892c20100117ddb7cb2f99df123fe84e47d0f1a520a50890bc98e3698aa4578e419b028da"
+        "5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc4"
+        "2d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a208811b6f6a7f2a86c20a74b93dbcb79cbffbf717f5ae896457b45c5e85248db09"
+        "222044b00d4325ccd4f7144dea69471119afe24c291fbc7ec67f325c84fa4b90b30a2a2075fe7041eb562caefa307b967f97e6053cba"
+        "a5f1fae7ba8dbac97a1d10fe78033220a8599f2d6c0449801d23adca49130fbe967a9fb606305ea0cbc7196f2416330b124b123b6575"
+        "7170777863766f71696a746f7464656a666a787968766e767a656d62696c2d3331393637353439363732343637323134383135383736"
+        "3438301a0c0a0a08a7dcb5f99dc5888109",
+        parser_ok);
+}
+
+TEST(SCALE, FlowSummaryItems) {
+    parser_context_t ctx = {0};
+    parser_tx_t tx_obj = {0};
+    parser_error_t err;
+
+    // A delegation funded by a spend, with the delegation tokens paid back to us
+    uint8_t buffer[6000];
+    auto bufferLen = parseHexString(buffer, sizeof(buffer),
+        "0a42c2023f0a220a20190f5214f58d21b07bc3f143e2a8ec4de51b463843cdb537e8221084b4e4be7d10a70c1a0a08f1b980b6c0b7ac"
+        "ff09220a08c6bdf9ca96c487e0090abe020abb020aa8010a300a0a08ddea9b83c6bfc8d81412220a2029ea9c2f3371f6a487e7e95c24"
+        "7041f4a356f983eb064e5d2b3bcf322ca96a101220aa741bc8b8d4171ed9501a1f52ac4c6f4f0f84e3c13dd92034e39a893b18cc041a"
+        "520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e1859"
+        "5d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a208f6088d0b5c664721d3e34ab6da2b6"
+        "3aa71819f143b06baa76ef0e0f5678dd092220b388301f7d598cfefdc6cf6a92a1c430bde9b12e5c3e242fc789f2c46093ed052a2051"
+        "c10a905b83fdbf1b94e1280a0356f812cf704aafc9902e5fa36422f59470083220690068bb3b8760ed07b12475a198653d5ee011bceb"
+        "d3222ba83726802e95c5050a9102128e020a300a0a08c6bdf9ca96c487e00912220a20eb8fee224b51a42fdcf707067b0fbc153a320c"
+        "b9494f9cd19b7dfd647056950312520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead3"
+        "50816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a203d0ebf68c53ee62eea"
+        "c7ca1e38e6d888a9654e8716b3ee2411a5d5e9ea048a012220a559a6bbed4e491e28187796c2cad8a2c58fae350c9e3fca40ae014ca3"
+        "ad92032a20878b4369bebdf65fa471ed91d2d3cd85b2fd1b65e26410395db4914d2f00e80332209314d0062dbd0373a5d82570de31e1"
+        "5dd929e5d9e1308dbe68134f3fde89b701122512157274732d34363830313137323933343733323539341a0c0a0a08ecb09bcd85889c"
+        "d90a2aec020ac7020a520a5003ea4fa22155ab8476c5e8b1db71e0aa898099485bc38f82684a669730587fcdfa31d1280722aad31555"
+        "03cec36632f1ec1740febad7ba6169a2c442732c5a7581f17f56185adad613c0f604b5211a3212f001326c433359416d472020502073"
+        "564e5077352036492067454c615832383069204b2020204e562072416c33617a36202076595a56582067775558312039482050775456"
+        "307a426f203367354c20654b204a3852206f457533702039354c78485a6a2050496271764a32374231374d7a7a44203744206f372078"
+        "303033685668783520206d2073316e634f38203450386441324f67517130685247663434584a204b4d344f206235612039326c612058"
+        "7639624520314763566e39312068726632664e4a65204d6e456d596d38672020414d366e4f49334832624d753369776e72206d204a20"
+        "203034384a6d3530384678122099aef8745c0a34e4085939bb061198411c55df15507482c4e6a0914ce8ec5587");
+
+    err = parser_parse(&ctx, buffer, bufferLen, &tx_obj);
+    ASSERT_EQ(err, parser_ok) << parser_getErrorDescription(err);
+
+    // The summary is only filled in along with the effect hash
+    err = parser_computeEffectHash(&ctx);
+    ASSERT_EQ(err, parser_ok) << parser_getErrorDescription(err);
+
+    // The spend and the output back to us stay out of the summary, which shows what the
+    // delegation takes and gives back, with the fee next to the asset it is paid in
+    const std::vector<std::string> expected = {
+        "4 | Sent [1/3] : 720208411551079665 passet1984fctenw8m2",
+        "4 | Sent [2/3] : fpl8a9wzguzp7j34d7vravryuhft808nyt9fdg",
+        "4 | Sent [3/3] : gqxmanqm",
+        "5 | Fee [1/3] : 770801907918100588 passet1984fctenw8m2",
+        "5 | Fee [2/3] : fpl8a9wzguzp7j34d7vravryuhft808nyt9fdg",
+        "5 | Fee [3/3] : gqxmanqm",
+        "6 | Received [1/3] : 702594670720343750 passet1aw87ugjt2xjz",
+        "6 | Received [2/3] : lh8hqur8krauz5aryr9ef98ee5vm0h7kguzkj5",
+        "6 | Received [3/3] : ps8l5sdy",
+    };
+
+    auto output = dumpUI(&ctx, 39, 39);
+    ASSERT_GE(output.size(), 12 + expected.size());
+    const std::vector<std::string> summary(output.begin() + 12, output.begin() + 12 + expected.size());
+    EXPECT_THAT(summary, testing::ElementsAreArray(expected));
+}
@@ -37,6 +37,7 @@ typedef struct {
     std::string name;
     std::string blob;
     std::string hash;
+    std::string error;
 } testcase_effect_hash_t;
 
 class JsonTestsEffectHash : public ::testing::TestWithParam<testcase_effect_hash_t> {
@@ -72,7 +73,8 @@ std::vector<testcase_effect_hash_t> GetJsonTestCasesEffectHash(std::string jsonF
         answer.push_back(testcase_effect_hash_t{obj[i]["index"].get<uint64_t>(),
                                                 obj[i]["name"].is_null() ? "" : obj[i]["name"].get<std::string>(),
                                                 obj[i]["blob"].is_null() ? "" : obj[i]["blob"].get<std::string>(),
-                                                obj[i]["hash"].is_null() ? "" : obj[i]["hash"].get<std::string>()});
+                                                obj[i]["hash"].is_null() ? "" : obj[i]["hash"].get<std::string>(),
+                                                obj[i]["error"].is_null() ? "" : obj[i]["error"].get<std::string>()});
     }
 
     return answer;
@@ -91,6 +93,15 @@ void check_testcase_effect_hash(const testcase_effect_hash_t &tc, bool expert_mo
     parser_tx_t tx_obj = {0};
 
     err = parser_parse(&ctx, buffer, bufferLen, &tx_obj);
+
+    // plans that must be rejected carry the expected error instead of a hash
+    if (!tc.error.empty()) {
+        if (err == parser_ok) {
+            err = parser_computeEffectHash(&ctx);
+        }
+        EXPECT_EQ(std::string(parser_getErrorDescription(err)), tc.error);
+        return;
+    }
     ASSERT_EQ(err, parser_ok) << parser_getErrorDescription(err);
 
     err = parser_computeEffectHash(&ctx);
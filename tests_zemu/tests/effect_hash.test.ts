@@ -55,10 +55,11 @@ describe('Standard', function () {
         const signatureResponse = await signatureRequest
         console.log('Effect hash:', signatureResponse.effectHash.toString('hex'))
 
+        expect(signatureResponse.spendAuthSignatures.length).toEqual(data.expected_spend_sigs.length)
         if (signatureResponse.spendAuthSignatures.length > 0) {
           signatureResponse.spendAuthSignatures.forEach((signature, index) => {
             console.log(`Spend Auth Signature ${index + 1}: ${signature.toString('hex')}`)
-            expect(signature.toString('hex')).toEqual(data.expected_spend_sig)
+            expect(signature.toString('hex')).toEqual(data.expected_spend_sigs[index])
           })
         } else {
           console.log('No spend auth signatures available.')
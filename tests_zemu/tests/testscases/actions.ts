@@ -4,20 +4,24 @@ export const ACTIONS_TESTCASES: TestCase[] = [
   {
     idx: 0,
     name: 'Spend',
-    blob: '0abe020abb020aa8010a300a0a08cbe5fdba84e7fff00712220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a101220931e59aa772ba57d44dc85d301dfb75dc1636b96fe57fa25c249a0b944d18ab71a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a202d6bdd168c08c681ed138083d9feece3e281dd293e1b8286ce7ba07007a579042220c6b3f83c86a087f881249186bbc65c32ca269ffa9cd7f91d2b6f1fcd011d0b012a20d30887aaf837dedb1943bb44e5fa4006ec01b9475acc1ff4f3053608583a190532204dc0e0a6902ac14c6585da44589c701e3a6bf74f1676e8043aa1d040d35cbb03124b123b65757170777863766f71696a746f7464656a666a787968766e767a656d62696c2d33313936373534393637323436373231343831353837363438301a0c0a0a08a7dcb5f99dc5888109',
+    blob: '0abe020abb020aa8010a300a0a08cbe5fdba84e7fff00712220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a101220931e59aa772ba57d44dc85d301dfb75dc1636b96fe57fa25c249a0b944d18ab71a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a202d6bdd168c08c681ed138083d9feece3e281dd293e1b8286ce7ba07007a579042220c6b3f83c86a087f881249186bbc65c32ca269ffa9cd7f91d2b6f1fcd011d0b012a20d30887aaf837dedb1943bb44e5fa4006ec01b9475acc1ff4f3053608583a190532204dc0e0a6902ac14c6585da44589c701e3a6bf74f1676e8043aa1d040d35cbb030abe020abb020aa8010a300a0a08dcf6b7be99de88900112220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012209e89d3083410174c9ddf76db32d892c20100117ddb7cb2f99df123fe84e47d0f1a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a208811b6f6a7f2a86c20a74b93dbcb79cbffbf717f5ae896457b45c5e85248db09222044b00d4325ccd4f7144dea69471119afe24c291fbc7ec67f325c84fa4b90b30a2a2075fe7041eb562caefa307b967f97e6053cbaa5f1fae7ba8dbac97a1d10fe78033220a8599f2d6c0449801d23adca49130fbe967a9fb606305ea0cbc7196f2416330b124b123b65757170777863766f71696a746f7464656a666a787968766e767a656d62696c2d33313936373534393637323436373231343831353837363438301a0c0a0a08a7dcb5f99dc5888109',
     expected_effect_hash:
-      'bd6419d9a090c7461f9d5749b126fbb291288071f3fe39abfb24eca6e3e6c3daafea1a7ca63269393c5e50bf4256b847853e70d6e0ae6bef5fa891f3e2c1310f',
-    expected_spend_sig:
-      '8481bc35b09b5ed8a6c51f1a50ca5670bb478c4707364c00bc91393e940b8a0c6ae9f026d8a8c0d98f2900a4f93bf1b6c13ed79e2a7fca290e2f818137cff602',
+      '0b15d61295f041db24a4d72cb8a5ae04650b0c823c0928e9daabe6d1dc68c8f76d98472bce24812caec5832e080eed4609c16da7b6d6ab42544becde6bf56c83',
+    expected_spend_sigs: [
+      'ca6ce82243f604d9cf2ba3b6bbe62cfa3875e6ff093f4c48d35a71cf1e4b99005c2f489d2b78457ddccc0b74f4b1ee98741bc900a223320b0640bf7d8611e000',
+      'f05674983aed8a599ad4119b98e8747674b0dbc331f4fb178f10fb70a4052a0490d93cb6c1e7ba76dc39a539d692319cc58fae8c92c4f2093d59418011e29e04',
+    ],
     metadata: [],
   },
   {
     idx: 1,
     name: 'Output',
-    blob: '0a9102128e020a300a0a0897e4ffc783b1a6ec0c12220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a20cb302f60ae37fb29c3cfc6e58c8b3f3a633ed723542d29145343fc8516f5268922208f05c39e61fa49c5b3b00707835362a7f280bf57a3c49f4f256f5b72746926032a20ddef66f55bdde614f8a26292a463cedce66b4c3a44f67a593f4e0baa22dd720b3220e870b617cb404e0167291fb08fa02df2df4c9180a4d18c63e17f7017ce1b8301121b120b612d3230393139353532361a0c0a0a08f5dcb0cbcedfe69a05',
+    blob: '0a9102128e020a300a0a0897e4ffc783b1a6ec0c12220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a20cb302f60ae37fb29c3cfc6e58c8b3f3a633ed723542d29145343fc8516f5268922208f05c39e61fa49c5b3b00707835362a7f280bf57a3c49f4f256f5b72746926032a20ddef66f55bdde614f8a26292a463cedce66b4c3a44f67a593f4e0baa22dd720b3220e870b617cb404e0167291fb08fa02df2df4c9180a4d18c63e17f7017ce1b83010abe020abb020aa8010a300a0a088cc1b093d2908d871212220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a101220f0e1dfab15410afc50b634482a70aaeb60848d1e70f6797b6f0166630b8060021a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a2090b0b62b9da6132d0b1649f4dc1b3331d2163a7870e361f8b8f12fb2259cfa05222020296098c73db253f0a134a9ac1dc3ed3228f08de65bed4398391659cb53de022a20c94d62a7aff8596bec3c1fffee02358fb5df173bfe738250d531df4918e6870532204ea80d4f2c097de1d3d66b3cc406e738f7e3d949c8f86e3afc29517b0dd46b07121b120b612d3230393139353532361a0c0a0a08f5dcb0cbcedfe69a05',
     expected_effect_hash:
-      '0ac386aecba110d78e238465f0b7e39e49f7f9839eb7e36ae4113c58fef9cf9c72a3ae99c34b1f48ef82497171613a3aea4a7fae6f6b3991771130a805a5e3d3',
-    expected_spend_sig: '',
+      '8193b05e11263f06d676046f29ea23704e30f0a0220d8cd083abb17d678b20756252a148143cffe80b9d8dd40ee56e4006c41449e3d6cb8ddb99f48ae950c955',
+    expected_spend_sigs: [
+      '02f68a9aec891d1418ab27ef16bed6fa7027b00f38e2187e8f1f8029047ef10ff902f4ca7ec626aacab7893ab514b65bd7fd3d0f5b421bf4354de806432d3403',
+    ],
     metadata: [],
   },
   // {
@@ -29,100 +33,129 @@ export const ACTIONS_TESTCASES: TestCase[] = [
   {
     idx: 3,
     name: 'Ics20_Withdrawal',
-    blob: '0aa802c20ca4020a0a089ac1a69ed6899eb20a12170a156555696136517031326662333046327071304e67411a8f0170656e756d62726131357a376c717a30667578716c3363746168717a6a687365726d6b74756c327a376e716b3074673364787876656b793076327574706130373474377174766e6a3036796d33756c6771766a6c356b6a7271686c32636464736866306a337a79666e30746c733974397736677975616e363263376e61613239747a3238353430323970716563776d22520a507d1ae84746eeca2356ab7d18a7597b3b0e3a91fdb701b3003df15e90d2f6b2775d41893e37ccd1afd4d8cd170838f2f3ff69a99baa1384cca876c0ed7ff7771956f46197af3fc6f9b10aa671ff53e46e2a0c08f6baf1db0310fe97bafb013a096368616e6e656c2d30123808e188091224796469667779697573666f6b737777627166656e6b2d33323234363639343633383737331a0c0a0a08fae384879bc48c890a2a91030aec020a520a50738391f16c9d87ecf88aaa28f6dc7cc4eb413594adfb83ae85fb2fd5dd6c9709c3899b3a15f6a5a4dd8fdd5c10d0ea84dccaa02f20bb5476e1646b18fd54f657a8b1906f67995f40834f9c44440f22e01295025820452032386a31547520204868303147652033554c52375a532020383575782079492072202063597165202020206168346631583079306e6c3255453737313020544774506b5a514766766e4f4678314f2020346920393768207056204f203120544c6f697659706e794630774a506b3733556f3573336e205a76652078623774613136642064207738734d436a2053207730314a6b37204962452078616d2020654a392042205455203066566a634e204437554d684e4b4d71717a2036656d492046376c457431546e376578674f4f71546667387050386c204e203352333139764774706b4820455520344320754b30306f4170332020204d6f35204634353820383774356f58396562204f4520644f5553201220dd9b6fc0592cbdc2d6680301d529a5448f72b0ba63451979351d7d2ada963950',
+    blob: '0aa802c20ca4020a0a089ac1a69ed6899eb20a12170a156555696136517031326662333046327071304e67411a8f0170656e756d62726131357a376c717a30667578716c3363746168717a6a687365726d6b74756c327a376e716b3074673364787876656b793076327574706130373474377174766e6a3036796d33756c6771766a6c356b6a7271686c32636464736866306a337a79666e30746c733974397736677975616e363263376e61613239747a3238353430323970716563776d22520a507d1ae84746eeca2356ab7d18a7597b3b0e3a91fdb701b3003df15e90d2f6b2775d41893e37ccd1afd4d8cd170838f2f3ff69a99baa1384cca876c0ed7ff7771956f46197af3fc6f9b10aa671ff53e46e2a0c08f6baf1db0310fe97bafb013a096368616e6e656c2d300abe020abb020aa8010a300a0a089ac1a69ed6899eb20a12220a20d8e94ef68b2fa00eda30f4ded825d57c62af60de3ebd0a239e6694573468490c122043a1d02cf89cb8bb2618f29d1302272fa2e594593fa514b4de76bb52693bc7031a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a2021c9da742bfaa3d6021bd17d530524df5d40612ab8608c71b9dba4b6ec91ce08222027b930ad68c15c203fe873833c348c79588873500e1841a9ce7a561ee02467022a20e6702335bf68a065976faa894b19c83f9acd02cb8827f14d9b8d6241da1d0a05322038e095e792714b9120c4c639cad32e274c2f3e4b5195ebcdd2b9c3415c23a10c0abe020abb020aa8010a300a0a08fae384879bc48c890a12220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012200f1788c419ea6e9fb67757e3f8f8fa525832f2d1ada9cd02e194a115946b410a1a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a20e9da664f17b9621cbb8113428a9661c32424a09ac3186d01269ef86a5e98610b2220ab478f0f746dd0b6e3762515e2b83383c8c1e6d4f836296df550e88a2cc4860f2a204f129996c99519baefdb6bc1b9b477664ecb0b6409e3b3627dc7501a161488043220b1805e698c473d560eb96a86abb89692f2a849a5252916d75f07ad6e6b613105123808e188091224796469667779697573666f6b737777627166656e6b2d33323234363639343633383737331a0c0a0a08fae384879bc48c890a2a91030aec020a520a50738391f16c9d87ecf88aaa28f6dc7cc4eb413594adfb83ae85fb2fd5dd6c9709c3899b3a15f6a5a4dd8fdd5c10d0ea84dccaa02f20bb5476e1646b18fd54f657a8b1906f67995f40834f9c44440f22e01295025820452032386a31547520204868303147652033554c52375a532020383575782079492072202063597165202020206168346631583079306e6c3255453737313020544774506b5a514766766e4f4678314f2020346920393768207056204f203120544c6f697659706e794630774a506b3733556f3573336e205a76652078623774613136642064207738734d436a2053207730314a6b37204962452078616d2020654a392042205455203066566a634e204437554d684e4b4d71717a2036656d492046376c457431546e376578674f4f71546667387050386c204e203352333139764774706b4820455520344320754b30306f4170332020204d6f35204634353820383774356f58396562204f4520644f5553201220dd9b6fc0592cbdc2d6680301d529a5448f72b0ba63451979351d7d2ada963950',
     expected_effect_hash:
-      '462717be27e0ecf1a4d9d52537fb3729d4328f1867a97fa7c374a6c02a44ee58ca0acb2f2702693946e5d4dfbd6c014238231b27556938bb2f0c2ffd2b3e3d0b',
-    expected_spend_sig: '',
+      '1d7ba6ba08106872a7670aa15fbae7b7b3f560bdcc6dee63466ca8c4b12dd6351be9cf3cba2b195f31a21c6c349b91e097cd44e488a6cdec5cbd8d24ec703457',
+    expected_spend_sigs: [
+      'c42477bff678c783a3ff449a23ed2bf8754cbe053fb58fc19aceaf3a1bb38c034269aff898ea7d588c3de15c4dc60c0e1a293073d567a495416da91781c8b601',
+      '14d21734b862555e904c0c29757f916601d39403df577e5c779149689ddae9088bde8f0c1320643d0977553154508269645e0209b5ec2b61fba1ad2f485a3003',
+    ],
     metadata: [],
   },
   {
     idx: 4,
     name: 'Delegate',
-    blob: '0a42c2023f0a220a20190f5214f58d21b07bc3f143e2a8ec4de51b463843cdb537e8221084b4e4be7d10a70c1a0a08f1b980b6c0b7acff09220a08c6bdf9ca96c487e009122512157274732d34363830313137323933343733323539341a0c0a0a08ecb09bcd85889cd90a2aec020ac7020a520a5003ea4fa22155ab8476c5e8b1db71e0aa898099485bc38f82684a669730587fcdfa31d1280722aad3155503cec36632f1ec1740febad7ba6169a2c442732c5a7581f17f56185adad613c0f604b5211a3212f001326c433359416d472020502073564e5077352036492067454c615832383069204b2020204e562072416c33617a36202076595a56582067775558312039482050775456307a426f203367354c20654b204a3852206f457533702039354c78485a6a2050496271764a32374231374d7a7a44203744206f372078303033685668783520206d2073316e634f38203450386441324f67517130685247663434584a204b4d344f206235612039326c6120587639624520314763566e39312068726632664e4a65204d6e456d596d38672020414d366e4f49334832624d753369776e72206d204a20203034384a6d3530384678122099aef8745c0a34e4085939bb061198411c55df15507482c4e6a0914ce8ec5587',
+    blob: '0a42c2023f0a220a20190f5214f58d21b07bc3f143e2a8ec4de51b463843cdb537e8221084b4e4be7d10a70c1a0a08f1b980b6c0b7acff09220a08c6bdf9ca96c487e0090abe020abb020aa8010a300a0a08ddea9b83c6bfc8d81412220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a101220aa741bc8b8d4171ed9501a1f52ac4c6f4f0f84e3c13dd92034e39a893b18cc041a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a208f6088d0b5c664721d3e34ab6da2b63aa71819f143b06baa76ef0e0f5678dd092220b388301f7d598cfefdc6cf6a92a1c430bde9b12e5c3e242fc789f2c46093ed052a2051c10a905b83fdbf1b94e1280a0356f812cf704aafc9902e5fa36422f59470083220690068bb3b8760ed07b12475a198653d5ee011bcebd3222ba83726802e95c5050a9102128e020a300a0a08c6bdf9ca96c487e00912220a20eb8fee224b51a42fdcf707067b0fbc153a320cb9494f9cd19b7dfd647056950312520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a203d0ebf68c53ee62eeac7ca1e38e6d888a9654e8716b3ee2411a5d5e9ea048a012220a559a6bbed4e491e28187796c2cad8a2c58fae350c9e3fca40ae014ca3ad92032a20878b4369bebdf65fa471ed91d2d3cd85b2fd1b65e26410395db4914d2f00e80332209314d0062dbd0373a5d82570de31e15dd929e5d9e1308dbe68134f3fde89b701122512157274732d34363830313137323933343733323539341a0c0a0a08ecb09bcd85889cd90a2aec020ac7020a520a5003ea4fa22155ab8476c5e8b1db71e0aa898099485bc38f82684a669730587fcdfa31d1280722aad3155503cec36632f1ec1740febad7ba6169a2c442732c5a7581f17f56185adad613c0f604b5211a3212f001326c433359416d472020502073564e5077352036492067454c615832383069204b2020204e562072416c33617a36202076595a56582067775558312039482050775456307a426f203367354c20654b204a3852206f457533702039354c78485a6a2050496271764a32374231374d7a7a44203744206f372078303033685668783520206d2073316e634f38203450386441324f67517130685247663434584a204b4d344f206235612039326c6120587639624520314763566e39312068726632664e4a65204d6e456d596d38672020414d366e4f49334832624d753369776e72206d204a20203034384a6d3530384678122099aef8745c0a34e4085939bb061198411c55df15507482c4e6a0914ce8ec5587',
     expected_effect_hash:
-      '6d2048e48ada8800a46ca80c2a42b394f0459e132c041cd9a9c85fb8c4bb159048951f39b3f4299401f0e47ba5a59319bed54ec1221950ba2f6a24add73743e8',
-    expected_spend_sig: '',
+      '60a0fd32b60adc66bda215fd3ba06a03f22ca893054732daed7a4ac2cd608da08d1ebce5299147ea69583b9ef30e5882f0cf196ccbc6703df32e172fb8c21b96',
+    expected_spend_sigs: [
+      '36c403042945c6bc57b4cbd19358fd1f5b23989439cf325202e7132baf6ec50e4128efcae2e833415eb61ab0987aaf6104b9833ecce52be17644c55e0e829100',
+    ],
     metadata: [],
   },
   {
     idx: 5,
     name: 'Undelegate',
-    blob: '0a46ca02430a220a200429420ed639777d600b547e6adc8fe8e8858fa944188a2734c502c3edd9aff21a0908c6dbe5d3e0bd9718220a08cebfcaeef2a2dcfd0c2a0608ab1110ab11122c08b98f28121879656a636279766d6a6f6768696e76787275792d343738341a0c0a0a0898d5b3c1d0c6dbe30a',
+    blob: '0a46ca02430a220a200429420ed639777d600b547e6adc8fe8e8858fa944188a2734c502c3edd9aff21a0908c6dbe5d3e0bd9718220a08cebfcaeef2a2dcfd0c2a0608ab1110ab110abe020abb020aa8010a300a0a08cebfcaeef2a2dcfd0c12220a20600f1599824e37a5284b7ba5830c46b027edda040610aaa6c37e0bbb7f4315071220d96a99b3180bc3d94cc9c1cd014fb2621a71692c11e923f04ded5f18e0eacb071a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a205bf0eb4a9c5eb594537dcac25a8c982835571800935df19e320ecb4ecf265d0522208d9a407adb63682d5f570b25a215a1de4f0f38bd5d8fec22d1962c5e1edcc1082a2046f1e99e95f82ef8f02bee43a97671e3ae55d17c7251153a51feeb63754e0b043220757bd8d01936e1a6a51f4ac457b2d2f16f612c26e7bf0f48a09b5e7a891c900c0abe020abb020aa8010a300a0a0898d5b3c1d0c6dbe30a12220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012200d1490820575ba526159049262fb2538bec1593fe288593140299f16023cd1011a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a2086bca1d9a777b088810eca9dfccf55b0f36ea16dee4b9094b177e4a90e5d890822202bdff668780f53324296c3dd1f7d2fa6aadb7bfe6d13891e362457cf2dcf920e2a2043b9c4c0a620afc006645c9ccb4590dcd1a42c72c7e0a6f0895bd3ed95674a0e3220b20b0204ff5cb0afb992347b642a7bebd6bf012e37dcd3f479b84af9fa5502090a9002128d020a2f0a0908c6dbe5d3e0bd971812220a20162c2e0f693477c040b0ee576bb93507c8c53fbfe0c2f4fec72c29d628951c0112520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a2021f231e6a2533a9fbca715bb22e096ea667dd04240f90895b01295c33117f3002220e677c09a7f2be010cff0ba8961fff5b18cfe2770bcd4726892c1d16521f538042a20d0d59ddf35c70fcb70967e43304693c375a2ff5597491b078ae875cc7b95cc0132200f13111442ca80965b13c85f9b1376b63747110046de6fd811597caa88c4f501122c08b98f28121879656a636279766d6a6f6768696e76787275792d343738341a0c0a0a0898d5b3c1d0c6dbe30a',
     expected_effect_hash:
-      '9616a3347a7fb88ee1ef46bf6893e542bd7f19da7f0d3bb1d2968ef0366f6748f95678ddb205b078658c45917e1ed8d1fd9530a3908490d3de37a1370e9705e2',
-    expected_spend_sig: '',
+      'a4ab6683b43db1ffcf3eb9256af846589ba762e92dfeb11d9c7f4ff01fbb796e5fa5f008a9c42e9ff437c1325937e87ff6b4dd383fada1ac310fc205f3aa2f41',
+    expected_spend_sigs: [
+      '7cbb66d2c1fb057311ab62b203184f14942619c062377b87a1351a550dd6ca0c989e82b007c7eb2359d1446f07ebb748013b5af064bedf4c4a8b49858cf53804',
+      '7cfe55faf92ca96ecc175e22d2e78002f91cf8daf228bc39327c28e98f7e9b047c922f19ec0e7342cbb8d72af0c6518262960e9df3c2c0ff63f81c5d86990d04',
+    ],
     metadata: [],
   },
   {
     idx: 6,
     name: 'Undelegate_Claim',
-    blob: '0ac201d202be010a220a2027836f69712e112e9db6957619379924fed46c2ea43254ad5b03389d87bb8ed222220a2000000000000000000000000000000000ffb15b573eab367a0f9096bb98c7e2832a0a08a4f1d88eabb7a088073220ce45b5ca03c5b70aad2572a3e60b9cd348c6d4d6175bafcdbadc77df5c715f013a20d0e4be966e508d10b493d8896a9a6cd33c4bc6ab3abcbdf9d03a78b62fde0f02422095b6d39c349ec34643c6a5330f9f1fbe225e3f81b05c2d9afc7cb8c559966c0d48d6f505122408d2a72f12106f71667277642d3136373735323039301a0c0a0a08dbe3f3ebd89eedff042aa3030afe020a520a503db91d3972d73cd32b0a2b50ad228bd1d5f8eb13ab367ba7fec05aed82324fcbb84218b399b55aa96ef7fef3768d8ce78ebd8d735cd46d767a48dc8059ddf1f83d29d2473b64847af2a8cc379e6ba92012a7025559204e3172205865414f6758465a203678356b6752317537374f32353820534b38496c6d7a4220652020733037206551424b204f79326757663335373452202053594e204e7330554f6168646f3438206f705836206c20652077206747494c6d312039314e4957314e55435732376168386470683520382043756a726d67316b324777395a387232366c364f2020786863397a7556324a3238434845394d385a63205a7970367265334a32654b20202020373436785a3230205634535439377a4b38386e6f2057572020555a4b3936367853206e6b74504f20626f20396a692072386e2074797343566e3148674c5839387220323971447a3859623748206620774b4a5a6120754275202055417a37337a2031207968572031204e513954203920206932503512202fcfc901480c42fda18c33fbe8d7901e57291ceca1f74b8357b366ab20436167',
+    blob: '0ac201d202be010a220a2027836f69712e112e9db6957619379924fed46c2ea43254ad5b03389d87bb8ed222220a2000000000000000000000000000000000ffb15b573eab367a0f9096bb98c7e2832a0a08a4f1d88eabb7a088073220ce45b5ca03c5b70aad2572a3e60b9cd348c6d4d6175bafcdbadc77df5c715f013a20d0e4be966e508d10b493d8896a9a6cd33c4bc6ab3abcbdf9d03a78b62fde0f02422095b6d39c349ec34643c6a5330f9f1fbe225e3f81b05c2d9afc7cb8c559966c0d48d6f5050abe020abb020aa8010a300a0a08a4f1d88eabb7a0880712220a2036a5d308ae90995b322666f90bddcfa8a46b1c28e53cc83838d842bf0471860612200e58c2ca31329fb9d8b38dfb9b950d7437e3648680b355c0e7f6916a4bc9eb061a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a200cb2f1a038e3459bea6bbc8d74ac03511682c09d1d1fffff662bdb36dd661a0922207be38e0b08f988e25d32b4aa03414284ab6f0e2c569fc9c6e6d6c4d7c1beab012a20fbc61de53a15e2b64443cf2898d1ed14049230c2e6e5173ffadd188bea8f210632202bbdb95993dbda1a1b1edcdbbf92dc3f5dacf71fa063b92f6766c99974d27e070a9102128e020a300a0a08c9a4c291a6a6a8870212220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a208a5d9cee4c6d90512011251185f56b9cdbdaf7aafb544b14f750ac632d2be50b2220e8ebd05949535362b32adbc80dd1ebc54ee12d5978ae67168dfe0dc3cc23490b2a206c68f5167b4db3dbd356ddd4b4745d34c171d8080d490715be4bda988a11990532201aba10788e19c3b38328c0e8ddd3594b64594c9d2dbff3996c3bd74f464c4105122408d2a72f12106f71667277642d3136373735323039301a0c0a0a08dbe3f3ebd89eedff042aa3030afe020a520a503db91d3972d73cd32b0a2b50ad228bd1d5f8eb13ab367ba7fec05aed82324fcbb84218b399b55aa96ef7fef3768d8ce78ebd8d735cd46d767a48dc8059ddf1f83d29d2473b64847af2a8cc379e6ba92012a7025559204e3172205865414f6758465a203678356b6752317537374f32353820534b38496c6d7a4220652020733037206551424b204f79326757663335373452202053594e204e7330554f6168646f3438206f705836206c20652077206747494c6d312039314e4957314e55435732376168386470683520382043756a726d67316b324777395a387232366c364f2020786863397a7556324a3238434845394d385a63205a7970367265334a32654b20202020373436785a3230205634535439377a4b38386e6f2057572020555a4b3936367853206e6b74504f20626f20396a692072386e2074797343566e3148674c5839387220323971447a3859623748206620774b4a5a6120754275202055417a37337a2031207968572031204e513954203920206932503512202fcfc901480c42fda18c33fbe8d7901e57291ceca1f74b8357b366ab20436167',
     expected_effect_hash:
-      '54d1599266060ded55b6af002002c78cbb39a3368b32059a426cc01d4a9ed978433390b5b0efc49a9549ea18bc6f55395ae48a0cde5098df7461dec5e16bc665',
-    expected_spend_sig: '',
+      '5e85d2f6d87e01f8ff7ae2dffaecba304bb382db6f35d95d677fd1b1650ef2baa740b5c3c0d3e5612200309194f4999d805ec193b379fd67e2ea4197f4073dbe',
+    expected_spend_sigs: [
+      'a883d5ef91d48e9a4b7c53e5ac354b4b32f62fabd048ecbef1af93f3ef1c4e10aa414230083aebb9a99a61fd3cc3ec90a639d782dc3d71f9bcec3c011ab44f01',
+    ],
     metadata: [],
   },
   {
     idx: 7,
     name: 'Position_Open',
-    blob: '0aae019a02aa010aa7010a630a1712090881f095b1b4a9be2e1a0a08ff81d3bf98acfdb20412480a220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10122000000000000000000000000000000000000000000000000000000000000000001a02080222180a0a08dcf191c3c2d880df08120a08d187e2d7eadaf1a10a2801122612166f7868616179656376636e736d666c63796f2d3237331a0c0a0a0892bcc9ee9fdfb7de0b',
+    blob: '0aae019a02aa010aa7010a630a1712090881f095b1b4a9be2e1a0a08ff81d3bf98acfdb20412480a220a2007ef660132a4c3235fab272d43d9b9752a8337b2d108597abffaff5f246d0f0f12220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10122000000000000000000000000000000000000000000000000000000000000000001a02080122180a0a08dcf191c3c2d880df08120a08d187e2d7eadaf1a10a28010abe020abb020aa8010a300a0a08e3c3abc68abaa9801612220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012209c276a9a18ad76dd900a4dffb143a08cfada57c652c29943542ec80e6882b7061a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a209473955e89530075e45fce44c54f1ac4ea64a2b21e8734f4a9f42a3a38a9d2002220b216f32f39d08c03416129e74422c38c0bf63a0b196fa062f957779aad28570b2a200976f380cad61d9d2717dc1233d22ec3c75a1cd60ff265cf8d03ae544579c40532203d42cff9e26557fffd99f08f6dc131247da30da79e2f05e08a8db171a5b7ca030abe020abb020aa8010a300a0a08dcf191c3c2d880df0812220a2007ef660132a4c3235fab272d43d9b9752a8337b2d108597abffaff5f246d0f0f122001be92a56a190838b69ed4f793b84bf442f8426491f3195209e7cfd7f14b700d1a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a20a5c9ff7f6ecb7813065ce5120e1f3493df92bdb6c2c17dcd3b6acd55ffe4bb0e22208e7839cd77db84dd96fc6c0c20de373b8b3c549e6b37cc38c8462de5471a130d2a20160f06c6a32589737afd1f05c2b28ef5f2c5c556f86f8304fe77ea386d404d0632204c9aca5b05ee9303fb68ee8130fedca8aa590b52134de1c1accc01ec0471910d0a89021286020a280a02080112220a20d8c076c7679d85aaba5fa83614a89561b3e5e6c959cafbd4b63e7c29feb9b60012520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a204d0871ede1c78099cf5af8062af892f4eec783f206388ae92c5fad744ad71a062220817ff5bdcfc785e41c7a9123e527eb0eaf07c298f12bc75c1e29276db3cd63042a20dffd292d8e8d85bdfc991b5a3c66401e6043b092eb729c02d16dadf1e5c0b9093220acb2a60caf4cedfce2936720d8c6cda7518c506cf191a2398728869c9d44e30b122612166f7868616179656376636e736d666c63796f2d3237331a0c0a0a0892bcc9ee9fdfb7de0b',
     expected_effect_hash:
-      'b1f19d2fb912c11321d9983e75b692f64945bd1de277b163da362b4e736e1973958dc1358748fe18f7265e921a1fe5e10c2a36050a9244c6c4fca3748a44e69c',
-    expected_spend_sig: '',
+      'd5d36925a7828064b0c42540ce46504176d3a62a721142c513b91155a9275f368cf2b8d593ecf0c335de014d115e115a4db2ea26c2947e3148cc189b78686343',
+    expected_spend_sigs: [
+      '4247b92f23a707e2f43800f1714a0516ca72fb2bffff4c342241849d9aa432098f17791f6c7fd95274a5d49c5c688052725af38c9bbc7d3c77d81afc213ebd00',
+      '743528f9bca873390d21c8c89aa4151483d9d477f8cdc39d4f96389e610b4500f569fd3b0e15e9e13f28ef2536ea8a6d8040a5728b04b0fdf2784580a06b3c02',
+    ],
     metadata: [],
   },
   {
     idx: 8,
     name: 'Position_Close',
-    blob: '0a27fa01240a220a204bbe086b0f46948f7fd7a5b873f7fb086fc399ede0ed812cfb46800769c701b5121e0896e71b120a70656e756d6272612d311a0c0a0a08b9f0f9d6bed78e8e01',
+    blob: '0a27fa01240a220a204bbe086b0f46948f7fd7a5b873f7fb086fc399ede0ed812cfb46800769c701b50abe020abb020aa8010a300a0a08b9f0f9d6bed78e8e0112220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10122009ff39762f8091b55a594742b956468ef17ef1664344b98efe24053ec3ded20f1a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a20d5cb44dfe600ffa0fc7ed98bc531f147c0b7f644a5320e4506098eef1eb24e05222096b9a63970fea9cef02d37492a76795e7154e3c99ac9ed0219a7dc7cafb7110e2a20bc5a596349b994080b168516ee48b2ebda96fd0dc232fd0d09e865fca9786f0b322064740c31eb0970d1669f3adf87dd4a5e8201a01234e3e377cfb8e832574515090ab6020ab3020aa0010a280a02080112220a2081d39e9e5d591ab0b2ff460cc5e7bb7d12d15fca2f0d1632f45e59c3b98c8c0e12203ba59eeb23f11c4f223754eafa5706049b6c0efde3764e89f4bd965662930b051a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a20d9b1a73969280347bec73d6a2b130b3bb064192174b8f08ddd83987bb69599002220509f6e8bc88f523612c9c2448bfcf88bbe22c6a40e92b704b2d3fde994cdec032a2008ff0ea14eb84e52e2610eb9baaf7029723fab360cf10f9adc28ecf4c2d7470c3220cfd4a67fa1a8d9fa39d52bae9e448ffd196b5310f1611bf012772a6ba8c3b50f0a89021286020a280a02080112220a20ba1538f8e36805a4af20ff143c778a7e8f9b0879c70726ee9b4db4b1329e2b0012520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a207bb648688cccfe26bd0114bd01a1c7255a9ac060ce1a220235868fc0c91b420e2220be478dd12c5526e08733728ffb85bd3b4042b7d16c2e805a93df296b178e4c082a206697f5120e37c0a2482590b9d1ae4c580d468e3eebc9d0607bce60d5860bfe0d322003e21d1c0d963534bdf5f32a87a3d13b4cce4a5d32f80ab6b12335479bdd1d0c121e0896e71b120a70656e756d6272612d311a0c0a0a08b9f0f9d6bed78e8e01',
     expected_effect_hash:
-      'b29f5e6cdd16d91953f827e5afd254d3691b209ab8c0ce449b709d2de99f9169b6a931ff206a888e99143f7691bcad94eaa1a91d4047844fa6e8afac9ee05232',
-    expected_spend_sig: '',
+      'a4c861e425a97b83cce66db71063ff70b6148aaa8eb88867558c717d1b2f3bbc5fb67e9cbf478f4a969b99e616149fc0b034dbf727a2b80f3d5ddbad5bc0c60d',
+    expected_spend_sigs: [
+      '24ee76eb507220f91bbfc4d1de520c63e801505ada1daab8068683c85a5aef0943d07c5e3c40c855772020a49b6b386f08a18278ccec4db3c701f9e1df7d8103',
+      'dce3d048c183e722cd29093b29cd61db5ac6c8937087b360b1be3fe35fd5dc0a4dbcb9cb43b6251a6052dd720b76824a99480be6db6d4b0326bc13bb17639802',
+    ],
     metadata: [],
   },
   {
     idx: 9,
     name: 'Position_Withdraw',
-    blob: '0a9701820293010a180a0a08aa9588978fb6cb8f02120a08d8c2ffceeffececc0612220a20e51530cb7af3ad0a870394567d165be6c235585f9d6b4310334fb2fd2fe337891a480a220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1020b1a0eba691e5d1e4cd0112371227786b61626d6a766873617263656e6f7664696c6e66622d343933393636383538313832353231361a0c0a0a0892a0feb38d8297980c',
+    blob: '0a9701820293010a180a0a08aa9588978fb6cb8f02120a08d8c2ffceeffececc0612220a20e51530cb7af3ad0a870394567d165be6c235585f9d6b4310334fb2fd2fe337891a480a220a2007ef660132a4c3235fab272d43d9b9752a8337b2d108597abffaff5f246d0f0f12220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1020b1a0eba691e5d1e4cd010abe020abb020aa8010a300a0a08baddfee49d83c8cb0512220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a101220ab085f704a0bd3db87fc41c636a0f1eae05e6a76f901396e8e9bb1e9655e47021a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a202718c3c0e8729f2b629a66716ea45319b085701a7e0cda45e4ba33c1bd540f0b2220216e636d6f290f0fe6fd51233db5f161d3cd1fa7bafe4857bae07751a6dc84072a20af7c1dd99eeaa45a2885ad3d919ed6de157fc49a65b565ace66395924053a50c3220e6c75d9c7017f09a95229fd690fc2cbffdd07022d639863056c1e1e893d723070ab6020ab3020aa0010a280a02080112220a202c3345f21910cbae06ef556f9b1ac2e01bcd000cc051eaf608fa70f14a9fec051220b955287e0e34c0b96a48f7c739ba4f31cfddadf0a521cdffff20bd4a7f81b4041a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a20b6d31800bf9ec7cf3365473bae2806d96cdd6036a73a8c76547bc4a55142810022205c7b90c41676f21c97025c245a5ef24f90d4a724ec8bc7df484117fcbe82650c2a20397f94e562b054473e0a3ed446e82ade45f1dd35fb89c0f2aca8e515e9848a00322055d0082bf2127e8f998248e6cf71c4e76dec4aafd4ad67bfad80592e37cbdd0e0a89021286020a280a02080112220a20a95cef8b2692657cb16ec28c38c553c968047b81e114acf10e87eff79d49500412520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a2025c745c1c72207e5919f865dfee5d882bb11b257f762bcc6f672dddfed51cc0522203f19223b5fe041fb96d48d91d10e2c0a8ff67f03aaaaef93dca0e006fe20f0012a20be9321bbb52d2d6939c983b81c4c339da7f27fd23f4778693018a33e315c680c3220b4ded111d2fdf94077f5775691f3361f6398caa11fc00cd9ada663d85970f9000a9102128e020a300a0a08aa9588978fb6cb8f0212220a2007ef660132a4c3235fab272d43d9b9752a8337b2d108597abffaff5f246d0f0f12520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a203227e5db8f82b3c9fffab04dcb1e5591b190841a8e33e496f69d8a39243c3e0322206b5b4ec9a78d4850c931fc8fede8d5f57648cd7c520faa419ef156c5a602d0062a20b27f7cc7971c742c31506542769597b0e501760746792052399766162a9b0c0832209680b64ed6f2ff22305fa4ea4d36ea271d6e0a2a3c0a98e3946a318c26a0f80012371227786b61626d6a766873617263656e6f7664696c6e66622d343933393636383538313832353231361a0c0a0a0892a0feb38d8297980c',
     expected_effect_hash:
-      '3ae113513e3a6022f40b9eef1e7a1b47ea33b5a1ef08aa4db1659b8a8163a14943f311515669bb608d3f5f65cd75a1df89fe5b3afaa6d41298f06f2db4f9d0d9',
-    expected_spend_sig: '',
+      'dc5f2a5cc73bd6cf8abcf0d20bf97b3c98d77feb93c3f2216e1afc7da6510844ada32b16c17246e42f85728f75f4a67189ee25886d6c835895f374640404bd00',
+    expected_spend_sigs: [
+      '7675d9a8303f3c7f98e1b72d294a3c7a70f1574dfa36a095226daa7dd843180f9904ac1562308ce33aef3a74c953487c1ccf2a2a68e75f931b47827a52423b00',
+      'ba4daceb405c7cc53fad16d60a603b080bc61ad9f8c70085ea69b6db8088e5009ca33d08e51b33f43685eff8c27875f26fe82c09c9ec13d86b4f0430bd0bc601',
+    ],
     metadata: [],
   },
   {
     idx: 10,
     name: 'Dutch_Auction_Schedule',
-    blob: '0aa801aa03a4010aa1010a300a0a08efc1ac90d8c79c9f0712220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a101a0908d299b9a5ccef8d2d220a08c38397f18be6b5f60528cbf1f0bf0130ccf1f0bf0138d2abe2910342201c8c0f7041df8be2fe5f6bf04ec82e725986f6e4822679a68c45b9f473ef6c5f124b123b61706c6d6a7a6e71636d7675617165716c6c73717a637066706876642d3735373236353238353632363830313339353636393830363530333939311a0c0a0a08da93c3c4a1d7ec9607',
+    blob: '0aa801aa03a4010aa1010a300a0a08efc1ac90d8c79c9f0712220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012220a2076b3e4b10681358c123b381f90638476b7789040e47802de879f0fb3eedc8d0b1a0a08c38397f18be6b5f605220908d299b9a5ccef8d2d28cbf1f0bf0130ccf1f0bf0138d2abe2910342201c8c0f7041df8be2fe5f6bf04ec82e725986f6e4822679a68c45b9f473ef6c5f0abe020abb020aa8010a300a0a08c9d5efd4f99e89b60e12220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10122087f19387a46a9dc1991d55819d6e81ba9cc4640a738ecdc7ac8965327e3402031a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a205f2be5cc3b8a8252ed10776a2a078d618755551c517cf78b02b055d29a36620b22206f12b2d2aff5f78f80831b0b5ffc55b0e4827343b17bb099d24dd0cd745168042a20c18f598864bf1edd3d63f3efdd60c6083850601dcf8440993b54e2fe5efb0d0c32202042ee844939566224f951e32cb29f49046bca8b22414599715ef208049c4f060a89021286020a280a02080112220a20405cd871dba4752031a4ed75d4aa4b69164515e63d7f6dc7933c12844b84390b12520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a204c8e29afeaa79652d754661e7c784f39f2934ee1be338853bb3f650a313959032220835d916690c51ea3640a8656b7ce9c28dfe5978b50f5bd9ca7126d03c93545072a20f08a8c235a525108b22230be05f70437ddf5987b2d224739ec6808321fa5d3033220977595df848dde421f7d23c3c1c1a1a444da431215e7c95878285389c19cef04124b123b61706c6d6a7a6e71636d7675617165716c6c73717a637066706876642d3735373236353238353632363830313339353636393830363530333939311a0c0a0a08da93c3c4a1d7ec9607',
     expected_effect_hash:
-      'a7ec1f4df0720884446ae9c3a82d7e0f5ccd68117af1a66205f00a5ec7e652608e2e83fead73c6fb62785df9454255d8e291e2c637e0514ac2ca5681d3d49051',
-    expected_spend_sig: '',
+      'e52fd8814ead1dab447525032ad7356984dc478189c7ec1e11d8027e2988289e7be1bcdb824e3aae21b70bae1944b9f1c67db7ba02a21db7d22b88d0fc0ac471',
+    expected_spend_sigs: [
+      '8a976719ac6ee659303333895db269bba23c379c9a3c11d23a70b1e91838bb09ecff0439243174b4a2f5237fd092f6331043b3890396176898f4b01948562f03',
+    ],
     metadata: [],
   },
   {
     idx: 11,
     name: 'Dutch_Auction_End',
-    blob: '0a27b203240a220a209146032b96da50eb4002fca16ca8caf6d814eb04d1587e2d7375f7631f8c3223121a120a70656e756d6272612d311a0c0a0a08a2c6db89a5ccf2920a2a81020adc010a520a503405ce7611de176cf025875f5fe88e40f887ca3d057343fbee5d26325b698a89bd4f61f2f05b0c3fefaf35db606e928f68582ad48a5f2f879ae7f5a5bcc9cbf67dc68abc8d64d4fa676fff340f88d81c128501596177376a464d20426f3020684e5420307a3452546530476d203033652050573138204820206b3158774d5641365020323559452020733669207a44445020367a50684d4e36543855305979202047595a527862203320205446584720594a31494c6b38366a50476d7235362020623462652036416720322066506c4e43774b42634620531220218952e6049b4fe8d33c1ccd34b01d4067c7ca4a24313f609db9d3cfc6be739a',
+    blob: '0a27b203240a220a209146032b96da50eb4002fca16ca8caf6d814eb04d1587e2d7375f7631f8c32230abe020abb020aa8010a300a0a08a2c6db89a5ccf2920a12220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012202b59a9118541d3d6d74ce0a9f3ddff3c2233890c5c862f1d0fe5c9804ead390d1a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a201e70905321c28db1132a1ce3982ae608ebf964a7a4bd917adde5cc47921ead0222209e755018900751041e9b4b888e5bb6e69b9083167c2a0faa6b13400601b2350f2a202bad02a89d49236f1ddd06b8ef1e6f40e78849485cd695db2d11bc3561d4e6043220a189ace5afd61cf0e8a23368d38b196a2129c32c9bb606d7c03e5838cb7bfc000ab6020ab3020aa0010a280a02080112220a20b6f65fb8de0b5d489d120e8f52a05a5384b60d07f990a50c14a2179cac6e9d0612206294d5ed8ee59a7b043e0c6573e58d470bb3c6474dadead624e1b7a6039bb2081a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a202f7b4ea5daddd37940b764066dd25b2f87d909315fe6e5684e312eff9c5f780422205456669c3c8f6cc012b66cff80e1b9b99980eb83631bd25f2723c7ae84f4f4022a202f8d1ee8e3b82eb056f0742965a47b7945eb99c469ac32616478ad84289adc0b32206a1775b357bfc57d0777316af88629cab474f6903bd9b9cd84ca58b1d674a60a0a89021286020a280a02080112220a2068346eaf7a858518675e33740f3e7aeb3817dec7b2c94595dbd656cda183b40f12520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a202c00120e50850e0548b6d680f873501f081ab74e2d41311733b6ad14a1ae1e0922200bfb1b524ca76d8a46f483bca8ffde0e7608df8c9a17c95d155298c5cd6c21042a20b00c1d760890eb9d4281e9e85e9ddbb64884d340bf050a49fcd0bcca1999ef023220b40bd8c698ba0f454365435135254262f4a445a50f4faf4c33d9b750155ec706121a120a70656e756d6272612d311a0c0a0a08a2c6db89a5ccf2920a2a81020adc010a520a503405ce7611de176cf025875f5fe88e40f887ca3d057343fbee5d26325b698a89bd4f61f2f05b0c3fefaf35db606e928f68582ad48a5f2f879ae7f5a5bcc9cbf67dc68abc8d64d4fa676fff340f88d81c128501596177376a464d20426f3020684e5420307a3452546530476d203033652050573138204820206b3158774d5641365020323559452020733669207a44445020367a50684d4e36543855305979202047595a527862203320205446584720594a31494c6b38366a50476d7235362020623462652036416720322066506c4e43774b42634620531220218952e6049b4fe8d33c1ccd34b01d4067c7ca4a24313f609db9d3cfc6be739a',
     expected_effect_hash:
-      '831e71ade8f18e52d8b6d5917e5dd92cde5995b12de7bb9f513cdd1fa9d0f5510f178dac9c07bef18fdd8d0f62823e88b458d4bd479608cd5d77cbec59432c8f',
-    expected_spend_sig: '',
+      '36575c8dc9f0f6b227e99bc01a4d1a9bde629305cafea673428c6255802f25bedafbd01cf7365854b3ad2d105a40c833ac729271780cb55940efa3def8fdf1ad',
+    expected_spend_sigs: [
+      'fc75f884afe0135a32a76fd551bf01183dd16ee7fc0f02b7be55f87d8706d80d0c5f9b580f1e3b9b1e09466f5b95c7006642883350041c1de7bd6660420d3301',
+      '8a6228ef312b47ce25454b2faf7ba45795f8767457567daa0a1e39c667f17c099ad146b655b5807563a553033931414229d5368005a91248d4b039ae6e494f00',
+    ],
     metadata: [],
   },
   {
     idx: 12,
     name: 'Dutch_Auction_Withdraw',
-    blob: '0a9101ba038d010a220a20c8e835549a1ff640a5812680855d9f23d6ff06ebeed9e57dddca5e71806624b210f480db0a1a300a0a08f0a0aa93b1c3839a0c12220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1022300a0a08fda0b3c49de6d1cb0912220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a10122a121b6566657774677a72736873796d6162777a6c78647a6b63642d30321a0b0a0908f4bfcfd98adebe042af1020acc020a520a500f0b7f44e8814645e90dcaf1a8b1d35f794483fd067037a4c9ad0a104c12b0dc4fa351bd73ea6b3a3b7fd8af874e987a3e5d4f6420a53d66bd022b6c97048fad6430718940efc32fbf5d7e9f36105f1212f501485a48387320582068646a2020206e207538686f52533331202063376e43356f68722050356c2031205638695341206920343161207520386c4a7a4b79662062334a3120206a6c3133685564795a374973394756202051722032203420206b3732476d6531203465616131206b48346549375420454c2020716b4e72583631203420414155206d30385076426e3745522062202052663955437220204534204f366e594643644a4f363972757333203972207935204d7331625a496f20205943363167612058592036672050206b6d5a20436244206f4e51305632203533772041396e6f4e31574938686e39664e204f7a4c3171201220e59b72f45b2a4ae19ff9f731d0b5bd45766de79e791901deebdbf090380ea29d',
+    blob: '0a9101ba038d010a220a20c8e835549a1ff640a5812680855d9f23d6ff06ebeed9e57dddca5e71806624b210f480db0a1a300a0a08f0a0aa93b1c3839a0c12220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1022300a0a08fda0b3c49de6d1cb0912220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a100ab6020ab3020aa0010a280a02080112220a209e406b01bc5a45aef66cc67e685105f231a3692d2592f9d0f72bd4b7b2333705122048df674b2123260a638952aeb1e9895a7ce0adcf096072b61a762d0c1dedba041a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a2019c25f801235e08ffd1f3987d94c7c5c28084eddb3e72317c8e100f625d77a0d222091a8d6276afeb19b8fbffda4d34dbc8a284e9f293e551e7e48cd8571f81d590c2a208e2e2f125dd792c5cb365a237c991a5e955011170ceb551b9fd7f12dc3452106322026b73d90cb1ff078d0adc025a91750df4d1d588a5d3e02fcef9af419fbbcd7060a89021286020a280a02080112220a202d0edaf857b4925bceb91efe1b7cb36aa2878c079e436f7451b1d338e355ed0412520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a20b4fb0cb3fc17b254e158ae99adb71d9c1ee3a98f1bc932495dfb19ac9109510c222004a7e215c00c7e21132f7309b4286bee7dbf59bd830c4de6e51db0dd7c4fb4042a20ead2e7b65d7cadbe13f1136eda7aa7dc86bb08886992887e98fb8f984f9fa10a32202cd3ea1e6de40619872715e88cb4ca12006126e79167ca6447f70cef91a4c00d0a9102128e020a300a0a08f9818efec3cb96e11512220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012520a50bfbb4c92e67eb7e6af13f633a477c3d325bee50d044e441512e6bf7fcdd9650934f9d8ead350816317dad39308f0d91dcaa155f9bf4aa7e2f8378df85008aef46766d1fe5c2b097f71ac1489154ddea91a207bd5231ec16692c87d23b01e6574baf970fc00524316a95c43a5888cf2310d0e22205eeaffef56ecc3750fa0d130335af6b9a6950cfca6998023f060d36b3dfa3e012a20499d69213e399e95b676ebebefda85bfef5ed897e2a1b0a374d0ba53e00c1601322036e79c1ddd1584ce7899dc1aa9101af5951159282b1f1717315d2907411eba07122a121b6566657774677a72736873796d6162777a6c78647a6b63642d30321a0b0a0908f4bfcfd98adebe042af1020acc020a520a500f0b7f44e8814645e90dcaf1a8b1d35f794483fd067037a4c9ad0a104c12b0dc4fa351bd73ea6b3a3b7fd8af874e987a3e5d4f6420a53d66bd022b6c97048fad6430718940efc32fbf5d7e9f36105f1212f501485a48387320582068646a2020206e207538686f52533331202063376e43356f68722050356c2031205638695341206920343161207520386c4a7a4b79662062334a3120206a6c3133685564795a374973394756202051722032203420206b3732476d6531203465616131206b48346549375420454c2020716b4e72583631203420414155206d30385076426e3745522062202052663955437220204534204f366e594643644a4f363972757333203972207935204d7331625a496f20205943363167612058592036672050206b6d5a20436244206f4e51305632203533772041396e6f4e31574938686e39664e204f7a4c3171201220e59b72f45b2a4ae19ff9f731d0b5bd45766de79e791901deebdbf090380ea29d',
     expected_effect_hash:
-      '5a43389ca7f1beddea45593243f3d3074df09cbba250b5b2f29a0d9e13f4769b352d2b7cceb77264186754bad2b31513e08071f1714cc82f7b986ffc1c1d34b2',
-    expected_spend_sig: '',
+      '4632209056823c45ba843344276035d5bc44a07165f349fc404c50d29cc2b2de711a5e97319496ddde6366cf95e807df458b9b1428ae395f26b1e684ab444bce',
+    expected_spend_sigs: [
+      'e8a6089e8581fff2a8950304dcf3ba27305d3dc9c512d93c994f9ecf40c3fa0b2829287d1bb4f85b654f48d45bdce0d052ea50df52442f9a047b09cdb2ce4d02',
+    ],
     metadata: [],
   },
   {
     idx: 13,
     name: 'Output-test-asset',
-    blob: '0a9102128e020a300a0a088ef8e5ace3f88aad0a12220a2082d798bc3ffb0ab36b85cf4ba286a2152b30e1dfbb30f17e7b3db0ed8361fd0d12520a50093d4a766759cd838b50faf57aac33aa559c5f687e213aff40a31f5d8c2beacf2a7f54dd3634548adbb6c5842d144c4b5eaeb81fd0ae80cbef00c9c10d02758247f140b095e2af3ef6e6bc189b42db641a206e4ddf750b89ced675139e56f7a41fcdfe99eb3b6884eaf3d6535c95029ef8d522209b5868ea1d423772834d162e7ce633cdb8d4e52cf7b3351630804904f5b173022a2031986e5de7a87e3c38d9321ce8d0d9e998d739802c3e3b68b2a105056352740b32200b729f8b6686376ebf242f56c6f5e2f9996360c2ebd5e73ff44e37398fedd701122e121e7777797a686f77736a66796e616b667a63657868642d31313934303534351a0c0a0a08e3f2a1cad1dec1a9042ae1010abc010a520a50b455a44f1e6502c0ce682e48b94406ef7a1f47c080b5b2dcf6d5eb261c14dce1794375f95506b5fb690322824981dc5edeafd9f6bcba73334b944e6f4b64907aa1da925fb38135b4ec81db32e385f635126651384c2064204a6a3820314a504f314520666163454d4e2051202020202055204139664755204820485a433165564f56556548532062204f203962596a3674793275204d4d556770507720656667376f7337203634517075205059632072324f7a202070306612207f376f07f1391167daf4526c0f0344e40cfec26e582a16a21f847b1171a8c5ea',
+    blob: '0a9102128e020a300a0a088ef8e5ace3f88aad0a12220a2082d798bc3ffb0ab36b85cf4ba286a2152b30e1dfbb30f17e7b3db0ed8361fd0d12520a50093d4a766759cd838b50faf57aac33aa559c5f687e213aff40a31f5d8c2beacf2a7f54dd3634548adbb6c5842d144c4b5eaeb81fd0ae80cbef00c9c10d02758247f140b095e2af3ef6e6bc189b42db641a206e4ddf750b89ced675139e56f7a41fcdfe99eb3b6884eaf3d6535c95029ef8d522209b5868ea1d423772834d162e7ce633cdb8d4e52cf7b3351630804904f5b173022a2031986e5de7a87e3c38d9321ce8d0d9e998d739802c3e3b68b2a105056352740b32200b729f8b6686376ebf242f56c6f5e2f9996360c2ebd5e73ff44e37398fedd7010abe020abb020aa8010a300a0a088ef8e5ace3f88aad0a12220a2082d798bc3ffb0ab36b85cf4ba286a2152b30e1dfbb30f17e7b3db0ed8361fd0d1220371cd9015071a805cccff8bceceb9e2c157095c7707fda1b1166710bc3c30d0b1a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a2015746e54f2cbfe971c701d47983f0b49ed857b641ff8c3a664e330392df836022220977182e49dedf671701bc05b33bf04b625e494f6bb552e27ddd195816a77ec0a2a201d41edf4c7d455c0803e93e6c52d56c5f21e7da95a91ab1a4733c8ec2d7f580332208e8934a75c3189be94b455b8ffc54c7936367bbac8af9081455f8e5e3d152f010abe020abb020aa8010a300a0a08e3f2a1cad1dec1a90412220a2029ea9c2f3371f6a487e7e95c247041f4a356f983eb064e5d2b3bcf322ca96a1012208813b8ae1b8963d39f1f7c3095fff13630a825827da0aff8fe06dbc8c38a49061a520a50890bc98e3698aa4578e419b028da5672e627c280d8b06166f4c42d5366bccf1fcf3b296cd61e8d744a21f75f2fb697183e18595d8a79008539d8fb138b405db09db65cc42d54c0e772e5d42d5f20b52f10a19f8ecd8ff5161a2083ceb6460932ff440854394ec87ae52117e04125d15795830178429f1460160222202337406f5eeb32bf25c2dcd4acabc4a98d707b38e21902ab99d2a7b90d3397092a2054878fd68ab0201baa1659cd089281e4b55529e76abc24e0789044f9769df8013220668dc64e7e14cf10cb21e4ee6e1df220178ab00aedf82263fe3774427446ab0b122e121e7777797a686f77736a66796e616b667a63657868642d31313934303534351a0c0a0a08e3f2a1cad1dec1a9042ae1010abc010a520a50b455a44f1e6502c0ce682e48b94406ef7a1f47c080b5b2dcf6d5eb261c14dce1794375f95506b5fb690322824981dc5edeafd9f6bcba73334b944e6f4b64907aa1da925fb38135b4ec81db32e385f635126651384c2064204a6a3820314a504f314520666163454d4e2051202020202055204139664755204820485a433165564f56556548532062204f203962596a3674793275204d4d556770507720656667376f7337203634517075205059632072324f7a202070306612207f376f07f1391167daf4526c0f0344e40cfec26e582a16a21f847b1171a8c5ea',
     expected_effect_hash:
-      'f08dd6ef794daadf9ee676e211c4c1bc2f21ac524123d1296d79b2ca1beaa9f09b0051d1445a5620038311e65ba3aba34f2fb8095d84cde5317fe91718056387',
-    expected_spend_sig: '',
+      'a25d55c7820d0f4ee7f6ef4aea81cc5bd339701e17e86722459c5f890bec6a383257749505610e593d481d516d21a4eedf6a71e5a1be159a79a3ee18614c18b6',
+    expected_spend_sigs: [
+      'ca3a48ac99295404403cdabad1ef3c348c850f409b9f502fb3c85d6404b0810764278dbe5da5db656f82aac3e383745b8711db500d653413be1764b5414ca603',
+      '70fb46548acd670963835c9840ccfddf4bd51e8568a101fe54ac8308c4cef30d0723ac4ed1e615b9992e01ef36b53d0b20678fa76d79b49974e24ee6a5c4c702',
+    ],
     metadata: ['test-asset'],
   },
 ]
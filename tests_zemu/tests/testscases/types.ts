@@ -3,6 +3,6 @@ export interface TestCase {
   name: string
   blob: string
   expected_effect_hash: string
-  expected_spend_sig: string
+  expected_spend_sigs: string[]
   metadata: string[]
 }